@@ -64,6 +64,10 @@ log("client.start complete");
 log("createSession start");
 const session = await client.createSession({
   model: payload.model || "gpt-4.1",
+  // Voxii passes ai.prompts[...] from settings when the user overrides it.
+  ...(payload.systemPrompt
+    ? { systemMessage: { mode: "replace", content: payload.systemPrompt } }
+    : {}),
 });
 log("createSession complete");
 
@@ -0,0 +1,105 @@
+import { CopilotClient } from "@github/copilot-sdk";
+import fs from "fs/promises";
+
+const inputPath = process.argv[2];
+if (!inputPath) {
+  console.error("Missing input path");
+  process.exit(1);
+}
+
+const raw = await fs.readFile(inputPath, "utf-8");
+const cleaned = raw.replace(/^\uFEFF/, "").trim();
+const payload = JSON.parse(cleaned);
+
+const notes = payload.notes?.trim() ? `\n\nUser notes:\n${payload.notes}` : "";
+const transcript = payload.transcript?.trim() || "";
+
+const prompt = `You are a meeting assistant specialized in identifying decisions.
+
+Extract the key decisions made in the following meeting transcript and notes.
+
+Return ONLY valid JSON in this exact format (no markdown, no explanation):
+{
+  "decisions": [
+    {
+      "decision": "What was decided, stated as a clear outcome",
+      "rationale": "Why it was decided, if discussed, otherwise null",
+      "owner": "Who made or owns the decision if mentioned, otherwise null"
+    }
+  ]
+}
+
+Rules:
+- Only include decisions that were actually agreed, not proposals still under discussion
+- Do NOT include tasks; those are action items
+- Do NOT invent owners or rationale that weren't mentioned
+- Keep each decision to one sentence
+- If no decisions were made, return {"decisions": []}
+
+TRANSCRIPT:
+${transcript}${notes}
+
+Return only the JSON object, nothing else.`;
+
+const client = new CopilotClient();
+const startedAt = Date.now();
+const log = (message) => {
+  const elapsed = Date.now() - startedAt;
+  console.error(`[decisions] +${elapsed}ms ${message}`);
+};
+
+log("init");
+await client.start();
+log("client.start complete");
+
+log("createSession start");
+const session = await client.createSession({
+  model: payload.model || "gpt-4.1",
+});
+log("createSession complete");
+
+try {
+  log("sendAndWait start");
+  const response = await session.sendAndWait({ prompt });
+  log("sendAndWait complete");
+  
+  let content = response?.data?.content ?? "";
+  
+  // Try to extract JSON from the response
+  content = content.trim();
+  
+  // Remove markdown code blocks if present
+  if (content.startsWith("```json")) {
+    content = content.slice(7);
+  } else if (content.startsWith("```")) {
+    content = content.slice(3);
+  }
+  if (content.endsWith("```")) {
+    content = content.slice(0, -3);
+  }
+  content = content.trim();
+  
+  // Validate it's valid JSON
+  try {
+    const parsed = JSON.parse(content);
+    // Ensure it has the expected structure
+    if (!Array.isArray(parsed.decisions)) {
+      parsed.decisions = [];
+    }
+    // Output clean JSON
+    process.stdout.write(JSON.stringify(parsed, null, 2));
+  } catch (parseError) {
+    log(`JSON parse error: ${parseError.message}`);
+    // Return no decisions if parsing fails
+    process.stdout.write(JSON.stringify({ decisions: [] }));
+  }
+
+  log("destroy session");
+  await session.destroy();
+  log("client.stop");
+  await client.stop();
+} catch (error) {
+  await client.stop();
+  console.error(error instanceof Error ? error.message : String(error));
+  process.exit(1);
+}
@@ -19,6 +19,10 @@ await client.start();
 const streaming = process.env.STREAMING === "1";
 const session = await client.createSession({
   model: payload.model || "gpt-4.1",
+  // Voxii passes ai.prompts[...] from settings when the user overrides it.
+  ...(payload.systemPrompt
+    ? { systemMessage: { mode: "replace", content: payload.systemPrompt } }
+    : {}),
   ...(streaming ? { streaming: true } : {}),
 });
 
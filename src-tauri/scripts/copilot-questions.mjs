@@ -0,0 +1,110 @@
+import { CopilotClient } from "@github/copilot-sdk";
+import fs from "fs/promises";
+
+const inputPath = process.argv[2];
+if (!inputPath) {
+  console.error("Missing input path");
+  process.exit(1);
+}
+
+const raw = await fs.readFile(inputPath, "utf-8");
+const cleaned = raw.replace(/^\uFEFF/, "").trim();
+const payload = JSON.parse(cleaned);
+
+const notes = payload.notes?.trim() ? `\n\nUser notes:\n${payload.notes}` : "";
+const transcript = payload.transcript?.trim() || "";
+
+const prompt = `You are a meeting assistant specialized in identifying questions.
+
+Extract the questions raised in the following meeting transcript and notes.
+
+Return ONLY valid JSON in this exact format (no markdown, no explanation):
+{
+  "questions": [
+    {
+      "question": "The question as asked, rephrased as one clear sentence",
+      "askedBy": "Who asked it if mentioned, otherwise null",
+      "answered": false,
+      "answer": "The answer given in the meeting if answered, otherwise null"
+    }
+  ]
+}
+
+Rules:
+- Only include real questions that needed an answer, not rhetorical ones or small talk
+- Set "answered" to true only if the meeting actually answered the question
+- Do NOT invent askers or answers that weren't mentioned
+- Merge repeated asks of the same question into one entry
+- If no questions were raised, return {"questions": []}
+
+TRANSCRIPT:
+${transcript}${notes}
+
+Return only the JSON object, nothing else.`;
+
+const client = new CopilotClient();
+const startedAt = Date.now();
+const log = (message) => {
+  const elapsed = Date.now() - startedAt;
+  console.error(`[questions] +${elapsed}ms ${message}`);
+};
+
+log("init");
+await client.start();
+log("client.start complete");
+
+log("createSession start");
+const session = await client.createSession({
+  model: payload.model || "gpt-4.1",
+  // Voxii passes ai.prompts[...] from settings when the user overrides it.
+  ...(payload.systemPrompt
+    ? { systemMessage: { mode: "replace", content: payload.systemPrompt } }
+    : {}),
+});
+log("createSession complete");
+
+try {
+  log("sendAndWait start");
+  const response = await session.sendAndWait({ prompt });
+  log("sendAndWait complete");
+  
+  let content = response?.data?.content ?? "";
+  
+  // Try to extract JSON from the response
+  content = content.trim();
+  
+  // Remove markdown code blocks if present
+  if (content.startsWith("```json")) {
+    content = content.slice(7);
+  } else if (content.startsWith("```")) {
+    content = content.slice(3);
+  }
+  if (content.endsWith("```")) {
+    content = content.slice(0, -3);
+  }
+  content = content.trim();
+  
+  // Validate it's valid JSON
+  try {
+    const parsed = JSON.parse(content);
+    // Ensure it has the expected structure
+    if (!Array.isArray(parsed.questions)) {
+      parsed.questions = [];
+    }
+    // Output clean JSON
+    process.stdout.write(JSON.stringify(parsed, null, 2));
+  } catch (parseError) {
+    log(`JSON parse error: ${parseError.message}`);
+    // Return no questions if parsing fails
+    process.stdout.write(JSON.stringify({ questions: [] }));
+  }
+
+  log("destroy session");
+  await session.destroy();
+  log("client.stop");
+  await client.stop();
+} catch (error) {
+  await client.stop();
+  console.error(error instanceof Error ? error.message : String(error));
+  process.exit(1);
+}
@@ -22,7 +22,8 @@ const sections = payload.sections || [
 const notes = payload.notes?.trim() ? `\n\nUser notes:\n${payload.notes}` : "";
 const transcript = payload.transcript?.trim() || "";
 
-const prompt = `You are a meeting assistant. Create a concise, structured summary in Markdown with these sections:\n${sections
+// Voxii renders the prompt itself (so it can be previewed); this is the fallback.
+const prompt = payload.prompt || `You are a meeting assistant. Create a concise, structured summary in Markdown with these sections, in this order, each as a "## " heading:\n${sections
   .map((s) => `- ${s}`)
   .join("\n")}\n\nRules:\n- Use short bullet points\n- Be factual, no speculation\n- Keep names and numbers accurate\n- If a section has no content, write "- None"\n\nTranscript:\n${transcript}${notes}\n\nReturn only Markdown.`;
 
@@ -40,6 +41,10 @@ const streaming = process.env.STREAMING === "1";
 log(`createSession start (streaming=${streaming})`);
 const session = await client.createSession({
   model: payload.model || "gpt-4.1",
+  // Voxii passes ai.prompts[...] from settings when the user overrides it.
+  ...(payload.systemPrompt
+    ? { systemMessage: { mode: "replace", content: payload.systemPrompt } }
+    : {}),
   ...(streaming ? { streaming: true } : {}),
 });
 log("createSession complete");
@@ -0,0 +1,113 @@
+import { CopilotClient } from "@github/copilot-sdk";
+import fs from "fs/promises";
+
+const inputPath = process.argv[2];
+if (!inputPath) {
+  console.error("Missing input path");
+  process.exit(1);
+}
+
+const raw = await fs.readFile(inputPath, "utf-8");
+const cleaned = raw.replace(/^\uFEFF/, "").trim();
+const payload = JSON.parse(cleaned);
+
+const transcript = payload.transcript?.trim() || "";
+
+const prompt = `You are a meeting assistant that splits long meeting transcripts into chapters.
+
+Identify where the conversation moves to a new topic and give each topic a short title.
+
+Return ONLY valid JSON in this exact format (no markdown, no explanation):
+{
+  "chapters": [
+    {
+      "title": "Short topic title (max 8 words)",
+      "startQuote": "The first 6-12 words of the chapter, copied verbatim from the transcript"
+    }
+  ]
+}
+
+Rules:
+- Chapters must be in transcript order and cover the whole transcript
+- The first chapter starts at the beginning of the transcript
+- startQuote MUST be copied exactly, including punctuation and casing
+- Prefer a few meaningful chapters over many tiny ones
+- If the transcript covers a single topic, return one chapter
+
+TRANSCRIPT:
+${transcript}
+
+Return only the JSON object, nothing else.`;
+
+const client = new CopilotClient();
+const startedAt = Date.now();
+const log = (message) => {
+  const elapsed = Date.now() - startedAt;
+  console.error(`[topics] +${elapsed}ms ${message}`);
+};
+
+log("init");
+await client.start();
+log("client.start complete");
+
+log("createSession start");
+const session = await client.createSession({
+  model: payload.model || "gpt-4.1",
+  streaming: true,
+});
+log("createSession complete");
+
+try {
+  let finalContent = "";
+  const done = new Promise((resolve) => {
+    session.on((event) => {
+      if (event.type === "assistant.message_delta") {
+        const delta = event.data.deltaContent || "";
+        finalContent += delta;
+        process.stdout.write(
+          `${JSON.stringify({ type: "delta", content: delta })}\n`
+        );
+      } else if (event.type === "assistant.message") {
+        finalContent = event.data.content || finalContent;
+      } else if (event.type === "session.idle") {
+        log("session.idle received");
+        resolve();
+      }
+    });
+  });
+
+  log("send start");
+  await session.send({ prompt });
+  await done;
+
+  let content = finalContent.trim();
+  if (content.startsWith("```json")) {
+    content = content.slice(7);
+  } else if (content.startsWith("```")) {
+    content = content.slice(3);
+  }
+  if (content.endsWith("```")) {
+    content = content.slice(0, -3);
+  }
+  content = content.trim();
+
+  let chapters = [];
+  try {
+    const parsed = JSON.parse(content);
+    chapters = Array.isArray(parsed.chapters) ? parsed.chapters : [];
+  } catch (parseError) {
+    log(`JSON parse error: ${parseError.message}`);
+  }
+  process.stdout.write(
+    `${JSON.stringify({ type: "final", content: { chapters } })}\n`
+  );
+
+  log("destroy session");
+  await session.destroy();
+  log("client.stop");
+  await client.stop();
+} catch (error) {
+  await client.stop();
+  console.error(error instanceof Error ? error.message : String(error));
+  process.exit(1);
+}
@@ -1,15 +1,26 @@
 use base64::Engine;
 use serde::{Deserialize, Serialize};
 use std::{
-    collections::HashMap,
+    collections::{hash_map::DefaultHasher, BTreeSet, HashMap},
+    hash::{Hash, Hasher},
     fs,
-    io::{BufRead, BufReader},
+    io::{BufRead, BufReader, Read, Write},
+    net::{Ipv4Addr, SocketAddr, TcpListener, TcpStream},
     path::{Path, PathBuf},
-    process::{Command, Stdio},
-    sync::Mutex,
-    time::Instant,
+    process::{Child, Command, ExitStatus, Stdio},
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, Condvar, Mutex, OnceLock, PoisonError,
+    },
+    time::{Duration, Instant, SystemTime},
 };
+use regex::Regex;
 use tauri::{Emitter, Manager, State};
+use tauri_plugin_opener::OpenerExt;
+use tracing_subscriber::{
+    filter::LevelFilter, fmt::MakeWriter, layer::SubscriberExt, reload, util::SubscriberInitExt,
+    Registry,
+};
 
 // ============================================================================
 // Types
@@ -23,6 +34,60 @@ struct TranscribeResponse {
     stderr: String,
     command: String,
     provider: String,
+    model: String,
+    /// Timed segments when the provider reports them (local whisper only).
+    segments: Vec<TranscriptSegment>,
+    /// Whisper's output before `autoClean` or `restorePunctuation` rewrote
+    /// `transcript`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    raw_transcript: Option<String>,
+    /// Whether `transcript` is the punctuation-restored version.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    punctuation_restored: bool,
+    /// Language whisper detected when run with `auto`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+    /// Kept copy of the input audio when `transcription.local.keepAudio` is on.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    audio_path: Option<String>,
+    /// Model that failed to load when `fallbackModelOnError` switched to `model`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    model_fallback_from: Option<String>,
+    /// Per-word timings when `transcription.local.wordTimestamps` is on.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    words: Vec<WordTiming>,
+    /// Whether some segments lacked token timings, so their words were spread
+    /// evenly across the segment instead.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    words_interpolated: bool,
+    /// Bytes of transcript text plus JSON whisper wrote, so `test_model` can
+    /// tell a model that loads but produces nothing.
+    #[serde(skip)]
+    output_bytes: usize,
+}
+
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct WordTiming {
+    text: String,
+    start_ms: u64,
+    end_ms: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct TranscriptSegment {
+    start_ms: u64,
+    end_ms: u64,
+    text: String,
+    /// Mean token probability, 0.0-1.0.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    confidence: Option<f32>,
+    /// Detected for the audio this segment came from: its channel in
+    /// `transcribe_multichannel`, its piece of a multi-language
+    /// `transcribe_audio`. Unset for single-language runs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
@@ -44,11 +109,39 @@ struct StreamingConfig {
     chunk_duration_ms: u32,
     #[serde(default = "default_overlap")]
     overlap_ms: u32,
+    /// Bounds (in words) for the repeated run dropped where overlapping chunks
+    /// meet. A higher minimum keeps genuine short repeats ("no, no") at the
+    /// cost of letting short duplicates through; a higher maximum catches long
+    /// duplicated phrases but scans more text. Default to
+    /// `DEFAULT_OVERLAP_MATCH_TOKENS`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    overlap_match_min_tokens: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    overlap_match_max_tokens: Option<usize>,
+    /// Chunks whose average confidence falls below this are flagged
+    /// `lowConfidence`. Defaults to `DEFAULT_LOW_CONFIDENCE_THRESHOLD`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    low_confidence_threshold: Option<f32>,
+}
+
+impl StreamingConfig {
+    fn overlap_match_tokens(&self) -> (usize, usize) {
+        (
+            self.overlap_match_min_tokens.unwrap_or(DEFAULT_OVERLAP_MATCH_TOKENS.0),
+            self.overlap_match_max_tokens.unwrap_or(DEFAULT_OVERLAP_MATCH_TOKENS.1),
+        )
+    }
 }
 
 fn default_true() -> bool { true }
 fn default_chunk_duration() -> u32 { 5000 }
 fn default_overlap() -> u32 { 500 }
+/// Default (min, max) words for overlap dedup at chunk seams.
+const DEFAULT_OVERLAP_MATCH_TOKENS: (usize, usize) = (2, 8);
+/// Upper bound for `overlap_match_max_tokens`; 500ms of overlap is a few words.
+const MAX_OVERLAP_MATCH_TOKENS: usize = 50;
+const DEFAULT_LOW_CONFIDENCE_THRESHOLD: f32 = 0.6;
+const DEFAULT_MAX_AUDIO_BYTES: u64 = 100 * 1024 * 1024;
 
 #[derive(Serialize, Deserialize, Clone, Debug, Default)]
 #[serde(rename_all = "camelCase")]
@@ -59,12 +152,58 @@ struct LocalTranscriptionConfig {
     model_path: String,
     #[serde(default)]
     model_name: String,
+    /// Base for model paths written as `./…` or `models/…`, so configs can be
+    /// shared between machines. Empty means the app data dir.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    models_root: String,
     #[serde(default = "default_beam_size")]
     beam_size: u32,
     #[serde(default = "default_best_of")]
     best_of: u32,
+    // Optional decoding knobs; unset means whisper's own defaults (flag omitted).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    temperature: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    entropy_thold: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    logprob_thold: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    word_thold: Option<f32>,
+    /// Max characters per segment (`--max-len`); 0 leaves segmentation to the model.
+    #[serde(default)]
+    max_segment_len: u32,
+    /// Pass `--split-on-word` so `maxSegmentLen` breaks between words, not
+    /// tokens. Skipped with a warning when the binary doesn't list the flag.
+    #[serde(default)]
+    split_on_word: bool,
+    /// Run whisper once more when it exits cleanly but writes an empty
+    /// transcript, which is sometimes transient.
+    #[serde(default)]
+    retry_on_empty: bool,
+    /// Passed to whisper verbatim after every managed flag. whisper keeps the
+    /// last value for repeated options, so these win over the managed ones —
+    /// except output flags, since Voxii reads `<out>.txt`/`<out>.json` back.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    extra_args: Vec<String>,
+    /// Move the audio whisper read into `<data dir>/debug` instead of deleting it.
+    #[serde(default)]
+    keep_audio: bool,
+    /// When the model fails to load, retry once with the next preferred model
+    /// in the same folder. The response reports the substitution.
+    #[serde(default)]
+    fallback_model_on_error: bool,
+    /// Return per-word timings built from whisper's token timestamps. Makes
+    /// responses much larger, so it's off by default.
+    #[serde(default)]
+    word_timestamps: bool,
+    /// Overrides `transcription.language` for local runs; same values.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
 }
 
+/// Upper bound for `max_segment_len`; longer caps are effectively unlimited.
+const MAX_SEGMENT_LEN_LIMIT: u32 = 1000;
+
 fn default_beam_size() -> u32 { 5 }
 fn default_best_of() -> u32 { 5 }
 
@@ -77,6 +216,16 @@ struct OpenAICompatibleConfig {
     api_key: String,
     #[serde(default = "default_whisper_model")]
     model: String,
+    /// USD per audio minute, keyed by model. Overrides `DEFAULT_REMOTE_PRICES`.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    price_per_minute: HashMap<String, f64>,
+    /// Ask for `stream=true` on streaming chunks so captions update mid-chunk.
+    /// Needs a model that streams (e.g. `gpt-4o-transcribe`; `whisper-1` doesn't).
+    #[serde(default)]
+    stream: bool,
+    /// Overrides `transcription.language` for remote runs; same values.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
 }
 
 fn default_openai_endpoint() -> String {
@@ -89,8 +238,21 @@ fn default_whisper_model() -> String { "whisper-1".to_string() }
 struct TranscriptionConfig {
     #[serde(default)]
     provider: TranscriptionProvider,
+    /// Language hint. Unset falls back to `"en"`, `"auto"` asks whisper to
+    /// detect the language, and an explicit empty string sends no hint at all.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+    /// Run transcripts through the AI cleanup step after `transcribe_audio`.
     #[serde(default)]
-    language: String,
+    auto_clean: bool,
+    /// When whisper output comes back unpunctuated, have the AI cleanup step
+    /// restore punctuation only. Skipped when `autoClean` already ran.
+    #[serde(default)]
+    restore_punctuation: bool,
+    /// Largest decoded audio accepted over IPC; `0` disables the check.
+    /// Defaults to `DEFAULT_MAX_AUDIO_BYTES`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    max_audio_bytes: Option<u64>,
     #[serde(default)]
     streaming: StreamingConfig,
     #[serde(default)]
@@ -104,9 +266,115 @@ struct TranscriptionConfig {
 struct AIConfig {
     #[serde(default = "default_model")]
     default_model: String,
+    /// Maximum number of node-based AI processes allowed to run at once.
+    #[serde(default = "default_ai_max_concurrency")]
+    max_concurrency: u32,
+    /// Summary sections, in the order they are generated and exported.
+    #[serde(default = "default_summary_sections")]
+    summary_sections: Vec<String>,
+    /// System prompt overrides keyed by `AI_PROMPT_KINDS`; blank entries keep
+    /// the script's default.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    prompts: HashMap<String, String>,
+    /// Model per `AI_PROMPT_KINDS` task, e.g. a strong model for summaries and
+    /// a cheap one for cleanup. Takes precedence over the model a command is
+    /// called with; tasks without an entry keep it.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    models: HashMap<String, String>,
+    /// Where `list_models` discovers models. AI commands still run through the
+    /// Copilot scripts either way.
+    #[serde(default)]
+    provider: AIProvider,
+    #[serde(default, rename = "openaiCompatible")]
+    openai_compatible: AIEndpointConfig,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+enum AIProvider {
+    #[default]
+    Copilot,
+    #[serde(rename = "openai-compatible")]
+    OpenAICompatible,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+struct AIEndpointConfig {
+    /// API base URL, e.g. `https://api.openai.com/v1` or `http://localhost:11434/v1`.
+    #[serde(default)]
+    endpoint: String,
+    /// Optional; local servers usually accept unauthenticated requests.
+    #[serde(default)]
+    api_key: String,
 }
 
+const AI_PROMPT_KINDS: [&str; 5] = ["summary", "enhance", "clean", "actions", "questions"];
+
 fn default_model() -> String { "gpt-4.1".to_string() }
+fn default_ai_max_concurrency() -> u32 { 1 }
+fn default_summary_sections() -> Vec<String> {
+    ["Agenda", "Summary", "Decisions", "Risks", "Actions"]
+        .map(String::from)
+        .to_vec()
+}
+
+impl AIConfig {
+    fn effective_summary_sections(&self) -> Vec<String> {
+        let sections: Vec<String> = self
+            .summary_sections
+            .iter()
+            .map(|section| section.trim().to_string())
+            .filter(|section| !section.is_empty())
+            .collect();
+        if sections.is_empty() {
+            default_summary_sections()
+        } else {
+            sections
+        }
+    }
+}
+
+/// Configured summary sections, falling back to the defaults if the config
+/// can't be read.
+fn summary_sections(app: &tauri::AppHandle) -> Vec<String> {
+    load_config_sync(app)
+        .map(|config| config.ai.effective_summary_sections())
+        .unwrap_or_else(|_| default_summary_sections())
+}
+
+/// Model for an AI task: `ai.models[kind]` when set, else the caller's
+/// choice, else `ai.defaultModel`.
+fn ai_task_model(app: &tauri::AppHandle, kind: &str, requested: String) -> String {
+    let config = load_config_sync(app).unwrap_or_default();
+    let routed = config
+        .ai
+        .models
+        .get(kind)
+        .map(|model| model.trim())
+        .filter(|model| !model.is_empty());
+    match routed {
+        Some(model) => {
+            if model != requested {
+                tracing::debug!(kind, model, requested, "AI task routed");
+            }
+            model.to_string()
+        }
+        None if requested.trim().is_empty() => config.ai.default_model,
+        None => requested,
+    }
+}
+
+/// The configured system prompt for one AI command, if set.
+fn ai_system_prompt(app: &tauri::AppHandle, kind: &str) -> Option<String> {
+    let config = load_config_sync(app).ok()?;
+    config
+        .ai
+        .prompts
+        .get(kind)
+        .map(|prompt| prompt.trim().to_string())
+        .filter(|prompt| !prompt.is_empty())
+}
 
 #[derive(Serialize, Deserialize, Clone, Debug, Default)]
 #[serde(rename_all = "camelCase")]
@@ -115,6 +383,22 @@ struct ExportConfig {
     default_format: String,
     #[serde(default)]
     local_path: String,
+    /// Prepend YAML front-matter to markdown exports (for static site generators).
+    #[serde(default)]
+    frontmatter: bool,
+    /// Also write `<name>.meta.json` next to each markdown export.
+    #[serde(default)]
+    sidecar_metadata: bool,
+    /// Extra regexes for `export_redacted_transcript`, on top of the built-in
+    /// email and phone patterns.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    redaction_patterns: Vec<String>,
+    /// Mirror meetings into the export dir whenever `save_meetings` changes them.
+    #[serde(default)]
+    auto_export: bool,
+    /// Format for `autoExport`; one `sync_all_exports` accepts (`markdown` or `html`).
+    #[serde(default = "default_format")]
+    auto_export_format: String,
 }
 
 fn default_format() -> String { "markdown".to_string() }
@@ -128,10 +412,60 @@ struct UIConfig {
     show_diagnostics: bool,
     #[serde(default)]
     include_system_audio: bool,
+    /// Coalesce `save_meetings` calls arriving within this many ms into one
+    /// write of the latest list. 0 writes every call immediately.
+    #[serde(default)]
+    save_debounce_ms: u64,
 }
 
+/// Upper bound for `ui.saveDebounceMs`; longer windows risk noticeable data loss on a crash.
+const MAX_SAVE_DEBOUNCE_MS: u64 = 10_000;
+
 fn default_theme() -> String { "system".to_string() }
 
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+struct LoggingConfig {
+    /// One of `trace`, `debug`, `info`, `warn`, `error`, `off`.
+    #[serde(default = "default_log_level")]
+    level: String,
+}
+
+impl Default for LoggingConfig {
+    fn default() -> Self {
+        Self {
+            level: default_log_level(),
+        }
+    }
+}
+
+fn default_log_level() -> String { "info".to_string() }
+
+/// Optional localhost API for scripting transcription without the GUI.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+struct ServerConfig {
+    #[serde(default)]
+    enabled: bool,
+    #[serde(default = "default_server_port")]
+    port: u16,
+    /// Required on every request as `Authorization: Bearer <token>`.
+    #[serde(default)]
+    token: String,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            port: default_server_port(),
+            token: String::new(),
+        }
+    }
+}
+
+fn default_server_port() -> u16 { 8765 }
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
 struct AppConfig {
@@ -145,6 +479,14 @@ struct AppConfig {
     export: ExportConfig,
     #[serde(default)]
     ui: UIConfig,
+    #[serde(default)]
+    logging: LoggingConfig,
+    #[serde(default)]
+    server: ServerConfig,
+    /// Refuse every network call: remote transcription, model lists and all
+    /// AI features (they go through the Copilot SDK). Transcription runs locally.
+    #[serde(default)]
+    offline_mode: bool,
     // Legacy fields for backward compatibility
     #[serde(default, skip_serializing)]
     whisper_path: String,
@@ -169,6 +511,9 @@ impl Default for AppConfig {
             ai: AIConfig::default(),
             export: ExportConfig::default(),
             ui: UIConfig::default(),
+            logging: LoggingConfig::default(),
+            server: ServerConfig::default(),
+            offline_mode: false,
             whisper_path: String::new(),
             model_path: String::new(),
             language: String::new(),
@@ -179,25 +524,70 @@ impl Default for AppConfig {
 }
 
 impl AppConfig {
-    /// Migrate from v1 config format to v2
-    fn migrate_from_v1(&mut self) {
-        if self.version < 2 {
-            // Migrate legacy fields
-            if !self.whisper_path.is_empty() {
-                self.transcription.local.whisper_path = self.whisper_path.clone();
-            }
-            if !self.model_path.is_empty() {
-                self.transcription.local.model_path = self.model_path.clone();
-            }
-            if !self.language.is_empty() {
-                self.transcription.language = self.language.clone();
-            }
+    /// Migrate from v1 config format to v2. Safe to call on any version: for
+    /// v1 configs the legacy fields win, for v2 configs the new fields win and
+    /// legacy values only fill gaps. Legacy fields are cleared once consumed.
+    /// Returns a description of every change made.
+    fn migrate_from_v1(&mut self) -> Vec<String> {
+        let legacy_wins = self.version < 2;
+        let mut changes = Vec::new();
+
+        migrate_legacy_field(
+            &mut self.whisper_path,
+            &mut self.transcription.local.whisper_path,
+            "whisperPath",
+            "transcription.local.whisperPath",
+            legacy_wins,
+            &mut changes,
+        );
+        migrate_legacy_field(
+            &mut self.model_path,
+            &mut self.transcription.local.model_path,
+            "modelPath",
+            "transcription.local.modelPath",
+            legacy_wins,
+            &mut changes,
+        );
+        // An explicit empty language on a v2 config means "no hint", so it
+        // must not be treated as a gap for the legacy value to fill.
+        let had_language = self.transcription.language.is_some();
+        let mut language = self.transcription.language.take().unwrap_or_default();
+        if had_language && language.is_empty() && !legacy_wins && !self.language.is_empty() {
+            self.language.clear();
+            changes.push(
+                "Dropped legacy `language` in favor of the explicit no-hint `transcription.language`"
+                    .to_string(),
+            );
+        } else {
+            migrate_legacy_field(
+                &mut self.language,
+                &mut language,
+                "language",
+                "transcription.language",
+                legacy_wins,
+                &mut changes,
+            );
+        }
+        self.transcription.language = (had_language || !language.is_empty()).then_some(language);
+        migrate_legacy_field(
+            &mut self.default_model,
+            &mut self.ai.default_model,
+            "defaultModel",
+            "ai.defaultModel",
+            legacy_wins,
+            &mut changes,
+        );
+
+        // A bool can't express "unset", so only v1 configs carry this over.
+        if legacy_wins {
             self.ui.include_system_audio = self.include_system_audio;
-            if !self.default_model.is_empty() {
-                self.ai.default_model = self.default_model.clone();
-            }
+            changes.push("Moved legacy `includeSystemAudio` to `ui.includeSystemAudio`".to_string());
             self.version = 2;
+            changes.push("Upgraded config to version 2".to_string());
         }
+        self.include_system_audio = false;
+
+        changes
     }
 
     /// Get effective whisper path (with legacy fallback)
@@ -218,18 +608,58 @@ impl AppConfig {
         }
     }
 
-    /// Get effective language
-    fn effective_language(&self) -> &str {
-        if !self.transcription.language.is_empty() {
-            &self.transcription.language
-        } else if !self.language.is_empty() {
-            &self.language
-        } else {
-            "en"
+    /// Get effective language for `provider` (`auto` counts as local): its own
+    /// `language`, else `transcription.language`, else `"en"`. `None` means no
+    /// language hint should be sent.
+    fn effective_language(&self, provider: TranscriptionProvider) -> Option<&str> {
+        let provider_language = match provider {
+            TranscriptionProvider::Local | TranscriptionProvider::Auto => {
+                &self.transcription.local.language
+            }
+            TranscriptionProvider::OpenAICompatible => &self.transcription.openai_compatible.language,
+        };
+        let language = provider_language.as_deref().or(self.transcription.language.as_deref());
+        match language.map(str::trim) {
+            Some("") => None,
+            Some(language) => Some(language),
+            None if !self.language.is_empty() => Some(&self.language),
+            None => Some("en"),
         }
     }
 }
 
+/// Move a legacy root-level value into its v2 location, recording what happened.
+fn migrate_legacy_field(
+    legacy: &mut String,
+    current: &mut String,
+    legacy_name: &str,
+    target_name: &str,
+    legacy_wins: bool,
+    changes: &mut Vec<String>,
+) {
+    if legacy.is_empty() {
+        return;
+    }
+    let value = std::mem::take(legacy);
+    if value == *current {
+        changes.push(format!("Cleared duplicate legacy `{legacy_name}`"));
+    } else if legacy_wins || current.is_empty() {
+        changes.push(format!("Moved legacy `{legacy_name}` to `{target_name}`"));
+        *current = value;
+    } else {
+        changes.push(format!(
+            "Dropped legacy `{legacy_name}` in favor of `{target_name}`"
+        ));
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ConfigRepairReport {
+    changes: Vec<String>,
+    config: AppConfig,
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 struct ActionItem {
@@ -242,6 +672,44 @@ struct ActionItem {
     context: Option<String>,
 }
 
+/// A decision recorded in a meeting, as extracted by `extract_decisions`.
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct Decision {
+    decision: String,
+    #[serde(default)]
+    rationale: Option<String>,
+    #[serde(default)]
+    owner: Option<String>,
+}
+
+/// A question raised in a meeting, as extracted by `extract_questions`.
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct Question {
+    question: String,
+    #[serde(default)]
+    asked_by: Option<String>,
+    #[serde(default)]
+    answered: bool,
+    #[serde(default)]
+    answer: Option<String>,
+}
+
+/// A topic span of the transcript, as char offsets into `MeetingRecord::transcript`.
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct TopicChapter {
+    title: String,
+    start_char: usize,
+    end_char: usize,
+    /// Filled in when the transcript has segment timings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    start_ms: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    end_ms: Option<u64>,
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 struct MeetingRecord {
@@ -254,1372 +722,9967 @@ struct MeetingRecord {
     action_items: Vec<ActionItem>,
     created_at: String,
     updated_at: String,
+    /// Attached recording, relative to the app data dir (e.g. `audio/<id>.wav`).
+    #[serde(default)]
+    audio_path: Option<String>,
+    #[serde(default)]
+    chapters: Vec<TopicChapter>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    decisions: Vec<Decision>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    questions: Vec<Question>,
+    /// Timed segments covering `transcript`, when the provider reported them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    segments: Vec<TranscriptSegment>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<String>,
+    /// Provider and model that produced the latest transcript.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    transcription_provider: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    transcription_model: Option<String>,
+    /// Previous transcripts, oldest first, kept when a meeting is re-transcribed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    transcript_history: Vec<TranscriptRevision>,
+    /// Previous summaries, oldest first, kept when summaries are regenerated in bulk.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    summary_history: Vec<SummaryRevision>,
+    /// Paragraphed copy of `transcript` from `paragraphize_transcript`; only
+    /// used while `formatted_source_hash` still matches the transcript.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    formatted_transcript: Option<String>,
+    /// `transcript_hash` of the transcript `formatted_transcript` was made from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    formatted_source_hash: Option<String>,
+    /// `content_hash` of the record as last saved; refreshed on every write.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    content_hash: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct TranscriptRevision {
+    transcript: String,
+    replaced_at: String,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct SummaryRevision {
+    summary: String,
+    replaced_at: String,
 }
 
 // Streaming session state
 struct StreamingSession {
-    chunks: Vec<(u32, String)>, // (index, transcript)
+    chunks: Vec<StreamedChunk>,
     provider: TranscriptionProvider,
+    started_at: Instant,
+    /// Bumped on every chunk; sessions idle too long are swept.
+    last_activity: Instant,
+    /// Chunks that errored and have not been transcribed since.
+    failed_indices: BTreeSet<u32>,
+    live_summary: Option<LiveSummary>,
+}
+
+/// Rolling summary settings for a session, set by `start_live_summary`.
+struct LiveSummary {
+    meeting_id: String,
+    model: String,
+    every_chunks: usize,
+    /// Chunk count the last summary run started from.
+    summarized_chunks: usize,
+    last_started: Option<Instant>,
+    running: bool,
+}
+
+impl LiveSummary {
+    fn due(&self, chunk_count: usize) -> bool {
+        !self.running
+            && chunk_count >= self.summarized_chunks + self.every_chunks
+            && self
+                .last_started
+                .is_none_or(|started| started.elapsed() >= LIVE_SUMMARY_MIN_INTERVAL)
+    }
+}
+
+#[derive(Clone)]
+struct StreamedChunk {
+    index: u32,
+    text: String,
+    avg_confidence: Option<f32>,
+    low_confidence: bool,
 }
 
 struct AppState {
     streaming_sessions: Mutex<HashMap<String, StreamingSession>>,
+    ai_limiter: Arc<AiLimiter>,
+    /// Serializes local whisper runs; each process loads the full model, so
+    /// parallel inferences mostly just multiply memory use.
+    whisper_queue: Arc<WhisperQueue>,
+    /// Running summary, enhance, clean and whisper processes, for `cancel_all_jobs`.
+    child_jobs: Arc<ChildJobs>,
+    remote_usage: Mutex<UsageStats>,
+    /// Queued and running action-item extractions by meeting id.
+    action_item_jobs: Mutex<HashMap<String, ActionItemJob>>,
+    /// Cancel flag of the running `batch_regenerate_summaries`, if any.
+    summary_batch: Mutex<Option<Arc<AtomicBool>>>,
+    http_server: Mutex<Option<HttpServer>>,
+    logging: OnceLock<LogHandle>,
+    /// Stop flag of the running `start_log_tail` poller, if any.
+    log_tail: Mutex<Option<Arc<AtomicBool>>>,
+    last_meetings_write: Mutex<Option<MeetingsWrite>>,
+    /// Latest `save_meetings` list waiting out `ui.saveDebounceMs`.
+    pending_meetings: Mutex<Option<Vec<MeetingRecord>>>,
 }
 
-// ============================================================================
-// Transcription Commands
-// ============================================================================
+/// Fingerprint of the last meetings.json we wrote, used to skip identical saves.
+struct MeetingsWrite {
+    hash: u64,
+    modified: Option<SystemTime>,
+}
 
-#[tauri::command]
-async fn transcribe_audio(
-    app: tauri::AppHandle,
-    audio_base64: String,
-    language: Option<String>,
-    provider_override: Option<String>,
-) -> Result<TranscribeResponse, String> {
-    let config = load_config(app.clone()).await?;
-    
-    // Determine which provider to use
-    let provider = match provider_override.as_deref() {
-        Some("local") => TranscriptionProvider::Local,
-        Some("openai-compatible") => TranscriptionProvider::OpenAICompatible,
-        Some("auto") | None => config.transcription.provider,
-        Some(other) => return Err(format!("Unknown provider: {}", other)),
-    };
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SaveMeetingsResult {
+    written: bool,
+    /// Held for a coalesced write instead of being written now.
+    queued: bool,
+}
 
-    match provider {
-        TranscriptionProvider::Local | TranscriptionProvider::Auto => {
-            transcribe_local(config, audio_base64, language).await
+/// Bounds how many node-based AI processes run at once so that summary,
+/// enhance, clean and actions requests queue instead of stampeding the SDK.
+#[derive(Default)]
+struct AiLimiter {
+    running: Mutex<usize>,
+    slot_freed: Condvar,
+}
+
+struct AiPermit {
+    limiter: Arc<AiLimiter>,
+}
+
+impl AiLimiter {
+    /// Block until a slot is free. `on_queued` runs once if the caller has to wait.
+    fn acquire(self: &Arc<Self>, max: usize, on_queued: impl FnOnce()) -> AiPermit {
+        let mut running = self.running.lock().unwrap_or_else(|err| err.into_inner());
+        if *running >= max {
+            on_queued();
         }
-        TranscriptionProvider::OpenAICompatible => {
-            transcribe_openai_compatible(config, audio_base64, language).await
+        while *running >= max {
+            running = self
+                .slot_freed
+                .wait(running)
+                .unwrap_or_else(|err| err.into_inner());
+        }
+        *running += 1;
+        AiPermit {
+            limiter: Arc::clone(self),
         }
     }
 }
 
-async fn transcribe_local(
-    config: AppConfig,
-    audio_base64: String,
-    language: Option<String>,
-) -> Result<TranscribeResponse, String> {
-    tauri::async_runtime::spawn_blocking(move || {
-        let whisper_path = resolve_whisper_path(config.effective_whisper_path())?;
-        let model_path = resolve_model_path_with_selection(
-            config.effective_model_path(),
-            &config.transcription.local.model_name,
-        )?;
-
-        let audio_bytes = base64::engine::general_purpose::STANDARD
-            .decode(audio_base64)
-            .map_err(|err| format!("Failed to decode audio: {err}"))?;
-
-        let temp_dir = std::env::temp_dir().join("voxii");
-        fs::create_dir_all(&temp_dir)
-            .map_err(|err| format!("Failed to create temp dir: {err}"))?;
-
-        let id = uuid::Uuid::new_v4().to_string();
-        let wav_path = temp_dir.join(format!("{id}.wav"));
-        let out_base = temp_dir.join(format!("{id}_out"));
+impl Drop for AiPermit {
+    fn drop(&mut self) {
+        let mut running = self
+            .limiter
+            .running
+            .lock()
+            .unwrap_or_else(|err| err.into_inner());
+        *running = running.saturating_sub(1);
+        self.limiter.slot_freed.notify_one();
+    }
+}
 
-        fs::write(&wav_path, audio_bytes)
-            .map_err(|err| format!("Failed to write audio file: {err}"))?;
+/// Take an AI process slot, emitting `queued_event` if the request has to wait.
+/// Every AI feature passes through here, so this is where offline mode stops them.
+fn acquire_ai_permit(
+    app: &tauri::AppHandle,
+    queued_event: &str,
+    payload: serde_json::Value,
+) -> Result<AiPermit, String> {
+    let config = load_config_sync(app).ok();
+    if let Some(config) = &config {
+        ensure_online(config)?;
+    }
+    let max = config
+        .map(|config| config.ai.max_concurrency)
+        .unwrap_or_else(default_ai_max_concurrency)
+        .max(1) as usize;
+    let limiter = Arc::clone(&app.state::<AppState>().ai_limiter);
+    Ok(limiter.acquire(max, || {
+        let _ = app.emit(queued_event, payload);
+    }))
+}
 
-        let mut cmd = Command::new(&whisper_path);
-        cmd.arg("-m")
-            .arg(&model_path)
-            .arg("-f")
-            .arg(&wav_path)
-            .arg("-otxt")
-            .arg("-of")
-            .arg(&out_base)
-            .arg("--best-of")
-            .arg(config.transcription.local.best_of.to_string())
-            .arg("--beam-size")
-            .arg(config.transcription.local.beam_size.to_string());
-
-        let language = language.unwrap_or_else(|| config.effective_language().to_string());
-        if !language.trim().is_empty() {
-            cmd.arg("-l").arg(language.trim());
-        }
-
-        let command_string = format!(
-            "\"{}\" -m \"{}\" -f \"{}\" -otxt -of \"{}\"",
-            whisper_path.display(),
-            model_path.display(),
-            wav_path.display(),
-            out_base.display()
-        );
+/// First-come, first-served turns at whisper (a ticket lock), so a waiting
+/// run knows how many are ahead of it.
+#[derive(Default)]
+struct WhisperQueue {
+    tickets: Mutex<WhisperTickets>,
+    turn_done: Condvar,
+}
 
-        let output = cmd
-            .output()
-            .map_err(|err| format!("Failed to run whisper: {err}"))?;
+#[derive(Default)]
+struct WhisperTickets {
+    next: u64,
+    serving: u64,
+}
 
-        let stdout = String::from_utf8_lossy(&output.stdout).to_string();
-        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+struct WhisperTurn<'a> {
+    queue: &'a WhisperQueue,
+}
 
-        if !output.status.success() {
-            return Err(format!(
-                "Whisper failed (code {}).\nCommand: {}\nstdout: {}\nstderr: {}",
-                output.status.code().unwrap_or(-1),
-                command_string,
-                stdout,
-                stderr
-            ));
+impl WhisperQueue {
+    /// Block until it's this caller's turn. `on_wait` runs with the number of
+    /// runs ahead (the running one included) whenever that number changes.
+    fn acquire(&self, mut on_wait: impl FnMut(u64)) -> WhisperTurn<'_> {
+        let mut tickets = self.tickets.lock().unwrap_or_else(PoisonError::into_inner);
+        let ticket = tickets.next;
+        tickets.next += 1;
+        let mut reported = 0;
+        while tickets.serving != ticket {
+            let ahead = ticket - tickets.serving;
+            if ahead != reported {
+                on_wait(ahead);
+                reported = ahead;
+            }
+            tickets = self.turn_done.wait(tickets).unwrap_or_else(PoisonError::into_inner);
         }
+        WhisperTurn { queue: self }
+    }
+}
 
-        let transcript_path = out_base.with_extension("txt");
-        let transcript = fs::read_to_string(&transcript_path)
-            .map_err(|err| format!("Failed to read transcript: {err}"))?;
+impl Drop for WhisperTurn<'_> {
+    fn drop(&mut self) {
+        let mut tickets = self.queue.tickets.lock().unwrap_or_else(PoisonError::into_inner);
+        tickets.serving += 1;
+        self.queue.turn_done.notify_all();
+    }
+}
 
-        Ok(TranscribeResponse {
-            transcript,
-            stdout,
-            stderr,
-            command: command_string,
-            provider: "local".to_string(),
-        })
-    })
-    .await
-    .map_err(|err| format!("Failed to run transcription task: {err}"))?
+#[derive(Clone, Copy)]
+enum JobKind {
+    Summary,
+    Enhance,
+    Clean,
+    Whisper,
 }
 
-async fn transcribe_openai_compatible(
-    config: AppConfig,
-    audio_base64: String,
-    language: Option<String>,
-) -> Result<TranscribeResponse, String> {
-    let openai_config = &config.transcription.openai_compatible;
-    
-    if openai_config.api_key.is_empty() {
-        return Err("OpenAI-compatible API key not configured".to_string());
-    }
-    if openai_config.endpoint.is_empty() {
-        return Err("OpenAI-compatible endpoint not configured".to_string());
+/// Child processes `cancel_all_jobs` can kill, by run id. The job keeps the
+/// child's pipes and takes the child back only to wait on it once they
+/// close; finding it gone means it was killed.
+#[derive(Default)]
+struct ChildJobs(Mutex<HashMap<String, (JobKind, Child)>>);
+
+impl ChildJobs {
+    /// Register a spawned child whose pipes were already taken.
+    fn track(&self, kind: JobKind, child: Child) -> String {
+        let run_id = uuid::Uuid::new_v4().to_string();
+        self.0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(run_id.clone(), (kind, child));
+        run_id
     }
 
-    // Decode audio
-    let audio_bytes = base64::engine::general_purpose::STANDARD
-        .decode(&audio_base64)
-        .map_err(|err| format!("Failed to decode audio: {err}"))?;
-
-    // Build multipart form
-    let file_part = reqwest::multipart::Part::bytes(audio_bytes)
-        .file_name("audio.wav")
-        .mime_str("audio/wav")
-        .map_err(|err| format!("Failed to create multipart: {err}"))?;
+    /// The child back for waiting, or `None` when it was killed.
+    fn untrack(&self, run_id: &str) -> Option<Child> {
+        self.0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(run_id)
+            .map(|(_, child)| child)
+    }
 
-    let mut form = reqwest::multipart::Form::new()
-        .part("file", file_part)
-        .text("model", openai_config.model.clone());
+    /// `Command::output` that `cancel_all_jobs` can interrupt; `Ok(None)` when
+    /// it did.
+    fn output(&self, kind: JobKind, cmd: &mut Command) -> std::io::Result<Option<std::process::Output>> {
+        let mut child = cmd
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+        let stdout_pipe = child.stdout.take();
+        let stderr_pipe = child.stderr.take();
+        let run_id = self.track(kind, child);
+
+        let stderr_reader = std::thread::spawn(move || {
+            let mut stderr = Vec::new();
+            if let Some(mut pipe) = stderr_pipe {
+                let _ = pipe.read_to_end(&mut stderr);
+            }
+            stderr
+        });
+        let mut stdout = Vec::new();
+        if let Some(mut pipe) = stdout_pipe {
+            let _ = pipe.read_to_end(&mut stdout);
+        }
+        let stderr = stderr_reader.join().unwrap_or_default();
 
-    let language = language.unwrap_or_else(|| config.effective_language().to_string());
-    if !language.trim().is_empty() {
-        form = form.text("language", language);
+        let Some(mut child) = self.untrack(&run_id) else {
+            return Ok(None);
+        };
+        let status = child.wait()?;
+        Ok(Some(std::process::Output { status, stdout, stderr }))
     }
 
-    // Make request
-    let client = reqwest::Client::new();
-    let response = client
-        .post(&openai_config.endpoint)
-        .header("Authorization", format!("Bearer {}", openai_config.api_key))
-        .multipart(form)
-        .send()
-        .await
-        .map_err(|err| format!("Failed to call transcription API: {err}"))?;
+    /// Kill every tracked child; returns the kind of each.
+    fn kill_all(&self) -> Vec<JobKind> {
+        let jobs: Vec<(JobKind, Child)> = self
+            .0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .drain()
+            .map(|(_, job)| job)
+            .collect();
+        jobs.into_iter()
+            .map(|(kind, mut child)| {
+                // Already exited is fine; the job finds it gone either way.
+                let _ = child.kill();
+                let _ = child.wait();
+                kind
+            })
+            .collect()
+    }
+}
 
-    if !response.status().is_success() {
-        let status = response.status();
-        let body = response.text().await.unwrap_or_default();
-        return Err(format!(
-            "Transcription API failed ({}): {}",
-            status, body
-        ));
+/// `transcribe_local`'s way into the whisper queue. With `events`, the wait
+/// is reported as `transcription-queued` (with `position`, runs ahead) and
+/// `transcription-started` once it runs, each carrying the payload's fields.
+/// Internal runs (warmup, model tests) stay silent.
+struct WhisperSlot {
+    queue: Arc<WhisperQueue>,
+    jobs: Arc<ChildJobs>,
+    events: Option<(tauri::AppHandle, serde_json::Value)>,
+}
+
+impl WhisperSlot {
+    fn silent(app: &tauri::AppHandle) -> Self {
+        Self {
+            queue: app.state::<AppState>().whisper_queue.clone(),
+            jobs: app.state::<AppState>().child_jobs.clone(),
+            events: None,
+        }
     }
 
-    // Parse response - OpenAI returns { "text": "..." }
-    let result: serde_json::Value = response
-        .json()
-        .await
-        .map_err(|err| format!("Failed to parse API response: {err}"))?;
+    fn reporting(app: &tauri::AppHandle, payload: serde_json::Value) -> Self {
+        Self {
+            queue: app.state::<AppState>().whisper_queue.clone(),
+            jobs: app.state::<AppState>().child_jobs.clone(),
+            events: Some((app.clone(), payload)),
+        }
+    }
 
-    let transcript = result
-        .get("text")
-        .and_then(|v| v.as_str())
-        .unwrap_or("")
-        .to_string();
+    fn take_turn(&self) -> WhisperTurn<'_> {
+        let emit = |event: &str, position: Option<u64>| {
+            let Some((app, payload)) = &self.events else {
+                return;
+            };
+            let mut payload = payload.clone();
+            if let (Some(object), Some(position)) = (payload.as_object_mut(), position) {
+                object.insert("position".to_string(), position.into());
+            }
+            let _ = app.emit(event, payload);
+        };
+        let turn = self.queue.acquire(|ahead| emit("transcription-queued", Some(ahead)));
+        emit("transcription-started", None);
+        turn
+    }
+}
 
-    Ok(TranscribeResponse {
-        transcript,
-        stdout: String::new(),
-        stderr: String::new(),
-        command: format!("POST {}", openai_config.endpoint),
-        provider: "openai-compatible".to_string(),
-    })
+const OFFLINE_MODE_ERROR: &str = "Offline mode is enabled; network features are unavailable";
+
+fn ensure_online(config: &AppConfig) -> Result<(), String> {
+    if config.offline_mode {
+        return Err(OFFLINE_MODE_ERROR.to_string());
+    }
+    Ok(())
 }
 
 // ============================================================================
-// Streaming Transcription Commands
+// Transcription Commands
 // ============================================================================
 
+/// `languages` is for meetings that switch language: with more than one entry
+/// the audio is transcribed with `auto`. whisper detects once per run, so a
+/// local 16-bit WAV is transcribed in pieces of about `LANGUAGE_CHUNK_MS`, cut
+/// at pauses, and each segment is tagged with its piece's language; the
+/// response's `language` is the one spoken longest. Other audio and remote
+/// providers get one detection for the whole call.
 #[tauri::command]
-async fn start_streaming_session(
-    state: State<'_, AppState>,
-    provider: Option<String>,
-) -> Result<String, String> {
-    let session_id = uuid::Uuid::new_v4().to_string();
-    let provider_enum = match provider.as_deref() {
-        Some("local") => TranscriptionProvider::Local,
-        Some("openai-compatible") => TranscriptionProvider::OpenAICompatible,
-        _ => TranscriptionProvider::Local, // Default to local for streaming
-    };
-
-    let session = StreamingSession {
-        chunks: Vec::new(),
-        provider: provider_enum,
-    };
+async fn transcribe_audio(
+    app: tauri::AppHandle,
+    audio_base64: String,
+    language: Option<String>,
+    languages: Option<Vec<String>>,
+    provider_override: Option<String>,
+) -> Result<TranscribeResponse, String> {
+    let start = Instant::now();
+    let result = async {
+        let config = load_config(app.clone()).await?;
+        check_provider_configured(&app, &config, provider_override.as_deref())?;
+        check_audio_payload_size(&config, &audio_base64)?;
+        let audio = decode_audio_input(&audio_base64)?;
+        drop(audio_base64);
+
+        let languages: Vec<String> = languages
+            .unwrap_or_default()
+            .into_iter()
+            .map(|code| code.trim().to_lowercase())
+            .filter(|code| !code.is_empty())
+            .collect();
+        let (language, multi_language) = match languages.as_slice() {
+            [] => (language, false),
+            [single] => (Some(single.clone()), false),
+            _ => (Some("auto".to_string()), true),
+        };
 
-    state
-        .streaming_sessions
-        .lock()
-        .map_err(|_| "Failed to acquire lock")?
-        .insert(session_id.clone(), session);
+        let local = matches!(
+            select_provider(&config, provider_override.as_deref())?,
+            TranscriptionProvider::Local | TranscriptionProvider::Auto
+        );
+        let pieces = (multi_language && local && matches!(audio.format, AudioFormat::Wav))
+            .then(|| parse_pcm16_wav(&audio.bytes))
+            .flatten();
+        let response = match pieces {
+            Some(wav) => {
+                drop(audio);
+                let response =
+                    transcribe_language_chunks(&app, &wav, provider_override.as_deref()).await?;
+                polish_transcription(&app, &config, response).await?
+            }
+            None => {
+                finish_transcription(&app, &config, audio, language, provider_override.as_deref())
+                    .await?
+            }
+        };
+        if multi_language {
+            let detected = response
+                .segments
+                .iter()
+                .filter_map(|segment| segment.language.as_deref())
+                .chain(response.language.as_deref());
+            for detected in detected.collect::<std::collections::BTreeSet<_>>() {
+                if !languages.iter().any(|code| code == detected) {
+                    tracing::warn!(detected, ?languages, "detected language not in requested set");
+                }
+            }
+        }
+        Ok(response)
+    }
+    .await;
+    report_transcription(&app, start, "transcribe_audio", &result);
+    result
+}
 
-    Ok(session_id)
+/// Transcribe a recording straight from disk, avoiding the IPC size limits
+/// of base64 payloads for long meetings.
+#[tauri::command]
+async fn transcribe_file(
+    app: tauri::AppHandle,
+    path: String,
+    language: Option<String>,
+    provider_override: Option<String>,
+) -> Result<TranscribeResponse, String> {
+    let start = Instant::now();
+    let result = async {
+        let config = load_config(app.clone()).await?;
+        check_provider_configured(&app, &config, provider_override.as_deref())?;
+        let bytes = tauri::async_runtime::spawn_blocking(move || fs::read(&path))
+            .await
+            .map_err(|err| format!("Failed to read audio task: {err}"))?
+            .map_err(|err| format!("Failed to read audio file: {err}"))?;
+        let audio = AudioInput {
+            format: AudioFormat::sniff(&bytes),
+            bytes,
+        };
+        finish_transcription(&app, &config, audio, language, provider_override.as_deref()).await
+    }
+    .await;
+    report_transcription(&app, start, "transcribe_file", &result);
+    result
 }
 
+/// Transcribe each channel of a multi-channel 16-bit WAV on its own (e.g. an
+/// interview with one mic per channel) and interleave the results by time as
+/// `Speaker N:` turns, N being the channel number. Mono or other audio is
+/// transcribed normally.
 #[tauri::command]
-async fn transcribe_chunk(
+async fn transcribe_multichannel(
     app: tauri::AppHandle,
-    state: State<'_, AppState>,
-    session_id: String,
     audio_base64: String,
-    chunk_index: u32,
-) -> Result<(), String> {
-    let config = load_config(app.clone()).await?;
-    
-    // Get provider from session
-    let provider = {
-        let sessions = state.streaming_sessions.lock().map_err(|_| "Lock failed")?;
-        sessions
-            .get(&session_id)
-            .map(|s| s.provider)
-            .ok_or("Session not found")?
+    language: Option<String>,
+) -> Result<TranscribeResponse, String> {
+    let start = Instant::now();
+    let result = async {
+        let config = load_config(app.clone()).await?;
+        check_provider_configured(&app, &config, None)?;
+        check_audio_payload_size(&config, &audio_base64)?;
+        let audio = decode_audio_input(&audio_base64)?;
+        drop(audio_base64);
+
+        let wav = matches!(audio.format, AudioFormat::Wav)
+            .then(|| parse_pcm16_wav(&audio.bytes))
+            .flatten()
+            .filter(|wav| wav.channels > 1);
+        let Some(wav) = wav else {
+            return finish_transcription(&app, &config, audio, language, None).await;
+        };
+        drop(audio);
+
+        let channels = wav.channels as usize;
+        let mut responses = Vec::new();
+        for channel in 0..channels {
+            let samples = || wav.samples.iter().skip(channel).step_by(channels);
+            if samples().all(|sample| *sample == 0) {
+                tracing::info!(channel = channel + 1, "skipping silent channel");
+                continue;
+            }
+            let pcm: Vec<u8> = samples().flat_map(|sample| sample.to_le_bytes()).collect();
+            let audio = AudioInput {
+                bytes: pcm16_to_wav(&pcm, wav.sample_rate, 1)?,
+                format: AudioFormat::Wav,
+            };
+            let tag = format!("channel{}", channel + 1);
+            let response =
+                transcribe_with_settings(&app, audio, language.clone(), None, Some(&tag)).await?;
+            responses.push((channel + 1, response));
+        }
+        if responses.is_empty() {
+            return Err("Every channel is silent".to_string());
+        }
+        Ok(interleave_channels(responses))
+    }
+    .await;
+    report_transcription(&app, start, "transcribe_multichannel", &result);
+    result
+}
+
+/// Merge per-channel transcripts into one speaker-labeled transcript ordered
+/// by segment start. Consecutive segments from the same channel become one
+/// turn; a channel without timings is kept as a single turn at the start.
+fn interleave_channels(responses: Vec<(usize, TranscribeResponse)>) -> TranscribeResponse {
+    let mut timed: Vec<(usize, TranscriptSegment)> = Vec::new();
+    for (speaker, response) in &responses {
+        if response.segments.is_empty() {
+            timed.push((
+                *speaker,
+                TranscriptSegment {
+                    start_ms: 0,
+                    end_ms: 0,
+                    text: response.transcript.clone(),
+                    confidence: None,
+                    language: response.language.clone(),
+                },
+            ));
+        } else {
+            timed.extend(response.segments.iter().map(|segment| {
+                let language = segment.language.clone().or_else(|| response.language.clone());
+                (*speaker, TranscriptSegment { language, ..segment.clone() })
+            }));
+        }
+    }
+    timed.sort_by_key(|(speaker, segment)| (segment.start_ms, *speaker));
+
+    let mut turns: Vec<(usize, TranscriptSegment)> = Vec::new();
+    for (speaker, segment) in timed {
+        let text = segment.text.trim();
+        if text.is_empty() {
+            continue;
+        }
+        match turns.last_mut() {
+            Some((last, turn)) if *last == speaker => {
+                turn.text.push(' ');
+                turn.text.push_str(text);
+                turn.end_ms = turn.end_ms.max(segment.end_ms);
+                turn.confidence = match (turn.confidence, segment.confidence) {
+                    (Some(a), Some(b)) => Some(a.min(b)),
+                    (a, b) => a.or(b),
+                };
+            }
+            _ => turns.push((
+                speaker,
+                TranscriptSegment {
+                    text: text.to_string(),
+                    ..segment
+                },
+            )),
+        }
+    }
+
+    let segments: Vec<TranscriptSegment> = turns
+        .into_iter()
+        .map(|(speaker, turn)| TranscriptSegment {
+            text: format!("Speaker {speaker}: {}", turn.text),
+            ..turn
+        })
+        .collect();
+    let transcript = segments
+        .iter()
+        .map(|segment| segment.text.as_str())
+        .collect::<Vec<_>>()
+        .join("\n");
+    let join = |field: fn(&TranscribeResponse) -> &str| {
+        responses
+            .iter()
+            .map(|(_, response)| field(response))
+            .filter(|value| !value.trim().is_empty())
+            .collect::<Vec<_>>()
+            .join("\n")
     };
+    let first = &responses[0].1;
+    TranscribeResponse {
+        transcript,
+        stdout: join(|response| &response.stdout),
+        stderr: join(|response| &response.stderr),
+        command: join(|response| &response.command),
+        provider: first.provider.clone(),
+        model: first.model.clone(),
+        segments,
+        raw_transcript: None,
+        punctuation_restored: false,
+        language: responses.iter().find_map(|(_, response)| response.language.clone()),
+        audio_path: None,
+        model_fallback_from: responses
+            .iter()
+            .find_map(|(_, response)| response.model_fallback_from.clone()),
+        words: {
+            let mut words: Vec<WordTiming> = responses
+                .iter()
+                .flat_map(|(_, response)| response.words.iter().cloned())
+                .collect();
+            words.sort_by_key(|word| word.start_ms);
+            words
+        },
+        words_interpolated: responses.iter().any(|(_, response)| response.words_interpolated),
+        output_bytes: responses.iter().map(|(_, response)| response.output_bytes).sum(),
+    }
+}
 
-    // Transcribe the chunk
-    let result = match provider {
-        TranscriptionProvider::Local | TranscriptionProvider::Auto => {
-            transcribe_local(config, audio_base64, None).await
+/// Length `transcribe_language_chunks` aims for; whisper detects the
+/// language from the first 30 seconds of each run.
+const LANGUAGE_CHUNK_MS: u64 = 30_000;
+
+/// Transcribe `wav` with `auto` in pieces cut at pauses (see
+/// `chunk_boundaries`), so each piece gets its own language detection.
+async fn transcribe_language_chunks(
+    app: &tauri::AppHandle,
+    wav: &Pcm16Wav,
+    provider_override: Option<&str>,
+) -> Result<TranscribeResponse, String> {
+    let channels = wav.channels as usize;
+    let frames = (wav.samples.len() / channels) as u64;
+    let sample_at = |ms: u64| {
+        (ms.saturating_mul(wav.sample_rate as u64) / 1000).min(frames) as usize * channels
+    };
+    let layout = chunk_boundaries(wav, LANGUAGE_CHUNK_MS);
+    let starts: Vec<u64> = std::iter::once(0)
+        .chain(layout.boundaries.iter().map(|boundary| boundary.ms))
+        .collect();
+
+    let mut pieces = Vec::with_capacity(starts.len());
+    for (index, &start_ms) in starts.iter().enumerate() {
+        let end_ms = starts.get(index + 1).copied().unwrap_or(layout.duration_ms);
+        let end = match starts.get(index + 1) {
+            Some(&next) => sample_at(next),
+            None => wav.samples.len(),
+        };
+        let pcm: Vec<u8> = wav.samples[sample_at(start_ms)..end]
+            .iter()
+            .flat_map(|sample| sample.to_le_bytes())
+            .collect();
+        let audio = AudioInput {
+            bytes: pcm16_to_wav(&pcm, wav.sample_rate, wav.channels)?,
+            format: AudioFormat::Wav,
+        };
+        let tag = format!("part{}", index + 1);
+        let language = Some("auto".to_string());
+        let response =
+            transcribe_with_settings(app, audio, language, provider_override, Some(&tag)).await?;
+        pieces.push((start_ms, end_ms, response));
+    }
+    Ok(join_language_chunks(pieces))
+}
+
+/// Stitch per-piece responses (with their start and end ms) back together,
+/// shifting timings and tagging each segment with its piece's language. A
+/// piece without timings becomes one segment spanning the piece.
+fn join_language_chunks(pieces: Vec<(u64, u64, TranscribeResponse)>) -> TranscribeResponse {
+    let mut segments = Vec::new();
+    let mut words = Vec::new();
+    let mut spoken_ms: HashMap<String, u64> = HashMap::new();
+    for (start_ms, end_ms, response) in &pieces {
+        let language = response.language.clone();
+        if let Some(language) = &language {
+            *spoken_ms.entry(language.clone()).or_default() += end_ms - start_ms;
         }
-        TranscriptionProvider::OpenAICompatible => {
-            transcribe_openai_compatible(config, audio_base64, None).await
+        if response.segments.is_empty() {
+            let text = response.transcript.trim();
+            if !text.is_empty() {
+                segments.push(TranscriptSegment {
+                    start_ms: *start_ms,
+                    end_ms: *end_ms,
+                    text: text.to_string(),
+                    confidence: None,
+                    language,
+                });
+            }
+        } else {
+            segments.extend(response.segments.iter().map(|segment| TranscriptSegment {
+                start_ms: segment.start_ms + start_ms,
+                end_ms: segment.end_ms + start_ms,
+                language: segment.language.clone().or_else(|| language.clone()),
+                ..segment.clone()
+            }));
         }
+        words.extend(response.words.iter().map(|word| WordTiming {
+            text: word.text.clone(),
+            start_ms: word.start_ms + start_ms,
+            end_ms: word.end_ms + start_ms,
+        }));
+    }
+
+    let join = |field: fn(&TranscribeResponse) -> &str| {
+        pieces
+            .iter()
+            .map(|(_, _, response)| field(response).trim())
+            .filter(|value| !value.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n")
     };
+    let first = &pieces[0].2;
+    TranscribeResponse {
+        transcript: join(|response| &response.transcript),
+        stdout: join(|response| &response.stdout),
+        stderr: join(|response| &response.stderr),
+        command: join(|response| &response.command),
+        provider: first.provider.clone(),
+        model: first.model.clone(),
+        segments,
+        raw_transcript: None,
+        punctuation_restored: false,
+        language: spoken_ms
+            .into_iter()
+            .max_by_key(|(_, ms)| *ms)
+            .map(|(language, _)| language),
+        audio_path: None,
+        model_fallback_from: pieces
+            .iter()
+            .find_map(|(_, _, response)| response.model_fallback_from.clone()),
+        words,
+        words_interpolated: pieces.iter().any(|(_, _, response)| response.words_interpolated),
+        output_bytes: pieces.iter().map(|(_, _, response)| response.output_bytes).sum(),
+    }
+}
 
-    match result {
-        Ok(response) => {
-            // Store chunk result
-            {
-                let mut sessions = state.streaming_sessions.lock().map_err(|_| "Lock failed")?;
-                if let Some(session) = sessions.get_mut(&session_id) {
-                    session.chunks.push((chunk_index, response.transcript.clone()));
-                }
+/// Transcribe, then apply `autoClean` or `restorePunctuation` when enabled.
+async fn finish_transcription(
+    app: &tauri::AppHandle,
+    config: &AppConfig,
+    audio: AudioInput,
+    language: Option<String>,
+    provider_override: Option<&str>,
+) -> Result<TranscribeResponse, String> {
+    let response =
+        transcribe_with_settings(app, audio, language, provider_override, None).await?;
+    polish_transcription(app, config, response).await
+}
+
+/// Apply `autoClean` or `restorePunctuation` to a finished transcription.
+async fn polish_transcription(
+    app: &tauri::AppHandle,
+    config: &AppConfig,
+    mut response: TranscribeResponse,
+) -> Result<TranscribeResponse, String> {
+    if config.transcription.auto_clean && !response.transcript.trim().is_empty() {
+        let app_handle = app.clone();
+        let raw = response.transcript.clone();
+        let model = config.ai.default_model.clone();
+        let cleaned =
+            tauri::async_runtime::spawn_blocking(move || clean_transcript_sync(app_handle, raw, model))
+                .await
+                .map_err(|err| format!("Failed to run cleanup task: {err}"))
+                .and_then(|result| result);
+        // Cleanup is a nicety; never lose the transcription over it.
+        match cleaned {
+            Ok(cleaned) => {
+                response.raw_transcript =
+                    Some(std::mem::replace(&mut response.transcript, cleaned));
+            }
+            Err(err) => tracing::warn!("auto-clean skipped: {err}"),
+        }
+    }
+    if response.raw_transcript.is_none()
+        && config.transcription.restore_punctuation
+        && looks_unpunctuated(&response.transcript)
+    {
+        let app_handle = app.clone();
+        let raw = response.transcript.clone();
+        let model = config.ai.default_model.clone();
+        let punctuated = tauri::async_runtime::spawn_blocking(move || {
+            run_clean_transcript(app_handle, raw, model, Some(PUNCTUATION_PROMPT.to_string()))
+        })
+        .await
+        .map_err(|err| format!("Failed to run punctuation task: {err}"))
+        .and_then(|result| result);
+        match punctuated {
+            Ok(punctuated) if !punctuated.trim().is_empty() => {
+                response.raw_transcript =
+                    Some(std::mem::replace(&mut response.transcript, punctuated));
+                response.punctuation_restored = true;
             }
+            Ok(_) => tracing::warn!("punctuation restore returned nothing"),
+            Err(err) => tracing::warn!("punctuation restore skipped: {err}"),
+        }
+    }
+    Ok(response)
+}
 
-            // Emit event to frontend
+const PUNCTUATION_PROMPT: &str = "You restore punctuation in speech-to-text transcripts. \
+Add sentence punctuation, capitalization and paragraph breaks. Do not add, remove, reorder \
+or reword anything. Return only the transcript.";
+
+/// Heuristic for whisper setups that emit lowercase run-on text: fewer than
+/// one sentence end per 40 words. Short snippets are left alone.
+fn looks_unpunctuated(text: &str) -> bool {
+    let words = text.split_whitespace().count();
+    if words < 20 {
+        return false;
+    }
+    let sentence_ends = text
+        .chars()
+        .filter(|c| matches!(c, '.' | '?' | '!' | '\u{3002}' | '\u{FF1F}' | '\u{FF01}'))
+        .count();
+    sentence_ends * 40 < words
+}
+
+/// Mirror the result as events so global listeners see one-shot transcriptions too.
+fn report_transcription(
+    app: &tauri::AppHandle,
+    start: Instant,
+    label: &str,
+    result: &Result<TranscribeResponse, String>,
+) {
+    match result {
+        Ok(response) => {
             let _ = app.emit(
-                "transcription-chunk",
+                "transcription-complete",
                 serde_json::json!({
-                    "sessionId": session_id,
-                    "chunkIndex": chunk_index,
-                    "text": response.transcript,
                     "provider": response.provider,
+                    "durationMs": start.elapsed().as_millis() as u64,
+                    "charCount": response.transcript.chars().count(),
+                    "model": response.model,
                 }),
             );
         }
         Err(err) => {
-            let _ = app.emit(
-                "transcription-error",
-                serde_json::json!({
-                    "sessionId": session_id,
-                    "chunkIndex": chunk_index,
-                    "error": err,
-                }),
-            );
-            return Err(err);
+            tracing::error!("{label} failed: {err}");
+            let _ = app.emit("transcription-failed", serde_json::json!({ "error": err }));
         }
     }
+}
 
+/// Reject base64 payloads whose decoded size would exceed `maxAudioBytes`
+/// before spending memory on decoding them.
+fn check_audio_payload_size(config: &AppConfig, audio_base64: &str) -> Result<(), String> {
+    let limit = config
+        .transcription
+        .max_audio_bytes
+        .unwrap_or(DEFAULT_MAX_AUDIO_BYTES);
+    let decoded_estimate = audio_base64.len() as u64 / 4 * 3;
+    if limit > 0 && decoded_estimate > limit {
+        return Err(format!(
+            "Recording too large ({} MB, limit {} MB); use file-based transcription instead",
+            decoded_estimate / 1_000_000,
+            limit / 1_000_000
+        ));
+    }
     Ok(())
 }
 
-#[tauri::command]
-async fn end_streaming_session(
-    state: State<'_, AppState>,
-    session_id: String,
-) -> Result<String, String> {
-    let session = state
-        .streaming_sessions
-        .lock()
-        .map_err(|_| "Failed to acquire lock")?
-        .remove(&session_id)
-        .ok_or("Session not found")?;
+/// Transcribe with the current config, optionally forcing a provider. `tag`
+/// (e.g. a meeting id) is worked into local temp file names.
+async fn transcribe_with_settings(
+    app: &tauri::AppHandle,
+    audio: AudioInput,
+    language: Option<String>,
+    provider_override: Option<&str>,
+    tag: Option<&str>,
+) -> Result<TranscribeResponse, String> {
+    let config = load_config(app.clone()).await?;
+    let provider = select_provider(&config, provider_override)?;
+    tracing::info!(?provider, "transcription requested");
 
-    // Sort chunks by index and merge
-    let mut chunks = session.chunks;
-    chunks.sort_by_key(|(idx, _)| *idx);
+    match provider {
+        TranscriptionProvider::Local | TranscriptionProvider::Auto => {
+            let whisper = WhisperSlot::reporting(app, serde_json::json!({ "tag": tag }));
+            let models_root = models_root(app, &config)?;
+            let keep_audio_dir = keep_audio_dir(app, &config)?;
+            let tag = tag.map(str::to_string);
+            transcribe_local(whisper, models_root, keep_audio_dir, config, audio, language, tag).await
+        }
+        TranscriptionProvider::OpenAICompatible => {
+            transcribe_openai_compatible(&app.state::<AppState>(), config, audio, language, None)
+                .await
+        }
+    }
+}
 
-    let merged = chunks
-        .into_iter()
-        .map(|(_, text)| text)
-        .collect::<Vec<_>>()
-        .join(" ");
+/// In offline mode the configured provider always resolves to local, and
+/// explicitly asking for the remote one is an error.
+fn select_provider(
+    config: &AppConfig,
+    provider_override: Option<&str>,
+) -> Result<TranscriptionProvider, String> {
+    let provider = match provider_override {
+        Some("local") => TranscriptionProvider::Local,
+        Some("openai-compatible") => {
+            ensure_online(config)?;
+            TranscriptionProvider::OpenAICompatible
+        }
+        Some("auto") | None if config.offline_mode => TranscriptionProvider::Local,
+        Some("auto") | None => config.transcription.provider,
+        Some(other) => return Err(format!("Unknown provider: {}", other)),
+    };
+    Ok(provider)
+}
 
-    Ok(merged)
+/// Fail fast, before audio is decoded or temp files written, when the chosen
+/// provider can't run: whisper or the model don't resolve, or the remote
+/// endpoint or key is missing.
+fn check_provider_configured(
+    app: &tauri::AppHandle,
+    config: &AppConfig,
+    provider_override: Option<&str>,
+) -> Result<(), String> {
+    match select_provider(config, provider_override)? {
+        TranscriptionProvider::Local | TranscriptionProvider::Auto => {
+            resolve_whisper_path(config.effective_whisper_path()).map_err(|err| {
+                format!("Local transcription is not set up: {err}. Set the whisper path in Settings.")
+            })?;
+            resolve_model_path_with_selection(
+                config.effective_model_path(),
+                &config.transcription.local.model_name,
+                &models_root(app, config)?,
+            )
+            .map_err(|err| {
+                format!("Local transcription is not set up: {err}. Choose a model in Settings.")
+            })?;
+        }
+        TranscriptionProvider::OpenAICompatible => {
+            let remote = &config.transcription.openai_compatible;
+            if remote.endpoint.trim().is_empty() {
+                return Err(
+                    "OpenAI-compatible endpoint not configured. Set it in Settings.".to_string(),
+                );
+            }
+            if remote.api_key.trim().is_empty() {
+                return Err(
+                    "OpenAI-compatible API key not configured. Set it in Settings.".to_string(),
+                );
+            }
+        }
+    }
+    Ok(())
 }
 
-#[allow(dead_code)]
-fn get_transcription_config(config: &AppConfig) -> serde_json::Value {
-    serde_json::json!({
-        "provider": config.transcription.provider,
-        "streaming": {
-            "enabled": config.transcription.streaming.enabled,
-            "chunkDurationMs": config.transcription.streaming.chunk_duration_ms,
-            "overlapMs": config.transcription.streaming.overlap_ms,
-        },
-        "localConfigured": !config.effective_whisper_path().is_empty() 
-            && !config.effective_model_path().is_empty(),
-        "openaiConfigured": !config.transcription.openai_compatible.api_key.is_empty(),
-    })
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ProviderCapabilities {
+    provider: TranscriptionProvider,
+    /// `false` with `error` set when the provider isn't set up; every
+    /// capability is then `false` too.
+    configured: bool,
+    error: Option<String>,
+    supports_streaming: bool,
+    supports_timestamps: bool,
+    supports_word_timestamps: bool,
+    /// No provider diarizes yet; `transcribe_multichannel` labels speakers
+    /// by channel instead.
+    supports_diarization: bool,
+    /// Nothing passes whisper's `--translate` or calls a translation endpoint.
+    supports_translation: bool,
 }
 
+/// What the given (or configured) provider can return, so the UI can hide
+/// options it doesn't support instead of guessing.
 #[tauri::command]
-fn diagnose_whisper(whisper_path: String) -> Result<String, String> {
-    let resolved = resolve_whisper_path(&whisper_path)?;
-
-    let output = Command::new(&resolved)
-        .arg("-h")
-        .output()
-        .map_err(|err| format!("Failed to run whisper diagnostics: {err}"))?;
+async fn provider_capabilities(
+    app: tauri::AppHandle,
+    provider: Option<String>,
+) -> Result<ProviderCapabilities, String> {
+    let config = load_config(app.clone()).await?;
+    let selected = match select_provider(&config, provider.as_deref())? {
+        TranscriptionProvider::Auto => TranscriptionProvider::Local,
+        selected => selected,
+    };
+    let selected_name = match selected {
+        TranscriptionProvider::OpenAICompatible => "openai-compatible",
+        _ => "local",
+    };
+    tauri::async_runtime::spawn_blocking(move || {
+        let mut capabilities = ProviderCapabilities {
+            provider: selected,
+            configured: true,
+            error: None,
+            supports_streaming: false,
+            supports_timestamps: false,
+            supports_word_timestamps: false,
+            supports_diarization: false,
+            supports_translation: false,
+        };
+        if let Err(err) = check_provider_configured(&app, &config, Some(selected_name)) {
+            capabilities.configured = false;
+            capabilities.error = Some(err);
+            return Ok(capabilities);
+        }
+        capabilities.supports_streaming = true;
+        if selected == TranscriptionProvider::Local {
+            // Segment and token timings both come from whisper's full JSON.
+            let whisper_path = resolve_whisper_path(config.effective_whisper_path())?;
+            let full_json = whisper_supports_flag(&whisper_path, "--output-json-full");
+            capabilities.supports_timestamps = full_json;
+            capabilities.supports_word_timestamps = full_json;
+        }
+        Ok(capabilities)
+    })
+    .await
+    .map_err(|err| format!("Failed to check provider capabilities task: {err}"))?
+}
 
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let stderr = String::from_utf8_lossy(&output.stderr);
+fn decode_audio_base64(audio_base64: &str) -> Result<Vec<u8>, String> {
+    base64::engine::general_purpose::STANDARD
+        .decode(audio_base64.trim())
+        .map_err(|err| format!("Failed to decode audio: {err}"))
+}
 
-    Ok(format!(
-        "Resolved binary: {}\nExit code: {}\nstdout:\n{}\nstderr:\n{}",
-        resolved.display(),
-        output.status.code().unwrap_or(-1),
-        stdout,
-        stderr
-    ))
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum AudioFormat {
+    Wav,
+    Webm,
+    Ogg,
+    Mp3,
+    M4a,
+    Flac,
 }
 
-#[tauri::command]
-fn generate_summary(
-    transcript: String,
-    notes: String,
-    model: Option<String>,
-) -> Result<String, String> {
-    let temp_dir = std::env::temp_dir().join("voxii");
-    fs::create_dir_all(&temp_dir)
-        .map_err(|err| format!("Failed to create temp dir: {err}"))?;
+impl AudioFormat {
+    fn from_mime(mime: &str) -> Option<Self> {
+        match mime.trim().to_ascii_lowercase().as_str() {
+            "audio/wav" | "audio/wave" | "audio/x-wav" | "audio/vnd.wave" => Some(Self::Wav),
+            "audio/webm" => Some(Self::Webm),
+            "audio/ogg" => Some(Self::Ogg),
+            "audio/mpeg" | "audio/mp3" => Some(Self::Mp3),
+            "audio/mp4" | "audio/m4a" | "audio/x-m4a" => Some(Self::M4a),
+            "audio/flac" | "audio/x-flac" => Some(Self::Flac),
+            _ => None,
+        }
+    }
 
-    let id = uuid::Uuid::new_v4().to_string();
-    let input_path = temp_dir.join(format!("{id}_summary.json"));
+    /// Sniff the container; unknown payloads are assumed to be WAV as before.
+    fn sniff(bytes: &[u8]) -> Self {
+        Self::recognize(bytes).unwrap_or(Self::Wav)
+    }
 
-    let payload = serde_json::json!({
-        "transcript": transcript,
-        "notes": notes,
-        "sections": ["Agenda", "Summary", "Decisions", "Risks", "Actions"],
-        "model": model.unwrap_or_else(|| "gpt-4.1".to_string())
-    });
+    /// The container named by the bytes' magic number, if there is one.
+    fn recognize(bytes: &[u8]) -> Option<Self> {
+        match audio_file_extension(bytes) {
+            "wav" => Some(Self::Wav),
+            "webm" => Some(Self::Webm),
+            "ogg" => Some(Self::Ogg),
+            "mp3" => Some(Self::Mp3),
+            "m4a" => Some(Self::M4a),
+            "flac" => Some(Self::Flac),
+            _ => None,
+        }
+    }
 
-    fs::write(&input_path, payload.to_string())
-        .map_err(|err| format!("Failed to write summary payload: {err}"))?;
+    fn extension(self) -> &'static str {
+        match self {
+            Self::Wav => "wav",
+            Self::Webm => "webm",
+            Self::Ogg => "ogg",
+            Self::Mp3 => "mp3",
+            Self::M4a => "m4a",
+            Self::Flac => "flac",
+        }
+    }
 
-    let script_path = Path::new(env!("CARGO_MANIFEST_DIR"))
-        .join("scripts")
-        .join("copilot-summary.mjs");
+    fn mime(self) -> &'static str {
+        match self {
+            Self::Wav => "audio/wav",
+            Self::Webm => "audio/webm",
+            Self::Ogg => "audio/ogg",
+            Self::Mp3 => "audio/mpeg",
+            Self::M4a => "audio/mp4",
+            Self::Flac => "audio/flac",
+        }
+    }
 
-    if !script_path.exists() {
-        return Err(format!("Copilot summary script not found: {}", script_path.display()));
+    /// Formats whisper.cpp can open directly.
+    fn whisper_readable(self) -> bool {
+        matches!(self, Self::Wav | Self::Ogg | Self::Mp3 | Self::Flac)
     }
+}
 
-    let output = Command::new("node")
-        .arg(script_path)
-        .arg(&input_path)
-        .output()
-        .map_err(|err| format!("Failed to run Copilot SDK: {err}"))?;
+/// Decoded audio plus the container it arrived in.
+struct AudioInput {
+    bytes: Vec<u8>,
+    format: AudioFormat,
+}
 
-    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
-    let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+/// Accept raw base64 or a `data:<mime>;base64,...` URL, as produced by
+/// `FileReader.readAsDataURL`.
+fn decode_audio_input(input: &str) -> Result<AudioInput, String> {
+    let input = input.trim();
+    let Some(rest) = input.strip_prefix("data:") else {
+        let bytes = decode_audio_base64(input)?;
+        let format = AudioFormat::sniff(&bytes);
+        return Ok(AudioInput { bytes, format });
+    };
 
-    if !output.status.success() {
-        return Err(format!(
-            "Copilot SDK failed (code {}).\nstdout: {}\nstderr: {}",
-            output.status.code().unwrap_or(-1),
-            stdout,
-            stderr
-        ));
+    let (header, data) = rest
+        .split_once(',')
+        .ok_or("Failed to decode audio: malformed data URL")?;
+    let mut params = header.split(';');
+    let mime = params.next().unwrap_or_default();
+    if !params.any(|param| param.trim().eq_ignore_ascii_case("base64")) {
+        return Err("Failed to decode audio: data URL is not base64-encoded".to_string());
     }
 
-    let mut final_summary: Option<String> = None;
-    for line in stdout.lines() {
-        let trimmed = line.trim();
-        if trimmed.is_empty() {
-            continue;
-        }
-        if let Ok(value) = serde_json::from_str::<serde_json::Value>(trimmed) {
-            if value.get("type").and_then(|v| v.as_str()) == Some("final") {
-                if let Some(content) = value.get("content").and_then(|v| v.as_str()) {
-                    final_summary = Some(content.to_string());
-                }
+    let bytes = decode_audio_base64(data)?;
+    // Recognizable bytes win over the declared type: mislabeled uploads get
+    // rejected by providers that check the file name and content type.
+    let declared = AudioFormat::from_mime(mime);
+    let format = match (AudioFormat::recognize(&bytes), declared) {
+        (Some(actual), declared) => {
+            if declared.is_some_and(|declared| declared != actual) {
+                tracing::debug!(mime, actual = actual.mime(), "data URL type doesn't match audio");
             }
+            actual
         }
-    }
-
-    Ok(final_summary.unwrap_or_else(|| stdout.trim().to_string()))
+        (None, Some(declared)) => declared,
+        (None, None) if mime.is_empty() => AudioFormat::Wav,
+        (None, None) => {
+            return Err(format!(
+                "Unsupported audio type: {mime} (expected wav, webm, ogg, mp3, m4a or flac)"
+            ))
+        }
+    };
+    Ok(AudioInput { bytes, format })
 }
 
-#[tauri::command]
-fn start_summary_stream(
-    app: tauri::AppHandle,
-    meeting_id: String,
-    transcript: String,
-    notes: String,
-    model: String,
-) -> Result<(), String> {
-    let start = Instant::now();
-    let temp_dir = std::env::temp_dir().join("voxii");
-    fs::create_dir_all(&temp_dir)
-        .map_err(|err| format!("Failed to create temp dir: {err}"))?;
-
-    let id = uuid::Uuid::new_v4().to_string();
-    let input_path = temp_dir.join(format!("{id}_summary.json"));
-
-    let payload = serde_json::json!({
-        "transcript": transcript,
-        "notes": notes,
-        "sections": ["Agenda", "Summary", "Decisions", "Risks", "Actions"],
-        "model": model
-    });
+/// Where kept whisper input goes, or `None` when `keepAudio` is off.
+fn keep_audio_dir(app: &tauri::AppHandle, config: &AppConfig) -> Result<Option<PathBuf>, String> {
+    if !config.transcription.local.keep_audio {
+        return Ok(None);
+    }
+    Ok(Some(ensure_writable_dir(voxii_data_dir(app)?.join("debug"))?))
+}
 
-    fs::write(&input_path, payload.to_string())
-        .map_err(|err| format!("Failed to write summary payload: {err}"))?;
+/// Deletes whisper's temp input and output files when the run finishes.
+struct TempFiles(Vec<PathBuf>);
 
-    let script_path = Path::new(env!("CARGO_MANIFEST_DIR"))
-        .join("scripts")
-        .join("copilot-summary.mjs");
+impl Drop for TempFiles {
+    fn drop(&mut self) {
+        for path in &self.0 {
+            let _ = fs::remove_file(path);
+        }
+    }
+}
 
-    if !script_path.exists() {
-        return Err(format!("Copilot summary script not found: {}", script_path.display()));
+async fn transcribe_local(
+    whisper: WhisperSlot,
+    models_root: PathBuf,
+    keep_audio_dir: Option<PathBuf>,
+    config: AppConfig,
+    audio: AudioInput,
+    language: Option<String>,
+    tag: Option<String>,
+) -> Result<TranscribeResponse, String> {
+    if !audio.format.whisper_readable() {
+        return Err(format!(
+            "Local whisper cannot read {} audio; record WAV or use the OpenAI-compatible provider",
+            audio.format.mime()
+        ));
     }
 
     tauri::async_runtime::spawn_blocking(move || {
-        let _ = app.emit(
-            "summary-log",
-            format!("Rust: starting summary process ({}ms)", start.elapsed().as_millis()),
-        );
-        let mut child = match Command::new("node")
-            .env("STREAMING", "1")
-            .arg(script_path)
-            .arg(&input_path)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()
-        {
-            Ok(child) => child,
-            Err(err) => {
-                let _ = app.emit(
-                    "summary-error",
-                    format!("Failed to start Copilot SDK: {err}"),
-                );
-                return;
-            }
-        };
+        let whisper_path = resolve_whisper_path(config.effective_whisper_path())?;
+        let model_path = resolve_model_path_with_selection(
+            config.effective_model_path(),
+            &config.transcription.local.model_name,
+            &models_root,
+        )?;
 
-        if let Some(stderr) = child.stderr.take() {
-            let app_handle = app.clone();
-            tauri::async_runtime::spawn_blocking(move || {
-                let reader = BufReader::new(stderr);
-                for line in reader.lines().flatten() {
-                    let _ = app_handle.emit("summary-log", line);
-                }
-            });
+        let temp_dir = voxii_temp_dir()?;
+
+        // `{tag}_{uuid}` lets a stuck whisper process be traced back to the
+        // meeting or session that started it; the UUID keeps names unique.
+        let mut id = uuid::Uuid::new_v4().to_string();
+        if let Some(tag) = tag.as_deref().map(sanitize_temp_tag).filter(|t| !t.is_empty()) {
+            id = format!("{tag}_{id}");
         }
+        let wav_path = temp_dir.join(format!("{id}.{}", audio.format.extension()));
+        let out_base = temp_dir.join(format!("{id}_out"));
+        tracing::debug!(
+            audio = %wav_path.display(),
+            output = %out_base.display(),
+            "whisper temp files"
+        );
 
-        let mut final_summary: Option<String> = None;
-        let mut first_delta_emitted = false;
+        let _temp_files = TempFiles(vec![
+            wav_path.clone(),
+            out_base.with_extension("txt"),
+            out_base.with_extension("json"),
+        ]);
+        fs::write(&wav_path, audio.bytes)
+            .map_err(|err| format!("Failed to write audio file: {err}"))?;
 
-        if let Some(stdout) = child.stdout.take() {
-            let reader = BufReader::new(stdout);
-            for line in reader.lines().flatten() {
-                let trimmed = line.trim_end().to_string();
-                if trimmed.is_empty() {
-                    continue;
-                }
-                if let Ok(value) = serde_json::from_str::<serde_json::Value>(&trimmed) {
-                    if !first_delta_emitted {
-                        first_delta_emitted = true;
-                        let _ = app.emit(
-                            "summary-log",
-                            format!(
-                                "Rust: first stdout event at {}ms",
-                                start.elapsed().as_millis()
-                            ),
-                        );
-                    }
-                    if value.get("type").and_then(|v| v.as_str()) == Some("final") {
-                        if let Some(content) = value.get("content").and_then(|v| v.as_str()) {
-                            final_summary = Some(content.to_string());
-                        }
-                    }
+        let local = &config.transcription.local;
+        let language = language.or_else(|| {
+            config.effective_language(TranscriptionProvider::Local).map(str::to_string)
+        });
+        let extra_args: Vec<&str> = local
+            .extra_args
+            .iter()
+            .map(|arg| arg.trim())
+            .filter(|arg| !arg.is_empty())
+            .collect();
+        if let Some(arg) = extra_args
+            .iter()
+            .find(|arg| ["-of", "--output-file", "-otxt", "-ojf"].contains(arg))
+        {
+            tracing::warn!("whisper extra arg {arg} overrides a managed output flag");
+        }
+        // Builds without full JSON output exit on -ojf; they get text only.
+        let full_json = whisper_supports_flag(&whisper_path, "--output-json-full");
+        if !full_json {
+            tracing::warn!(
+                "{} has no --output-json-full; transcribing without timings",
+                whisper_path.display()
+            );
+        }
 
-                    let payload = serde_json::json!({
-                        "meetingId": meeting_id,
-                        "event": value
-                    });
-                    let _ = app.emit("summary-delta", payload);
+        let run_whisper = |model_path: &Path| -> Result<(std::process::Output, String), String> {
+            let mut cmd = Command::new(&whisper_path);
+            cmd.arg("-m")
+                .arg(model_path)
+                .arg("-f")
+                .arg(&wav_path)
+                .arg("-otxt");
+            if full_json {
+                cmd.arg("-ojf");
+            }
+            cmd.arg("-of")
+                .arg(&out_base)
+                .arg("--best-of")
+                .arg(local.best_of.to_string())
+                .arg("--beam-size")
+                .arg(local.beam_size.to_string());
+
+            let decoding_flags = [
+                ("--temperature", local.temperature),
+                ("--entropy-thold", local.entropy_thold),
+                ("--logprob-thold", local.logprob_thold),
+                ("--word-thold", local.word_thold),
+            ];
+            let mut optional_args = String::new();
+            for (flag, value) in decoding_flags {
+                if let Some(value) = value {
+                    cmd.arg(flag).arg(value.to_string());
+                    optional_args.push_str(&format!(" {flag} {value}"));
+                }
+            }
+            if local.max_segment_len > 0 {
+                cmd.arg("--max-len").arg(local.max_segment_len.to_string());
+                optional_args.push_str(&format!(" --max-len {}", local.max_segment_len));
+            }
+            if local.split_on_word {
+                if whisper_supports_flag(&whisper_path, "--split-on-word") {
+                    cmd.arg("--split-on-word");
+                    optional_args.push_str(" --split-on-word");
                 } else {
-                    let _ = app.emit("summary-log", trimmed);
+                    tracing::warn!("{} has no --split-on-word; flag skipped", whisper_path.display());
                 }
             }
-        }
-
-        let output = child.wait_with_output();
-        if let Ok(output) = output {
-            if !output.status.success() {
-                let stderr = String::from_utf8_lossy(&output.stderr);
-                let _ = app.emit(
-                    "summary-error",
-                    format!("Copilot SDK failed: {stderr}"),
-                );
+            if let Some(language) = language.as_deref().map(str::trim).filter(|l| !l.is_empty()) {
+                cmd.arg("-l").arg(language);
+            }
+            cmd.args(&extra_args);
+            for arg in &extra_args {
+                optional_args.push_str(&format!(" {arg}"));
             }
-        }
-
-        let _ = app.emit(
-            "summary-done",
-            serde_json::json!({
-                "meetingId": meeting_id,
-                "summary": final_summary
-            }),
-        );
-        let _ = app.emit(
-            "summary-log",
-            format!("Rust: summary done at {}ms", start.elapsed().as_millis()),
-        );
-    });
 
-    Ok(())
-}
+            let command_string = format!(
+                "\"{}\" -m \"{}\" -f \"{}\" -otxt{} -of \"{}\"{}",
+                whisper_path.display(),
+                model_path.display(),
+                wav_path.display(),
+                if full_json { " -ojf" } else { "" },
+                out_base.display(),
+                optional_args
+            );
 
-#[tauri::command]
-async fn list_models() -> Result<Vec<serde_json::Value>, String> {
-    tauri::async_runtime::spawn_blocking(move || {
-        let script_path = Path::new(env!("CARGO_MANIFEST_DIR"))
-            .join("scripts")
-            .join("copilot-models.mjs");
+            let output = {
+                let _inference = whisper.take_turn();
+                tracing::debug!("running whisper: {command_string}");
+                whisper
+                    .jobs
+                    .output(JobKind::Whisper, &mut cmd)
+                    .map_err(|err| format!("Failed to run whisper: {err}"))?
+                    .ok_or("Transcription was cancelled")?
+            };
+            log_process_exit("whisper", &output.status);
+            Ok((output, command_string))
+        };
 
-        if !script_path.exists() {
-            return Err(format!("Models script not found: {}", script_path.display()));
+        let mut model_path = model_path;
+        let (mut output, mut command_string) = run_whisper(&model_path)?;
+        let mut model_fallback_from = None;
+        if !output.status.success()
+            && local.fallback_model_on_error
+            && whisper_model_load_failed(&String::from_utf8_lossy(&output.stderr))
+        {
+            if let Some(fallback) = fallback_model_path(&model_path) {
+                tracing::warn!(
+                    failed = %model_path.display(),
+                    fallback = %fallback.display(),
+                    "model failed to load; retrying with fallback"
+                );
+                (output, command_string) = run_whisper(&fallback)?;
+                let failed = std::mem::replace(&mut model_path, fallback);
+                model_fallback_from = Some(failed.to_string_lossy().to_string());
+            }
         }
 
-        let output = Command::new("node")
-            .arg(script_path)
-            .output()
-            .map_err(|err| format!("Failed to run models script: {err}"))?;
+        let check_output = |output: &std::process::Output, command_string: &str| {
+            if output.status.success() {
+                return Ok(());
+            }
+            Err(format!(
+                "Whisper failed ({}).\nCommand: {}\nstdout: {}\nstderr: {}",
+                describe_exit_status(&output.status),
+                command_string,
+                String::from_utf8_lossy(&output.stdout),
+                String::from_utf8_lossy(&output.stderr)
+            ))
+        };
+        let transcript_path = out_base.with_extension("txt");
+        let read_transcript = || {
+            fs::read_to_string(&transcript_path).map_err(|err| {
+                format!(
+                    "Failed to read transcript {}: {err}",
+                    transcript_path.display()
+                )
+            })
+        };
 
-        let stdout = String::from_utf8_lossy(&output.stdout).to_string();
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            return Err(format!("Model list failed: {stderr}\n{stdout}"));
+        check_output(&output, &command_string)?;
+        let mut transcript = read_transcript()?;
+        // At most one retry, so a recording that really is silent stays cheap.
+        if transcript.trim().is_empty() && local.retry_on_empty {
+            tracing::warn!(model = %model_path.display(), "whisper wrote an empty transcript; retrying once");
+            (output, command_string) = run_whisper(&model_path)?;
+            check_output(&output, &command_string)?;
+            transcript = read_transcript()?;
+            if transcript.trim().is_empty() {
+                tracing::info!("retry also produced an empty transcript");
+            }
         }
+        let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+        // Segments are best-effort; older whisper builds may not write JSON.
+        let json = full_json
+            .then(|| fs::read_to_string(out_base.with_extension("json")).ok())
+            .flatten();
+        let segments = json.as_deref().map(parse_whisper_segments).unwrap_or_default();
+        let detected_language = json.as_deref().and_then(parse_whisper_language);
+        let output_bytes = transcript.trim().len() + json.as_deref().map_or(0, |raw| raw.trim().len());
+        let (words, words_interpolated) = match json.as_deref() {
+            Some(raw) if local.word_timestamps => parse_whisper_words(raw, &segments),
+            _ => (Vec::new(), false),
+        };
 
-        let models = serde_json::from_str::<Vec<serde_json::Value>>(stdout.trim())
-            .map_err(|err| format!("Failed to parse models list: {err}"))?;
-        Ok(models)
+        let audio_path = keep_audio_dir.and_then(|dir| {
+            let kept = dir.join(wav_path.file_name()?);
+            // The temp dir may be on another volume, where rename fails.
+            let moved = fs::rename(&wav_path, &kept).or_else(|_| fs::copy(&wav_path, &kept).map(|_| ()));
+            match moved {
+                Ok(()) => Some(kept.to_string_lossy().to_string()),
+                Err(err) => {
+                    tracing::warn!("failed to keep whisper audio: {err}");
+                    None
+                }
+            }
+        });
+
+        Ok(TranscribeResponse {
+            transcript,
+            stdout,
+            stderr,
+            command: command_string,
+            provider: "local".to_string(),
+            segments,
+            raw_transcript: None,
+            punctuation_restored: false,
+            language: detected_language,
+            audio_path,
+            model_fallback_from,
+            words,
+            words_interpolated,
+            output_bytes,
+            model: model_path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or_default()
+                .to_string(),
+        })
     })
     .await
-    .map_err(|err| format!("Failed to run model list task: {err}"))?
+    .map_err(|err| format!("Failed to run transcription task: {err}"))?
 }
 
-#[tauri::command]
+/// Keep temp-file tags to a short, filesystem-safe slug.
+fn sanitize_temp_tag(tag: &str) -> String {
+    tag.chars()
+        .filter(|c| c.is_ascii_alphanumeric() || *c == '-' || *c == '_')
+        .take(64)
+        .collect()
+}
+
+/// Called with the text so far as a streamed transcription arrives.
+type PartialTranscript<'a> = &'a (dyn Fn(&str) + Sync);
+
+async fn transcribe_openai_compatible(
+    state: &AppState,
+    config: AppConfig,
+    audio: AudioInput,
+    language: Option<String>,
+    on_partial: Option<PartialTranscript<'_>>,
+) -> Result<TranscribeResponse, String> {
+    ensure_online(&config)?;
+    let openai_config = &config.transcription.openai_compatible;
+    let duration_ms = matches!(audio.format, AudioFormat::Wav)
+        .then(|| wav_duration_ms(&audio.bytes))
+        .flatten();
+    
+    if openai_config.api_key.is_empty() {
+        return Err("OpenAI-compatible API key not configured".to_string());
+    }
+    if openai_config.endpoint.is_empty() {
+        return Err("OpenAI-compatible endpoint not configured".to_string());
+    }
+
+    // Build multipart form
+    let file_part = reqwest::multipart::Part::bytes(audio.bytes)
+        .file_name(format!("audio.{}", audio.format.extension()))
+        .mime_str(audio.format.mime())
+        .map_err(|err| format!("Failed to create multipart: {err}"))?;
+
+    let mut form = reqwest::multipart::Form::new()
+        .part("file", file_part)
+        .text("model", openai_config.model.clone());
+
+    // Remote APIs detect the language themselves when no hint is sent, so
+    // `auto` is treated the same as an empty hint.
+    let language = language.or_else(|| {
+        config
+            .effective_language(TranscriptionProvider::OpenAICompatible)
+            .map(str::to_string)
+    });
+    if let Some(language) = language
+        .as_deref()
+        .map(str::trim)
+        .filter(|l| !l.is_empty() && !l.eq_ignore_ascii_case("auto"))
+    {
+        form = form.text("language", language.to_string());
+    }
+    let on_partial = on_partial.filter(|_| openai_config.stream);
+    if on_partial.is_some() {
+        form = form.text("stream", "true");
+    }
+
+    // Make request
+    let client = reqwest::Client::new();
+    let response = client
+        .post(&openai_config.endpoint)
+        .header("Authorization", format!("Bearer {}", openai_config.api_key))
+        .multipart(form)
+        .send()
+        .await
+        .map_err(|err| format!("Failed to call transcription API: {err}"))?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        return Err(format!(
+            "Transcription API failed ({}): {}",
+            status, body
+        ));
+    }
+
+    let is_event_stream = response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("text/event-stream"));
+    let transcript = match on_partial {
+        // Servers that ignore `stream` still answer with plain JSON.
+        Some(on_partial) if is_event_stream => {
+            read_transcription_stream(response, on_partial).await?
+        }
+        _ => {
+            // Parse response - OpenAI returns { "text": "..." }
+            let result: serde_json::Value = response
+                .json()
+                .await
+                .map_err(|err| format!("Failed to parse API response: {err}"))?;
+            result
+                .get("text")
+                .and_then(|v| v.as_str())
+                .unwrap_or("")
+                .to_string()
+        }
+    };
+
+    if let Ok(mut usage) = state.remote_usage.lock() {
+        usage.record(&config, duration_ms);
+    }
+
+    Ok(TranscribeResponse {
+        output_bytes: transcript.trim().len(),
+        transcript,
+        stdout: String::new(),
+        stderr: String::new(),
+        command: format!("POST {}", openai_config.endpoint),
+        provider: "openai-compatible".to_string(),
+        model: openai_config.model.clone(),
+        segments: Vec::new(),
+        raw_transcript: None,
+        punctuation_restored: false,
+        language: None,
+        audio_path: None,
+        model_fallback_from: None,
+        words: Vec::new(),
+        words_interpolated: false,
+    })
+}
+
+/// Collect an SSE transcription (`transcript.text.delta` events, then
+/// `transcript.text.done`), reporting the text so far after every delta.
+async fn read_transcription_stream(
+    mut response: reqwest::Response,
+    on_partial: PartialTranscript<'_>,
+) -> Result<String, String> {
+    let mut buffer = Vec::new();
+    let mut text = String::new();
+    let mut done = None;
+    while let Some(bytes) = response
+        .chunk()
+        .await
+        .map_err(|err| format!("Failed to read transcription stream: {err}"))?
+    {
+        buffer.extend_from_slice(&bytes);
+        while let Some(newline) = buffer.iter().position(|byte| *byte == b'\n') {
+            let line: Vec<u8> = buffer.drain(..=newline).collect();
+            let line = String::from_utf8_lossy(&line);
+            let Some(data) = line.trim().strip_prefix("data:") else {
+                continue;
+            };
+            let Ok(event) = serde_json::from_str::<serde_json::Value>(data.trim()) else {
+                continue;
+            };
+            match event.get("type").and_then(|v| v.as_str()) {
+                Some("transcript.text.delta") => {
+                    if let Some(delta) = event.get("delta").and_then(|v| v.as_str()) {
+                        text.push_str(delta);
+                        on_partial(&text);
+                    }
+                }
+                Some("transcript.text.done") => {
+                    done = event.get("text").and_then(|v| v.as_str()).map(str::to_string);
+                }
+                _ => {}
+            }
+        }
+    }
+    Ok(done.unwrap_or(text))
+}
+
+/// The language whisper reports in its `-ojf` output (`result.language`).
+fn parse_whisper_language(raw: &str) -> Option<String> {
+    let value = serde_json::from_str::<serde_json::Value>(raw).ok()?;
+    value
+        .get("result")
+        .and_then(|result| result.get("language"))
+        .and_then(|v| v.as_str())
+        .map(str::to_string)
+}
+
+/// Parse whisper's `-ojf` output into segments, averaging token probabilities
+/// (ignoring special `[_...]` tokens) for each segment's confidence.
+fn parse_whisper_segments(raw: &str) -> Vec<TranscriptSegment> {
+    let Ok(value) = serde_json::from_str::<serde_json::Value>(raw) else {
+        return Vec::new();
+    };
+    let Some(items) = value.get("transcription").and_then(|v| v.as_array()) else {
+        return Vec::new();
+    };
+
+    items
+        .iter()
+        .map(|item| {
+            let offset = |key: &str| {
+                item.get("offsets")
+                    .and_then(|offsets| offsets.get(key))
+                    .and_then(|v| v.as_u64())
+                    .unwrap_or_default()
+            };
+            let probabilities: Vec<f64> = item
+                .get("tokens")
+                .and_then(|v| v.as_array())
+                .into_iter()
+                .flatten()
+                .filter(|token| {
+                    !token
+                        .get("text")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or_default()
+                        .starts_with("[_")
+                })
+                .filter_map(|token| token.get("p").and_then(|v| v.as_f64()))
+                .collect();
+            let confidence = (!probabilities.is_empty())
+                .then(|| (probabilities.iter().sum::<f64>() / probabilities.len() as f64) as f32);
+
+            TranscriptSegment {
+                start_ms: offset("from"),
+                end_ms: offset("to"),
+                text: item
+                    .get("text")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or_default()
+                    .trim()
+                    .to_string(),
+                confidence,
+                language: None,
+            }
+        })
+        .collect()
+}
+
+/// Word timings for `segments` (as parsed from the same `-ojf` output). -ojf
+/// makes whisper compute token timestamps; segments whose tokens carry none
+/// fall back to `interpolate_words`. The flag reports whether any did.
+fn parse_whisper_words(raw: &str, segments: &[TranscriptSegment]) -> (Vec<WordTiming>, bool) {
+    let items = serde_json::from_str::<serde_json::Value>(raw)
+        .ok()
+        .and_then(|value| value.get("transcription").and_then(|v| v.as_array()).cloned())
+        .unwrap_or_default();
+    let mut words = Vec::new();
+    let mut interpolated = false;
+    for (index, segment) in segments.iter().enumerate() {
+        let from_tokens = items.get(index).map(token_words).unwrap_or_default();
+        if from_tokens.is_empty() {
+            interpolated |= !segment.text.trim().is_empty();
+            words.extend(interpolate_words(segment));
+        } else {
+            words.extend(from_tokens);
+        }
+    }
+    (words, interpolated)
+}
+
+/// Words from one whisper segment's tokens. A token starting with a space
+/// opens a word; others (word pieces, punctuation) extend the current one.
+/// Empty when the tokens have no usable timings.
+fn token_words(item: &serde_json::Value) -> Vec<WordTiming> {
+    let mut words: Vec<WordTiming> = Vec::new();
+    let tokens = item.get("tokens").and_then(|v| v.as_array()).into_iter().flatten();
+    for token in tokens {
+        let text = token.get("text").and_then(|v| v.as_str()).unwrap_or_default();
+        if text.is_empty() || text.starts_with("[_") {
+            continue;
+        }
+        let offset = |key: &str| {
+            token
+                .get("offsets")
+                .and_then(|offsets| offsets.get(key))
+                .and_then(|v| v.as_u64())
+        };
+        let (Some(start), Some(end)) = (offset("from"), offset("to")) else {
+            return Vec::new();
+        };
+        match words.last_mut() {
+            Some(word) if !text.starts_with(' ') => {
+                word.text.push_str(text);
+                word.end_ms = word.end_ms.max(end);
+            }
+            _ => words.push(WordTiming {
+                text: text.trim_start().to_string(),
+                start_ms: start,
+                end_ms: end.max(start),
+            }),
+        }
+    }
+    words.retain(|word| !word.text.trim().is_empty());
+    // Every word collapsed to an instant means timestamps weren't computed.
+    if words.iter().all(|word| word.end_ms == word.start_ms) {
+        return Vec::new();
+    }
+    words
+}
+
+/// Spread a segment's words evenly over its duration.
+fn interpolate_words(segment: &TranscriptSegment) -> Vec<WordTiming> {
+    let parts: Vec<&str> = segment.text.split_whitespace().collect();
+    let count = parts.len() as u64;
+    let span = segment.end_ms.saturating_sub(segment.start_ms);
+    parts
+        .iter()
+        .enumerate()
+        .map(|(index, text)| WordTiming {
+            text: text.to_string(),
+            start_ms: segment.start_ms + span * index as u64 / count,
+            end_ms: segment.start_ms + span * (index as u64 + 1) / count,
+        })
+        .collect()
+}
+
+/// Duration-weighted mean of segment confidences.
+fn average_confidence(segments: &[TranscriptSegment]) -> Option<f32> {
+    let (total, weight) = segments
+        .iter()
+        .filter_map(|segment| {
+            let weight = segment.end_ms.saturating_sub(segment.start_ms).max(1) as f32;
+            segment.confidence.map(|confidence| (confidence * weight, weight))
+        })
+        .fold((0.0, 0.0), |(total, weight), (c, w)| (total + c, weight + w));
+    (weight > 0.0).then(|| total / weight)
+}
+
+// ============================================================================
+// Remote Usage
+// ============================================================================
+
+/// Published USD per-minute prices; `openaiCompatible.pricePerMinute` overrides.
+const DEFAULT_REMOTE_PRICES: &[(&str, f64)] = &[
+    ("whisper-1", 0.006),
+    ("gpt-4o-transcribe", 0.006),
+    ("gpt-4o-mini-transcribe", 0.003),
+];
+
+fn remote_price_per_minute(config: &OpenAICompatibleConfig, model: &str) -> Option<f64> {
+    config.price_per_minute.get(model).copied().or_else(|| {
+        DEFAULT_REMOTE_PRICES
+            .iter()
+            .find(|(name, _)| *name == model)
+            .map(|(_, price)| *price)
+    })
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CostEstimate {
+    model: String,
+    minutes: f64,
+    price_per_minute: Option<f64>,
+    estimated_cost: Option<f64>,
+    currency: &'static str,
+    assumptions: Vec<String>,
+}
+
+/// Remote transcription usage since launch.
+#[derive(Serialize, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+struct UsageStats {
+    requests: u64,
+    audio_minutes: f64,
+    estimated_cost: f64,
+    /// Requests whose duration couldn't be measured (non-WAV audio).
+    unmeasured_requests: u64,
+}
+
+impl UsageStats {
+    fn record(&mut self, config: &AppConfig, duration_ms: Option<u64>) {
+        self.requests += 1;
+        let Some(duration_ms) = duration_ms else {
+            self.unmeasured_requests += 1;
+            return;
+        };
+        let minutes = duration_ms as f64 / 60_000.0;
+        self.audio_minutes += minutes;
+        let openai_config = &config.transcription.openai_compatible;
+        if let Some(price) = remote_price_per_minute(openai_config, &openai_config.model) {
+            self.estimated_cost += minutes * price;
+        }
+    }
+}
+
+/// Estimate what a remote transcription of `audio_duration_ms` would cost.
+/// `profile` names the model to price; defaults to the configured model.
+#[tauri::command]
+async fn estimate_remote_cost(
+    app: tauri::AppHandle,
+    audio_duration_ms: u64,
+    profile: Option<String>,
+) -> Result<CostEstimate, String> {
+    let config = load_config(app).await?;
+    let openai_config = &config.transcription.openai_compatible;
+    let model = profile
+        .map(|profile| profile.trim().to_string())
+        .filter(|profile| !profile.is_empty())
+        .unwrap_or_else(|| openai_config.model.clone());
+
+    let minutes = audio_duration_ms as f64 / 60_000.0;
+    let price_per_minute = remote_price_per_minute(openai_config, &model);
+    let mut assumptions = vec![
+        "Billed per second of audio, in USD".to_string(),
+        "Excludes retries and any chunk overlap added while streaming".to_string(),
+    ];
+    match price_per_minute {
+        Some(price) if openai_config.price_per_minute.contains_key(&model) => {
+            assumptions.push(format!("Configured price of ${price}/min for {model}"));
+        }
+        Some(price) => assumptions.push(format!("Published price of ${price}/min for {model}")),
+        None => assumptions.push(format!(
+            "No price known for {model}; set transcription.openaiCompatible.pricePerMinute"
+        )),
+    }
+
+    Ok(CostEstimate {
+        model,
+        minutes,
+        price_per_minute,
+        estimated_cost: price_per_minute.map(|price| price * minutes),
+        currency: "USD",
+        assumptions,
+    })
+}
+
+#[tauri::command]
+fn get_usage_stats(state: State<'_, AppState>) -> Result<UsageStats, String> {
+    state
+        .remote_usage
+        .lock()
+        .map(|usage| usage.clone())
+        .map_err(|_| "Failed to acquire usage lock".to_string())
+}
+
+/// Duration of a PCM WAV payload from its header, if it has one.
+fn wav_duration_ms(bytes: &[u8]) -> Option<u64> {
+    let u32_at = |offset: usize| {
+        bytes
+            .get(offset..offset + 4)
+            .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+    };
+    if !bytes.starts_with(b"RIFF") || bytes.get(8..12) != Some(b"WAVE".as_slice()) {
+        return None;
+    }
+
+    let mut byte_rate = None;
+    let mut offset = 12;
+    while let (Some(id), Some(size)) = (bytes.get(offset..offset + 4), u32_at(offset + 4)) {
+        let body = offset + 8;
+        match id {
+            b"fmt " => byte_rate = u32_at(body + 8),
+            b"data" => {
+                let byte_rate = byte_rate.filter(|rate| *rate > 0)?;
+                // Streaming writers may leave the size unset; use what we have.
+                let size = (size as usize).min(bytes.len() - body) as u64;
+                return Some(size * 1000 / byte_rate as u64);
+            }
+            _ => {}
+        }
+        offset = body + size as usize + (size as usize & 1);
+    }
+    None
+}
+
+// ============================================================================
+// Streaming Transcription Commands
+// ============================================================================
+
+#[tauri::command]
+async fn start_streaming_session(
+    app: tauri::AppHandle,
+    state: State<'_, AppState>,
+    provider: Option<String>,
+) -> Result<String, String> {
+    let session_id = uuid::Uuid::new_v4().to_string();
+    let provider_enum = match provider.as_deref() {
+        Some("local") => TranscriptionProvider::Local,
+        Some("openai-compatible") => {
+            ensure_online(&load_config_sync(&app)?)?;
+            TranscriptionProvider::OpenAICompatible
+        }
+        _ => TranscriptionProvider::Local, // Default to local for streaming
+    };
+
+    let session = StreamingSession {
+        chunks: Vec::new(),
+        provider: provider_enum,
+        started_at: Instant::now(),
+        last_activity: Instant::now(),
+        failed_indices: BTreeSet::new(),
+        live_summary: None,
+    };
+
+    state
+        .streaming_sessions
+        .lock()
+        .map_err(|_| "Failed to acquire lock")?
+        .insert(session_id.clone(), session);
+
+    Ok(session_id)
+}
+
+#[tauri::command]
+async fn transcribe_chunk(
+    app: tauri::AppHandle,
+    state: State<'_, AppState>,
+    session_id: String,
+    audio_base64: String,
+    chunk_index: u32,
+) -> Result<(), String> {
+    let audio = load_config(app.clone())
+        .await
+        .and_then(|config| check_audio_payload_size(&config, &audio_base64))
+        .and_then(|_| decode_audio_input(&audio_base64));
+    let audio = match audio {
+        Ok(audio) => audio,
+        Err(err) => {
+            record_chunk_failure(&state, &session_id, chunk_index);
+            emit_chunk_error(&app, &session_id, chunk_index, &err);
+            return Err(err);
+        }
+    };
+    process_chunk(&app, &state, session_id, chunk_index, audio).await
+}
+
+/// Accept raw interleaved 16-bit little-endian PCM and wrap it in a WAV header
+/// on the Rust side, avoiding per-chunk WAV encoding in the frontend.
+#[tauri::command]
+async fn transcribe_pcm_chunk(
+    app: tauri::AppHandle,
+    state: State<'_, AppState>,
+    session_id: String,
+    pcm_base64: String,
+    sample_rate: u32,
+    channels: u16,
+    chunk_index: u32,
+) -> Result<(), String> {
+    let wav = decode_audio_base64(&pcm_base64)
+        .and_then(|pcm| pcm16_to_wav(&pcm, sample_rate, channels));
+    let audio = match wav {
+        Ok(bytes) => AudioInput {
+            bytes,
+            format: AudioFormat::Wav,
+        },
+        Err(err) => {
+            record_chunk_failure(&state, &session_id, chunk_index);
+            emit_chunk_error(&app, &session_id, chunk_index, &err);
+            return Err(err);
+        }
+    };
+    process_chunk(&app, &state, session_id, chunk_index, audio).await
+}
+
+const SUPPORTED_PCM_SAMPLE_RATES: [u32; 6] = [8000, 16000, 22050, 24000, 44100, 48000];
+
+/// Build a minimal PCM WAV file around 16-bit interleaved samples.
+fn pcm16_to_wav(pcm: &[u8], sample_rate: u32, channels: u16) -> Result<Vec<u8>, String> {
+    if !SUPPORTED_PCM_SAMPLE_RATES.contains(&sample_rate) {
+        return Err(format!(
+            "Unsupported PCM sample rate {sample_rate} Hz (supported: {:?})",
+            SUPPORTED_PCM_SAMPLE_RATES
+        ));
+    }
+    if !(1..=2).contains(&channels) {
+        return Err(format!(
+            "Unsupported PCM channel count {channels} (only mono or stereo)"
+        ));
+    }
+    let block_align = channels * 2;
+    if pcm.is_empty() || !pcm.len().is_multiple_of(block_align as usize) {
+        return Err(format!(
+            "PCM payload of {} bytes is not a whole number of {}-channel 16-bit frames",
+            pcm.len(),
+            channels
+        ));
+    }
+
+    let data_len = u32::try_from(pcm.len()).map_err(|_| "PCM chunk too large".to_string())?;
+    let mut wav = Vec::with_capacity(44 + pcm.len());
+    wav.extend_from_slice(b"RIFF");
+    wav.extend_from_slice(&(36 + data_len).to_le_bytes());
+    wav.extend_from_slice(b"WAVEfmt ");
+    wav.extend_from_slice(&16u32.to_le_bytes());
+    wav.extend_from_slice(&1u16.to_le_bytes()); // PCM
+    wav.extend_from_slice(&channels.to_le_bytes());
+    wav.extend_from_slice(&sample_rate.to_le_bytes());
+    wav.extend_from_slice(&(sample_rate * block_align as u32).to_le_bytes());
+    wav.extend_from_slice(&block_align.to_le_bytes());
+    wav.extend_from_slice(&16u16.to_le_bytes());
+    wav.extend_from_slice(b"data");
+    wav.extend_from_slice(&data_len.to_le_bytes());
+    wav.extend_from_slice(pcm);
+    Ok(wav)
+}
+
+/// Remember a chunk that produced no text so the merge can report the gap.
+fn record_chunk_failure(state: &AppState, session_id: &str, chunk_index: u32) {
+    if let Ok(mut sessions) = state.streaming_sessions.lock() {
+        if let Some(session) = sessions.get_mut(session_id) {
+            session.failed_indices.insert(chunk_index);
+        }
+    }
+}
+
+fn emit_chunk_error(app: &tauri::AppHandle, session_id: &str, chunk_index: u32, err: &str) {
+    let _ = app.emit(
+        "transcription-error",
+        serde_json::json!({
+            "sessionId": session_id,
+            "chunkIndex": chunk_index,
+            "error": err,
+        }),
+    );
+}
+
+/// Transcribe one decoded chunk for a streaming session and publish the result.
+async fn process_chunk(
+    app: &tauri::AppHandle,
+    state: &AppState,
+    session_id: String,
+    chunk_index: u32,
+    audio: AudioInput,
+) -> Result<(), String> {
+    let config = load_config(app.clone()).await?;
+
+    let threshold = config
+        .transcription
+        .streaming
+        .low_confidence_threshold
+        .unwrap_or(DEFAULT_LOW_CONFIDENCE_THRESHOLD);
+
+    // Get provider from session
+    let provider = {
+        let mut sessions = state.streaming_sessions.lock().map_err(|_| "Lock failed")?;
+        let session = sessions.get_mut(&session_id).ok_or("Session not found")?;
+        session.last_activity = Instant::now();
+        session.provider
+    };
+
+    // Transcribe the chunk
+    let result = match provider {
+        TranscriptionProvider::Local | TranscriptionProvider::Auto => {
+            let models_root = models_root(app, &config)?;
+            let keep_audio_dir = keep_audio_dir(app, &config)?;
+            let whisper = WhisperSlot::reporting(
+                app,
+                serde_json::json!({ "sessionId": session_id, "chunkIndex": chunk_index }),
+            );
+            transcribe_local(
+                whisper,
+                models_root,
+                keep_audio_dir,
+                config,
+                audio,
+                None,
+                Some(session_id.clone()),
+            )
+            .await
+        }
+        TranscriptionProvider::OpenAICompatible => {
+            let emit_partial = |partial: &str| {
+                let _ = app.emit(
+                    "transcription-chunk-delta",
+                    serde_json::json!({
+                        "sessionId": session_id,
+                        "chunkIndex": chunk_index,
+                        "partial": partial,
+                    }),
+                );
+            };
+            transcribe_openai_compatible(state, config, audio, None, Some(&emit_partial)).await
+        }
+    };
+
+    match result {
+        Ok(response) => {
+            let avg_confidence = average_confidence(&response.segments);
+            let low_confidence = avg_confidence.is_some_and(|confidence| confidence < threshold);
+
+            // Store chunk result
+            {
+                let mut sessions = state.streaming_sessions.lock().map_err(|_| "Lock failed")?;
+                if let Some(session) = sessions.get_mut(&session_id) {
+                    session.last_activity = Instant::now();
+                    session.failed_indices.remove(&chunk_index);
+                    session.chunks.retain(|chunk| chunk.index != chunk_index);
+                    session.chunks.push(StreamedChunk {
+                        index: chunk_index,
+                        text: response.transcript.clone(),
+                        avg_confidence,
+                        low_confidence,
+                    });
+                    queue_live_summary(app, &session_id, session);
+                }
+            }
+
+            // Emit event to frontend
+            let _ = app.emit(
+                "transcription-chunk",
+                serde_json::json!({
+                    "sessionId": session_id,
+                    "chunkIndex": chunk_index,
+                    "text": response.transcript,
+                    "provider": response.provider,
+                    "avgConfidence": avg_confidence,
+                    "lowConfidence": low_confidence,
+                }),
+            );
+        }
+        Err(err) => {
+            tracing::warn!(%session_id, chunk_index, "chunk transcription failed: {err}");
+            record_chunk_failure(state, &session_id, chunk_index);
+            emit_chunk_error(app, &session_id, chunk_index, &err);
+            return Err(err);
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct StreamingSessionInfo {
+    session_id: String,
+    chunk_count: usize,
+    provider: TranscriptionProvider,
+    first_index: Option<u32>,
+    last_index: Option<u32>,
+    /// Some index between first and last never arrived (or failed to transcribe).
+    has_gaps: bool,
+    age_ms: u64,
+    idle_ms: u64,
+}
+
+impl StreamingSessionInfo {
+    fn new(session_id: &str, session: &StreamingSession) -> Self {
+        let mut indices: Vec<u32> = session.chunks.iter().map(|chunk| chunk.index).collect();
+        indices.sort_unstable();
+        indices.dedup();
+        let first_index = indices.first().copied();
+        let last_index = indices.last().copied();
+        let has_gaps = match (first_index, last_index) {
+            (Some(first), Some(last)) => (last - first) as usize + 1 != indices.len(),
+            _ => false,
+        };
+        Self {
+            session_id: session_id.to_string(),
+            chunk_count: session.chunks.len(),
+            provider: session.provider,
+            first_index,
+            last_index,
+            has_gaps,
+            age_ms: session.started_at.elapsed().as_millis() as u64,
+            idle_ms: session.last_activity.elapsed().as_millis() as u64,
+        }
+    }
+}
+
+#[tauri::command]
+async fn get_streaming_session(
+    state: State<'_, AppState>,
+    session_id: String,
+) -> Result<StreamingSessionInfo, String> {
+    let sessions = state
+        .streaming_sessions
+        .lock()
+        .map_err(|_| "Failed to acquire lock")?;
+    let session = sessions.get(&session_id).ok_or("Session not found")?;
+    Ok(StreamingSessionInfo::new(&session_id, session))
+}
+
+/// All live sessions, oldest first; useful for spotting ones that never ended.
+#[tauri::command]
+async fn list_streaming_sessions(
+    state: State<'_, AppState>,
+) -> Result<Vec<StreamingSessionInfo>, String> {
+    let sessions = state
+        .streaming_sessions
+        .lock()
+        .map_err(|_| "Failed to acquire lock")?;
+    let mut infos: Vec<StreamingSessionInfo> = sessions
+        .iter()
+        .map(|(id, session)| StreamingSessionInfo::new(id, session))
+        .collect();
+    infos.sort_by_key(|info| std::cmp::Reverse(info.age_ms));
+    Ok(infos)
+}
+
+/// Sessions with no chunk for this long are assumed abandoned (crash, reload).
+const DEFAULT_SESSION_IDLE_SECS: u64 = 600;
+const SESSION_SWEEP_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Drop sessions idle for longer than `max_idle`, emitting
+/// `streaming-session-expired` for each. Returns the removed ids.
+fn prune_stale_sessions_sync(app: &tauri::AppHandle, max_idle: Duration) -> Vec<String> {
+    let state = app.state::<AppState>();
+    let expired: Vec<(String, StreamingSession)> = {
+        let Ok(mut sessions) = state.streaming_sessions.lock() else {
+            return Vec::new();
+        };
+        let stale: Vec<String> = sessions
+            .iter()
+            .filter(|(_, session)| session.last_activity.elapsed() > max_idle)
+            .map(|(id, _)| id.clone())
+            .collect();
+        stale
+            .into_iter()
+            .filter_map(|id| sessions.remove(&id).map(|session| (id, session)))
+            .collect()
+    };
+
+    for (session_id, session) in &expired {
+        tracing::warn!(%session_id, chunks = session.chunks.len(), "streaming session expired");
+        let _ = app.emit(
+            "streaming-session-expired",
+            serde_json::json!({
+                "sessionId": session_id,
+                "chunkCount": session.chunks.len(),
+                "idleMs": session.last_activity.elapsed().as_millis() as u64,
+            }),
+        );
+    }
+    expired.into_iter().map(|(id, _)| id).collect()
+}
+
+#[tauri::command]
+async fn prune_stale_sessions(
+    app: tauri::AppHandle,
+    max_idle_secs: Option<u64>,
+) -> Result<Vec<String>, String> {
+    let max_idle = Duration::from_secs(max_idle_secs.unwrap_or(DEFAULT_SESSION_IDLE_SECS));
+    Ok(prune_stale_sessions_sync(&app, max_idle))
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct StreamingSessionResult {
+    transcript: String,
+    /// Chunks missing from `transcript`; the session stays open until these
+    /// are retried successfully or it expires.
+    failed_indices: Vec<u32>,
+}
+
+#[tauri::command]
+async fn end_streaming_session(
+    app: tauri::AppHandle,
+    state: State<'_, AppState>,
+    session_id: String,
+    mark_uncertain: Option<bool>,
+) -> Result<StreamingSessionResult, String> {
+    finish_streaming_session(&app, &state, &session_id, mark_uncertain.unwrap_or(false)).await
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RetryChunk {
+    chunk_index: u32,
+    audio_base64: String,
+}
+
+/// Re-transcribe chunks that failed in an ended session and return the new
+/// merge. Chunks that fail again stay in `failedIndices`.
+#[tauri::command]
+async fn retry_failed_chunks(
+    app: tauri::AppHandle,
+    state: State<'_, AppState>,
+    session_id: String,
+    chunks: Vec<RetryChunk>,
+    mark_uncertain: Option<bool>,
+) -> Result<StreamingSessionResult, String> {
+    let failed = {
+        let sessions = state
+            .streaming_sessions
+            .lock()
+            .map_err(|_| "Failed to acquire lock")?;
+        let session = sessions.get(&session_id).ok_or("Session not found")?;
+        session.failed_indices.clone()
+    };
+    if let Some(chunk) = chunks.iter().find(|chunk| !failed.contains(&chunk.chunk_index)) {
+        return Err(format!("Chunk {} did not fail in this session", chunk.chunk_index));
+    }
+
+    for chunk in chunks {
+        let audio = decode_audio_input(&chunk.audio_base64);
+        let result = match audio {
+            Ok(audio) => {
+                process_chunk(&app, &state, session_id.clone(), chunk.chunk_index, audio).await
+            }
+            Err(err) => {
+                emit_chunk_error(&app, &session_id, chunk.chunk_index, &err);
+                Err(err)
+            }
+        };
+        // Failures are recorded on the session; keep going with the rest.
+        if let Err(err) = result {
+            tracing::warn!(%session_id, chunk_index = chunk.chunk_index, "chunk retry failed: {err}");
+        }
+    }
+
+    finish_streaming_session(&app, &state, &session_id, mark_uncertain.unwrap_or(false)).await
+}
+
+/// Merge a session's chunks. The session is dropped once nothing is missing;
+/// otherwise it is kept (and its idle timer reset) so failed chunks can be retried.
+async fn finish_streaming_session(
+    app: &tauri::AppHandle,
+    state: &AppState,
+    session_id: &str,
+    mark_uncertain: bool,
+) -> Result<StreamingSessionResult, String> {
+    let live_summary;
+    let (mut chunks, failed_indices) = {
+        let mut sessions = state
+            .streaming_sessions
+            .lock()
+            .map_err(|_| "Failed to acquire lock")?;
+        let session = sessions.get_mut(session_id).ok_or("Session not found")?;
+        live_summary = session.live_summary.take();
+        if session.failed_indices.is_empty() {
+            let session = sessions.remove(session_id).ok_or("Session not found")?;
+            (session.chunks, Vec::new())
+        } else {
+            session.last_activity = Instant::now();
+            let failed = session.failed_indices.iter().copied().collect();
+            (session.chunks.clone(), failed)
+        }
+    };
+
+    // Sort chunks by index and merge
+    chunks.sort_by_key(|chunk| chunk.index);
+
+    let scored: Vec<f32> = chunks.iter().filter_map(|chunk| chunk.avg_confidence).collect();
+    tracing::info!(
+        %session_id,
+        chunks = chunks.len(),
+        failed = failed_indices.len(),
+        low_confidence = chunks.iter().filter(|chunk| chunk.low_confidence).count(),
+        avg_confidence = (!scored.is_empty()).then(|| scored.iter().sum::<f32>() / scored.len() as f32),
+        "streaming session ended"
+    );
+
+    // Overlapping audio means each chunk may repeat the previous one's tail.
+    let streaming = load_config(app.clone()).await?.transcription.streaming;
+    let overlap_window = (streaming.overlap_ms > 0).then(|| streaming.overlap_match_tokens());
+
+    let chunk_count = chunks.len();
+    // Optionally wrap low-confidence chunks as `[? ... ?]` for review.
+    let mut merged = String::new();
+    for chunk in chunks {
+        let mut text = chunk.text.trim();
+        if let Some((min_tokens, max_tokens)) = overlap_window {
+            let repeated = overlap_token_count(&merged, text, min_tokens, max_tokens);
+            text = skip_words(text, repeated);
+        }
+        if text.is_empty() {
+            continue;
+        }
+        if !merged.is_empty() {
+            if chunk_seam_splits_word(&merged, text) {
+                tracing::warn!(%session_id, chunk_index = chunk.index, "chunk boundary may split a word");
+                let _ = app.emit(
+                    "transcription-seam-warning",
+                    serde_json::json!({
+                        "sessionId": session_id,
+                        "chunkIndex": chunk.index,
+                    }),
+                );
+            }
+            // Punctuation that continues the previous chunk attaches directly.
+            if !text.starts_with([',', '.', ';', ':', '!', '?', ')']) {
+                merged.push(' ');
+            }
+        }
+        if mark_uncertain && chunk.low_confidence {
+            merged.push_str(&format!("[? {text} ?]"));
+        } else {
+            merged.push_str(text);
+        }
+    }
+
+    if let Some(live) = live_summary {
+        run_live_summary(app, session_id, &live, merged.clone(), chunk_count, true);
+    }
+
+    Ok(StreamingSessionResult {
+        transcript: merged,
+        failed_indices,
+    })
+}
+
+const DEFAULT_LIVE_SUMMARY_EVERY_CHUNKS: u32 = 6;
+/// Floor between live summary runs, however fast chunks arrive.
+const LIVE_SUMMARY_MIN_INTERVAL: Duration = Duration::from_secs(45);
+
+/// Keep a rolling summary of a streaming session: every `everyChunks` chunks
+/// (at most once per 45s, one run at a time) the transcript so far is
+/// summarized and sent as `live-summary-update`. Ending the session sends a
+/// last update with `final: true`. Calling again replaces the settings.
+#[tauri::command]
+async fn start_live_summary(
+    state: State<'_, AppState>,
+    session_id: String,
+    meeting_id: String,
+    model: String,
+    every_chunks: Option<u32>,
+) -> Result<(), String> {
+    let every_chunks = every_chunks.unwrap_or(DEFAULT_LIVE_SUMMARY_EVERY_CHUNKS).max(1) as usize;
+    let mut sessions = state
+        .streaming_sessions
+        .lock()
+        .map_err(|_| "Failed to acquire lock")?;
+    let session = sessions.get_mut(&session_id).ok_or("Session not found")?;
+    session.live_summary = Some(LiveSummary {
+        meeting_id,
+        model,
+        every_chunks,
+        summarized_chunks: 0,
+        last_started: None,
+        running: false,
+    });
+    Ok(())
+}
+
+/// Start a live summary run if one is due. Called with the session locked.
+fn queue_live_summary(app: &tauri::AppHandle, session_id: &str, session: &mut StreamingSession) {
+    let chunk_count = session.chunks.len();
+    let Some(live) = session.live_summary.as_mut().filter(|live| live.due(chunk_count)) else {
+        return;
+    };
+    live.running = true;
+    live.summarized_chunks = chunk_count;
+    live.last_started = Some(Instant::now());
+
+    // A rough join is enough here; the final update uses the deduplicated merge.
+    let mut chunks: Vec<&StreamedChunk> = session.chunks.iter().collect();
+    chunks.sort_by_key(|chunk| chunk.index);
+    let transcript = chunks
+        .iter()
+        .map(|chunk| chunk.text.trim())
+        .filter(|text| !text.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    run_live_summary(app, session_id, live, transcript, chunk_count, false);
+}
+
+fn run_live_summary(
+    app: &tauri::AppHandle,
+    session_id: &str,
+    live: &LiveSummary,
+    transcript: String,
+    chunk_count: usize,
+    is_final: bool,
+) {
+    let app = app.clone();
+    let session_id = session_id.to_string();
+    let meeting_id = live.meeting_id.clone();
+    let model = live.model.clone();
+    tauri::async_runtime::spawn_blocking(move || {
+        let result = if transcript.trim().is_empty() {
+            Err("Nothing transcribed yet".to_string())
+        } else {
+            generate_summary_sync(app.clone(), transcript, String::new(), Some(model))
+        };
+
+        if !is_final {
+            let state = app.state::<AppState>();
+            let Ok(mut sessions) = state.streaming_sessions.lock() else {
+                return;
+            };
+            // Once the session has ended its final summary supersedes this one.
+            let Some(live) = sessions
+                .get_mut(&session_id)
+                .and_then(|session| session.live_summary.as_mut())
+            else {
+                return;
+            };
+            live.running = false;
+        }
+
+        let payload = match result {
+            Ok(summary) => serde_json::json!({
+                "sessionId": session_id,
+                "meetingId": meeting_id,
+                "summary": summary,
+                "chunkCount": chunk_count,
+                "final": is_final,
+            }),
+            Err(err) => {
+                tracing::warn!(%session_id, "live summary failed: {err}");
+                let _ = app.emit(
+                    "live-summary-error",
+                    serde_json::json!({
+                        "sessionId": session_id,
+                        "meetingId": meeting_id,
+                        "error": err,
+                        "final": is_final,
+                    }),
+                );
+                return;
+            }
+        };
+        let _ = app.emit("live-summary-update", payload);
+    });
+}
+
+/// Length of the longest run of `min..=max` words that ends `previous` and
+/// starts `next`, compared case- and punctuation-insensitively. 0 if none.
+fn overlap_token_count(previous: &str, next: &str, min: usize, max: usize) -> usize {
+    let normalize = |word: &str| -> String {
+        word.chars()
+            .filter(|c| c.is_alphanumeric() || *c == '\'')
+            .flat_map(char::to_lowercase)
+            .collect()
+    };
+    let tail: Vec<String> = previous.split_whitespace().rev().take(max).map(normalize).collect();
+    let head: Vec<String> = next.split_whitespace().take(max).map(normalize).collect();
+    let longest = tail.len().min(head.len());
+
+    (min.max(1)..=longest)
+        .rev()
+        .find(|&len| tail[..len].iter().rev().eq(head[..len].iter()))
+        .unwrap_or(0)
+}
+
+/// `text` without its first `count` whitespace-separated words.
+fn skip_words(text: &str, count: usize) -> &str {
+    let mut rest = text.trim_start();
+    for _ in 0..count {
+        let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        rest = rest[end..].trim_start();
+    }
+    rest
+}
+
+/// Heuristic for a word cut at a chunk boundary (most likely with
+/// `overlap_ms = 0`): whisper marks truncated words with a trailing hyphen or
+/// ellipsis, or the next chunk opens mid-sentence with a fragment.
+fn chunk_seam_splits_word(previous: &str, next: &str) -> bool {
+    let previous = previous.trim_end();
+    if previous.ends_with(['.', '!', '?']) && !previous.ends_with("...") {
+        return false;
+    }
+    previous.ends_with('-')
+        || previous.ends_with("...")
+        || previous.ends_with('…')
+        || next.starts_with('-')
+        || next.starts_with("...")
+        || next.starts_with('…')
+}
+
+#[allow(dead_code)]
+fn get_transcription_config(config: &AppConfig) -> serde_json::Value {
+    serde_json::json!({
+        "provider": config.transcription.provider,
+        "streaming": {
+            "enabled": config.transcription.streaming.enabled,
+            "chunkDurationMs": config.transcription.streaming.chunk_duration_ms,
+            "overlapMs": config.transcription.streaming.overlap_ms,
+        },
+        "localConfigured": !config.effective_whisper_path().is_empty() 
+            && !config.effective_model_path().is_empty(),
+        "openaiConfigured": !config.transcription.openai_compatible.api_key.is_empty(),
+    })
+}
+
+/// Run whisper once on a short silent clip so the model is in the OS file
+/// cache (and the GPU context is initialized) before the first real recording.
+#[tauri::command]
+async fn warmup_whisper(app: tauri::AppHandle) -> Result<u64, String> {
+    let start = Instant::now();
+    let mut config = load_config(app.clone()).await?;
+    // Silence never yields text, and one run is the whole warm-up.
+    config.transcription.local.retry_on_empty = false;
+    let silence = pcm16_to_wav(&vec![0u8; 16_000 * 2], 16_000, 1)?;
+    let whisper = WhisperSlot::silent(&app);
+
+    let audio = AudioInput {
+        bytes: silence,
+        format: AudioFormat::Wav,
+    };
+    let models_root = models_root(&app, &config)?;
+    let language = Some("en".to_string());
+    let tag = Some("warmup".to_string());
+    match transcribe_local(whisper, models_root, None, config, audio, language, tag).await {
+        Ok(_) => {
+            let elapsed_ms = start.elapsed().as_millis() as u64;
+            tracing::info!(elapsed_ms, "whisper warm-up complete");
+            let _ = app.emit("warmup-done", serde_json::json!({ "elapsedMs": elapsed_ms }));
+            Ok(elapsed_ms)
+        }
+        Err(err) => {
+            tracing::warn!("whisper warm-up failed: {err}");
+            let _ = app.emit("warmup-failed", serde_json::json!({ "error": err }));
+            Err(err)
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ModelTestReport {
+    /// whisper loaded the model and exited cleanly.
+    loaded: bool,
+    /// whisper wrote a non-empty transcript or JSON file for the clip.
+    produced_output: bool,
+    /// whisper's own `load time`, when it reports one.
+    load_ms: Option<f64>,
+    elapsed_ms: u64,
+    error: Option<String>,
+}
+
+/// Check that whisper can actually load `path` (right format, not truncated)
+/// by running it on a one-second silent clip.
+#[tauri::command]
+async fn test_model(app: tauri::AppHandle, path: String) -> Result<ModelTestReport, String> {
+    let mut config = load_config(app.clone()).await?;
+    let models_root = models_root(&app, &config)?;
+    let model_file = expand_model_path(unquote_path(&path), &models_root);
+    if !model_file.is_file() {
+        return Err(format!("Model file not found: {path}"));
+    }
+
+    config.transcription.local.model_path = model_file.to_string_lossy().to_string();
+    config.transcription.local.model_name.clear();
+    config.transcription.local.extra_args.clear();
+    // A fallback model loading would hide exactly the failure being tested.
+    config.transcription.local.fallback_model_on_error = false;
+    // The silent clip is expected to come back empty.
+    config.transcription.local.retry_on_empty = false;
+
+    let silence = pcm16_to_wav(&vec![0u8; 16_000 * 2], 16_000, 1)?;
+    let audio = AudioInput {
+        bytes: silence,
+        format: AudioFormat::Wav,
+    };
+    let whisper = WhisperSlot::silent(&app);
+
+    let start = Instant::now();
+    let language = Some("en".to_string());
+    let tag = Some("model-test".to_string());
+    let result = transcribe_local(whisper, models_root, None, config, audio, language, tag).await;
+    let elapsed_ms = start.elapsed().as_millis() as u64;
+
+    Ok(match result {
+        Ok(response) => ModelTestReport {
+            loaded: true,
+            produced_output: response.output_bytes > 0,
+            load_ms: whisper_load_time_ms(&response.stderr),
+            elapsed_ms,
+            error: None,
+        },
+        Err(err) => ModelTestReport {
+            loaded: false,
+            produced_output: false,
+            load_ms: whisper_load_time_ms(&err),
+            elapsed_ms,
+            error: Some(err),
+        },
+    })
+}
+
+/// How much leading audio `detect_language` transcribes.
+const LANGUAGE_SAMPLE_MS: u32 = 10_000;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LanguageDetection {
+    language: String,
+    /// whisper's probability for `language`, 0.0-1.0.
+    confidence: Option<f32>,
+    /// Model file used; may differ from the configured one (see `detect_language`).
+    model: String,
+}
+
+/// Guess the spoken language from the first seconds of a recording so the UI
+/// can suggest it before the full transcription. Runs local whisper with
+/// `auto`; an English-only configured model is swapped for the smallest
+/// multilingual model in the same folder.
+#[tauri::command]
+async fn detect_language(
+    app: tauri::AppHandle,
+    audio_base64: String,
+) -> Result<LanguageDetection, String> {
+    let mut config = load_config(app.clone()).await?;
+    check_audio_payload_size(&config, &audio_base64)?;
+    let models_root = models_root(&app, &config)?;
+    let mut model = resolve_model_path_with_selection(
+        config.effective_model_path(),
+        &config.transcription.local.model_name,
+        &models_root,
+    )?;
+    let model_stem = |path: &Path| {
+        path.file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_default()
+    };
+    if is_english_only_model(&model_stem(&model)) {
+        let dir = model.parent().map(Path::to_path_buf).unwrap_or_default();
+        model = fs::read_dir(&dir)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("bin")))
+            .filter(|path| !is_english_only_model(&model_stem(path)))
+            .filter_map(|path| Some((fs::metadata(&path).ok()?.len(), path)))
+            .min()
+            .map(|(_, path)| path)
+            .ok_or_else(|| {
+                format!(
+                    "Language detection needs a multilingual model, but only English-only (.en) models are installed in {}. Download one such as ggml-base.bin.",
+                    dir.display()
+                )
+            })?;
+    }
+
+    let local = &mut config.transcription.local;
+    local.model_path = model.to_string_lossy().to_string();
+    local.model_name.clear();
+    local.extra_args = vec!["--duration".to_string(), LANGUAGE_SAMPLE_MS.to_string()];
+    let audio = decode_audio_input(&audio_base64)?;
+    drop(audio_base64);
+    let whisper = WhisperSlot::silent(&app);
+    let language = Some("auto".to_string());
+    let tag = Some("detect-language".to_string());
+    let response = transcribe_local(whisper, models_root, None, config, audio, language, tag).await?;
+
+    static DETECTED: OnceLock<Regex> = OnceLock::new();
+    let pattern = DETECTED.get_or_init(|| {
+        Regex::new(r"auto-detected language:\s*([a-z-]+)\s*\(p\s*=\s*([0-9.]+)\)")
+            .expect("valid regex")
+    });
+    let captures = pattern.captures(&response.stderr);
+    let language = captures
+        .as_ref()
+        .map(|captures| captures[1].to_string())
+        .or(response.language)
+        .ok_or("whisper did not report a language")?;
+    Ok(LanguageDetection {
+        language,
+        confidence: captures.and_then(|captures| captures[2].parse().ok()),
+        model: response.model,
+    })
+}
+
+/// Parse `load time = 123.45 ms` from whisper's timing summary.
+fn whisper_load_time_ms(stderr: &str) -> Option<f64> {
+    static LOAD_TIME: OnceLock<Regex> = OnceLock::new();
+    let pattern = LOAD_TIME.get_or_init(|| {
+        Regex::new(r"load time\s*=\s*([0-9.]+)\s*ms").expect("valid regex")
+    });
+    pattern
+        .captures(stderr)
+        .and_then(|captures| captures[1].parse().ok())
+}
+
+/// Whether `whisper -h` lists `flag`. The help text is cached per binary; an
+/// unreadable help counts as supported so a flaky probe can't drop options.
+fn whisper_supports_flag(whisper_path: &Path, flag: &str) -> bool {
+    static HELP: OnceLock<Mutex<HashMap<PathBuf, Option<String>>>> = OnceLock::new();
+    let cache = HELP.get_or_init(|| Mutex::new(HashMap::new()));
+    let mut cache = cache.lock().unwrap_or_else(PoisonError::into_inner);
+    let help = cache.entry(whisper_path.to_path_buf()).or_insert_with(|| {
+        Command::new(whisper_path).arg("-h").output().ok().map(|output| {
+            // Builds differ on which stream the usage goes to.
+            format!(
+                "{}{}",
+                String::from_utf8_lossy(&output.stdout),
+                String::from_utf8_lossy(&output.stderr)
+            )
+        })
+    });
+    help.as_deref()
+        .filter(|help| help.contains("usage") || help.contains("options"))
+        .is_none_or(|help| help.contains(flag))
+}
+
+#[tauri::command]
+fn diagnose_whisper(whisper_path: String) -> Result<String, String> {
+    let resolved = resolve_whisper_path(&whisper_path)?;
+
+    let output = Command::new(&resolved)
+        .arg("-h")
+        .output()
+        .map_err(|err| format!("Failed to run whisper diagnostics: {err}"))?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    Ok(format!(
+        "Resolved binary: {}\nExit: {}\nstdout:\n{}\nstderr:\n{}",
+        resolved.display(),
+        describe_exit_status(&output.status),
+        stdout,
+        stderr
+    ))
+}
+
+/// Runs on a blocking thread: waiting for an AI slot would otherwise freeze
+/// the UI behind a running summary.
+#[tauri::command]
+async fn generate_summary(
+    app: tauri::AppHandle,
+    transcript: String,
+    notes: String,
+    model: Option<String>,
+) -> Result<String, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        generate_summary_sync(app, transcript, notes, model)
+    })
+    .await
+    .map_err(|err| format!("Failed to generate summary task: {err}"))?
+}
+
+fn generate_summary_sync(
+    app: tauri::AppHandle,
+    transcript: String,
+    notes: String,
+    model: Option<String>,
+) -> Result<String, String> {
+    let temp_dir = voxii_temp_dir()?;
+
+    let id = uuid::Uuid::new_v4().to_string();
+    let input_path = temp_dir.join(format!("{id}_summary.json"));
+
+    let model = ai_task_model(&app, "summary", model.unwrap_or_default());
+    let payload = summary_payload(
+        &transcript,
+        &notes,
+        summary_sections(&app),
+        &model,
+        ai_system_prompt(&app, "summary"),
+    );
+
+    fs::write(&input_path, payload.to_string())
+        .map_err(|err| format!("Failed to write summary payload: {err}"))?;
+
+    let script_path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("scripts")
+        .join("copilot-summary.mjs");
+
+    if !script_path.exists() {
+        return Err(format!("Copilot summary script not found: {}", script_path.display()));
+    }
+
+    let _permit = acquire_ai_permit(&app, "summary-queued", serde_json::json!({ "meetingId": null }))?;
+    let output = app
+        .state::<AppState>()
+        .child_jobs
+        .output(JobKind::Summary, Command::new("node").arg(script_path).arg(&input_path))
+        .map_err(|err| format!("Failed to run Copilot SDK: {err}"))?
+        .ok_or("Summary was cancelled")?;
+    log_process_exit("summary", &output.status);
+
+    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+    let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+
+    if !output.status.success() {
+        return Err(format!(
+            "Copilot SDK failed ({}).\nstdout: {}\nstderr: {}",
+            describe_exit_status(&output.status),
+            stdout,
+            stderr
+        ));
+    }
+
+    Ok(final_script_content(&stdout))
+}
+
+/// Lines of a child process's output, decoded with `from_utf8_lossy` so an
+/// invalid byte sequence (e.g. a multi-byte character cut off by the script)
+/// costs a replacement character rather than the whole line. Unlike
+/// `lines().flatten()`, a read error ends the iteration instead of repeating.
+fn lossy_lines(pipe: impl Read) -> impl Iterator<Item = String> {
+    let mut reader = BufReader::new(pipe);
+    std::iter::from_fn(move || {
+        let mut line = Vec::new();
+        match reader.read_until(b'\n', &mut line) {
+            Ok(0) | Err(_) => None,
+            Ok(_) => {
+                if line.ends_with(b"\n") {
+                    line.pop();
+                    if line.ends_with(b"\r") {
+                        line.pop();
+                    }
+                }
+                Some(String::from_utf8_lossy(&line).into_owned())
+            }
+        }
+    })
+}
+
+/// Pull the `final` event's content out of a script's line-delimited JSON
+/// output, falling back to the raw stdout for scripts that print plain text.
+fn final_script_content(stdout: &str) -> String {
+    let mut final_content: Option<String> = None;
+    for line in stdout.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+        if let Ok(value) = serde_json::from_str::<serde_json::Value>(trimmed) {
+            if value.get("type").and_then(|v| v.as_str()) == Some("final") {
+                if let Some(content) = value.get("content").and_then(|v| v.as_str()) {
+                    final_content = Some(content.to_string());
+                }
+            }
+        }
+    }
+
+    final_content.unwrap_or_else(|| stdout.trim().to_string())
+}
+
+/// The node payload for a summary run. The prompt is rendered here (the script
+/// only falls back to its own copy) so `preview_summary_prompt` shows exactly
+/// what gets sent.
+fn summary_payload(
+    transcript: &str,
+    notes: &str,
+    sections: Vec<String>,
+    model: &str,
+    system_prompt: Option<String>,
+) -> serde_json::Value {
+    let prompt = render_summary_prompt(transcript, notes, &sections);
+    serde_json::json!({
+        "transcript": transcript,
+        "notes": notes,
+        "sections": sections,
+        "model": model,
+        "prompt": prompt,
+        "systemPrompt": system_prompt
+    })
+}
+
+fn render_summary_prompt(transcript: &str, notes: &str, sections: &[String]) -> String {
+    let section_list = sections
+        .iter()
+        .map(|section| format!("- {section}"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let notes = if notes.trim().is_empty() {
+        String::new()
+    } else {
+        format!("\n\nUser notes:\n{notes}")
+    };
+    format!(
+        "You are a meeting assistant. Create a concise, structured summary in Markdown with these sections, in this order, each as a \"## \" heading:\n{section_list}\n\nRules:\n- Use short bullet points\n- Be factual, no speculation\n- Keep names and numbers accurate\n- If a section has no content, write \"- None\"\n\nTranscript:\n{}{notes}\n\nReturn only Markdown.",
+        transcript.trim()
+    )
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SummaryPromptPreview {
+    prompt: String,
+    /// The JSON `start_summary_stream` would hand to the summary script.
+    payload: serde_json::Value,
+    char_count: usize,
+}
+
+/// Render the summary prompt without running the model. `sections` defaults to
+/// the configured summary sections.
+#[tauri::command]
+async fn preview_summary_prompt(
+    app: tauri::AppHandle,
+    transcript: String,
+    notes: String,
+    sections: Option<Vec<String>>,
+    model: String,
+) -> Result<SummaryPromptPreview, String> {
+    let (configured, system_prompt, model) = tauri::async_runtime::spawn_blocking(move || {
+        (
+            summary_sections(&app),
+            ai_system_prompt(&app, "summary"),
+            ai_task_model(&app, "summary", model),
+        )
+    })
+    .await
+    .map_err(|err| format!("Failed to load summary settings task: {err}"))?;
+    let sections = sections
+        .filter(|sections| !sections.is_empty())
+        .unwrap_or(configured);
+    let payload = summary_payload(&transcript, &notes, sections, &model, system_prompt);
+    let prompt = payload["prompt"].as_str().unwrap_or_default().to_string();
+    Ok(SummaryPromptPreview {
+        char_count: prompt.chars().count(),
+        prompt,
+        payload,
+    })
+}
+
+#[tauri::command]
+fn start_summary_stream(
+    app: tauri::AppHandle,
+    meeting_id: String,
+    transcript: String,
+    notes: String,
+    model: String,
+) -> Result<(), String> {
+    let start = Instant::now();
+    let temp_dir = voxii_temp_dir()?;
+
+    let id = uuid::Uuid::new_v4().to_string();
+    let input_path = temp_dir.join(format!("{id}_summary.json"));
+
+    let payload = summary_payload(
+        &transcript,
+        &notes,
+        summary_sections(&app),
+        &ai_task_model(&app, "summary", model),
+        ai_system_prompt(&app, "summary"),
+    );
+
+    fs::write(&input_path, payload.to_string())
+        .map_err(|err| format!("Failed to write summary payload: {err}"))?;
+
+    let script_path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("scripts")
+        .join("copilot-summary.mjs");
+
+    if !script_path.exists() {
+        return Err(format!("Copilot summary script not found: {}", script_path.display()));
+    }
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let _permit = match acquire_ai_permit(
+            &app,
+            "summary-queued",
+            serde_json::json!({ "meetingId": meeting_id }),
+        ) {
+            Ok(permit) => permit,
+            Err(err) => {
+                let _ = app.emit("summary-error", err);
+                return;
+            }
+        };
+        let _ = app.emit(
+            "summary-log",
+            format!("Rust: starting summary process ({}ms)", start.elapsed().as_millis()),
+        );
+        let mut child = match Command::new("node")
+            .env("STREAMING", "1")
+            .arg(script_path)
+            .arg(&input_path)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+        {
+            Ok(child) => child,
+            Err(err) => {
+                let _ = app.emit(
+                    "summary-error",
+                    format!("Failed to start Copilot SDK: {err}"),
+                );
+                return;
+            }
+        };
+
+        if let Some(stderr) = child.stderr.take() {
+            let app_handle = app.clone();
+            tauri::async_runtime::spawn_blocking(move || {
+                for line in lossy_lines(stderr) {
+                    let _ = app_handle.emit("summary-log", line);
+                }
+            });
+        }
+
+        let mut final_summary: Option<String> = None;
+        let mut first_delta_emitted = false;
+
+        let stdout_pipe = child.stdout.take();
+        let run_id = app.state::<AppState>().child_jobs.track(JobKind::Summary, child);
+        if let Some(stdout) = stdout_pipe {
+            for line in lossy_lines(stdout) {
+                let trimmed = line.trim_end().to_string();
+                if trimmed.is_empty() {
+                    continue;
+                }
+                if let Ok(value) = serde_json::from_str::<serde_json::Value>(&trimmed) {
+                    if !first_delta_emitted {
+                        first_delta_emitted = true;
+                        let _ = app.emit(
+                            "summary-log",
+                            format!(
+                                "Rust: first stdout event at {}ms",
+                                start.elapsed().as_millis()
+                            ),
+                        );
+                    }
+                    if value.get("type").and_then(|v| v.as_str()) == Some("final") {
+                        if let Some(content) = value.get("content").and_then(|v| v.as_str()) {
+                            final_summary = Some(content.to_string());
+                        }
+                    }
+
+                    let payload = serde_json::json!({
+                        "meetingId": meeting_id,
+                        "event": value
+                    });
+                    let _ = app.emit("summary-delta", payload);
+                } else {
+                    let _ = app.emit("summary-log", trimmed);
+                }
+            }
+        }
+
+        // `None` when cancel_all_jobs killed it, which is no failure.
+        let child = app.state::<AppState>().child_jobs.untrack(&run_id);
+        if let Some(Ok(output)) = child.map(Child::wait_with_output) {
+            log_process_exit("summary-stream", &output.status);
+            if !output.status.success() {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                let _ = app.emit(
+                    "summary-error",
+                    format!(
+                        "Copilot SDK failed ({}): {stderr}",
+                        describe_exit_status(&output.status)
+                    ),
+                );
+            }
+        }
+
+        let _ = app.emit(
+            "summary-done",
+            serde_json::json!({
+                "meetingId": meeting_id,
+                "summary": final_summary
+            }),
+        );
+        let _ = app.emit(
+            "summary-log",
+            format!("Rust: summary done at {}ms", start.elapsed().as_millis()),
+        );
+    });
+
+    Ok(())
+}
+
+/// How much of the transcript `suggest_title` sends along with the summary.
+const TITLE_TRANSCRIPT_CHARS: usize = 3000;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TitleSuggestion {
+    title: String,
+    /// Whether the suggestion was saved as the meeting's title.
+    applied: bool,
+}
+
+/// Ask the AI for a short meeting title from the summary and the start of the
+/// transcript. With `apply`, it replaces the title when that is still empty or
+/// the default "Untitled meeting"; a title the user typed is never overwritten.
+#[tauri::command]
+async fn suggest_title(
+    app: tauri::AppHandle,
+    meeting_id: String,
+    model: Option<String>,
+    apply: Option<bool>,
+) -> Result<TitleSuggestion, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let meeting = load_meetings_sync(&app)?
+            .into_iter()
+            .find(|meeting| meeting.id == meeting_id)
+            .ok_or_else(|| format!("Meeting not found: {meeting_id}"))?;
+        let excerpt: String = meeting.transcript.trim().chars().take(TITLE_TRANSCRIPT_CHARS).collect();
+        if excerpt.is_empty() && meeting.summary.trim().is_empty() {
+            return Err("Meeting has no transcript or summary to title".to_string());
+        }
+
+        let mut prompt = String::from(
+            "Suggest a concise title (3 to 8 words) for this meeting. Name the main topic, \
+not the meeting type. Reply with the title only: no quotes, no trailing period.\n",
+        );
+        if !meeting.summary.trim().is_empty() {
+            prompt.push_str(&format!("\nSummary:\n{}\n", meeting.summary.trim()));
+        }
+        if !excerpt.is_empty() {
+            prompt.push_str(&format!("\nTranscript (start):\n{excerpt}\n"));
+        }
+
+        let temp_dir = voxii_temp_dir()?;
+        let input_path = temp_dir.join(format!("{}_title.json", uuid::Uuid::new_v4()));
+        let payload = serde_json::json!({
+            "model": ai_task_model(&app, "summary", model.unwrap_or_default()),
+            "prompt": prompt,
+        });
+        fs::write(&input_path, payload.to_string())
+            .map_err(|err| format!("Failed to write title payload: {err}"))?;
+
+        // The summary script sends `payload.prompt` as-is.
+        let script_path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("scripts")
+            .join("copilot-summary.mjs");
+        if !script_path.exists() {
+            return Err(format!("Copilot summary script not found: {}", script_path.display()));
+        }
+
+        let output = {
+            let _permit = acquire_ai_permit(
+                &app,
+                "title-queued",
+                serde_json::json!({ "meetingId": meeting_id }),
+            )?;
+            Command::new("node")
+                .arg(script_path)
+                .arg(&input_path)
+                .output()
+                .map_err(|err| format!("Failed to run Copilot SDK: {err}"))?
+        };
+        let _ = fs::remove_file(&input_path);
+        log_process_exit("title", &output.status);
+        let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+        if !output.status.success() {
+            return Err(format!(
+                "Copilot SDK failed ({}).\nstdout: {}\nstderr: {}",
+                describe_exit_status(&output.status),
+                stdout,
+                String::from_utf8_lossy(&output.stderr)
+            ));
+        }
+
+        let title = clean_title_suggestion(&final_script_content(&stdout));
+        if title.is_empty() {
+            return Err("The model returned no title".to_string());
+        }
+
+        let mut applied = false;
+        if apply.unwrap_or(false) {
+            // Re-read so edits saved while the model was running aren't lost.
+            let mut meetings = load_meetings_sync(&app)?;
+            if let Some(meeting) = meetings.iter_mut().find(|meeting| meeting.id == meeting_id) {
+                let current = meeting.title.trim();
+                if current.is_empty() || current.eq_ignore_ascii_case("Untitled meeting") {
+                    meeting.title = title.clone();
+                    meeting.updated_at =
+                        chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true);
+                    let updated = meeting.clone();
+                    write_meetings_sync(&app, &mut meetings)?;
+                    let _ = app.emit("meeting-updated", &updated);
+                    applied = true;
+                }
+            }
+        }
+        Ok(TitleSuggestion { title, applied })
+    })
+    .await
+    .map_err(|err| format!("Failed to suggest title task: {err}"))?
+}
+
+/// Models like to wrap titles in quotes, headings or a "Title:" label.
+fn clean_title_suggestion(raw: &str) -> String {
+    let line = raw.lines().map(str::trim).find(|line| !line.is_empty()).unwrap_or_default();
+    let line = line.trim_start_matches('#').trim();
+    let line = ["title:", "**title:**"]
+        .iter()
+        .find_map(|label| {
+            line.get(..label.len())
+                .filter(|start| start.eq_ignore_ascii_case(label))
+                .map(|_| &line[label.len()..])
+        })
+        .unwrap_or(line);
+    let title = line
+        .trim()
+        .trim_matches(|c: char| matches!(c, '"' | '\'' | '*' | '`' | '\u{201C}' | '\u{201D}'))
+        .trim_end_matches('.')
+        .trim();
+    title.chars().take(80).collect::<String>().trim_end().to_string()
+}
+
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+struct BatchSummaryReport {
+    total: usize,
+    regenerated: usize,
+    failed: usize,
+    cancelled: bool,
+}
+
+/// Regenerate the summary of every meeting with a transcript, one at a time,
+/// keeping the previous summary in `summary_history`. Emits
+/// `batch-summary-progress { meetingId, index, total }` before each meeting,
+/// `meeting-updated` after it and `batch-summary-done` with the report.
+/// `cancel_batch_summaries` stops the batch after the current meeting.
+#[tauri::command]
+async fn batch_regenerate_summaries(
+    app: tauri::AppHandle,
+    model: String,
+) -> Result<BatchSummaryReport, String> {
+    let cancel = Arc::new(AtomicBool::new(false));
+    {
+        let state = app.state::<AppState>();
+        let mut batch = state
+            .summary_batch
+            .lock()
+            .map_err(|_| "Failed to acquire lock")?;
+        if batch.is_some() {
+            return Err("A summary batch is already running".to_string());
+        }
+        *batch = Some(cancel.clone());
+    }
+
+    let task_app = app.clone();
+    let result = tauri::async_runtime::spawn_blocking(move || {
+        let app = task_app;
+        let targets: Vec<(String, String, String)> = load_meetings_sync(&app)?
+            .into_iter()
+            .filter(|meeting| !meeting.transcript.trim().is_empty())
+            .map(|meeting| (meeting.id, meeting.transcript, meeting.notes))
+            .collect();
+        let mut report = BatchSummaryReport {
+            total: targets.len(),
+            ..Default::default()
+        };
+
+        for (index, (meeting_id, transcript, notes)) in targets.into_iter().enumerate() {
+            if cancel.load(Ordering::SeqCst) {
+                report.cancelled = true;
+                break;
+            }
+            let _ = app.emit(
+                "batch-summary-progress",
+                serde_json::json!({
+                    "meetingId": meeting_id,
+                    "index": index,
+                    "total": report.total,
+                }),
+            );
+
+            let summary = match generate_summary_sync(app.clone(), transcript, notes, Some(model.clone())) {
+                Ok(summary) if !summary.trim().is_empty() => summary,
+                result => {
+                    let error = result.err().unwrap_or_else(|| "Empty summary".to_string());
+                    tracing::warn!(%meeting_id, "batch summary failed: {error}");
+                    let _ = app.emit(
+                        "batch-summary-error",
+                        serde_json::json!({ "meetingId": meeting_id, "error": error }),
+                    );
+                    report.failed += 1;
+                    continue;
+                }
+            };
+
+            // Re-read so edits saved while the model was running aren't lost.
+            let mut meetings = load_meetings_sync(&app)?;
+            let Some(meeting) = meetings.iter_mut().find(|meeting| meeting.id == meeting_id) else {
+                continue;
+            };
+            let now = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true);
+            if !meeting.summary.trim().is_empty() {
+                meeting.summary_history.push(SummaryRevision {
+                    summary: std::mem::take(&mut meeting.summary),
+                    replaced_at: now.clone(),
+                });
+            }
+            meeting.summary = summary.trim().to_string();
+            meeting.updated_at = now;
+            let updated = meeting.clone();
+            write_meetings_sync(&app, &mut meetings)?;
+            let _ = app.emit("meeting-updated", &updated);
+            report.regenerated += 1;
+        }
+        Ok(report)
+    })
+    .await
+    .map_err(|err| format!("Failed to regenerate summaries task: {err}"))
+    .and_then(|result| result);
+
+    if let Ok(mut batch) = app.state::<AppState>().summary_batch.lock() {
+        *batch = None;
+    }
+    if let Ok(report) = &result {
+        let _ = app.emit("batch-summary-done", report);
+    }
+    result
+}
+
+/// Ask a running summary batch to stop; false when none is running.
+#[tauri::command]
+async fn cancel_batch_summaries(state: State<'_, AppState>) -> Result<bool, String> {
+    let batch = state
+        .summary_batch
+        .lock()
+        .map_err(|_| "Failed to acquire lock")?;
+    let Some(cancel) = batch.as_ref() else {
+        return Ok(false);
+    };
+    cancel.store(true, Ordering::SeqCst);
+    Ok(true)
+}
+
+#[tauri::command]
+async fn list_models(app: tauri::AppHandle) -> Result<Vec<serde_json::Value>, String> {
+    let config = load_config(app).await?;
+    ensure_online(&config)?;
+    if config.ai.provider == AIProvider::OpenAICompatible {
+        return list_openai_compatible_models(&config.ai.openai_compatible).await;
+    }
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let script_path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("scripts")
+            .join("copilot-models.mjs");
+
+        if !script_path.exists() {
+            return Err(format!("Models script not found: {}", script_path.display()));
+        }
+
+        let output = Command::new("node")
+            .arg(script_path)
+            .output()
+            .map_err(|err| format!("Failed to run models script: {err}"))?;
+        log_process_exit("models", &output.status);
+
+        let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(format!(
+                "Model list failed ({}): {stderr}\n{stdout}",
+                describe_exit_status(&output.status)
+            ));
+        }
+
+        let models = serde_json::from_str::<Vec<serde_json::Value>>(stdout.trim())
+            .map_err(|err| format!("Failed to parse models list: {err}"))?;
+        Ok(models)
+    })
+    .await
+    .map_err(|err| format!("Failed to run model list task: {err}"))?
+}
+
+/// `GET {endpoint}/models`, reshaped like the Copilot SDK's list
+/// (`id` and `name` on every entry), sorted by id.
+async fn list_openai_compatible_models(
+    ai_config: &AIEndpointConfig,
+) -> Result<Vec<serde_json::Value>, String> {
+    let endpoint = ai_config.endpoint.trim().trim_end_matches('/');
+    if endpoint.is_empty() {
+        return Err("AI endpoint not configured (ai.openaiCompatible.endpoint)".to_string());
+    }
+
+    let mut request = reqwest::Client::new().get(format!("{endpoint}/models"));
+    if !ai_config.api_key.is_empty() {
+        request = request.header("Authorization", format!("Bearer {}", ai_config.api_key));
+    }
+    let response = request
+        .send()
+        .await
+        .map_err(|err| format!("Failed to call models API: {err}"))?;
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        return Err(format!("Models API failed ({status}): {body}"));
+    }
+
+    let body: serde_json::Value = response
+        .json()
+        .await
+        .map_err(|err| format!("Failed to parse models response: {err}"))?;
+    let entries = body
+        .get("data")
+        .and_then(|data| data.as_array())
+        .ok_or("Models response has no data array")?;
+
+    let mut models: Vec<serde_json::Value> = entries
+        .iter()
+        .filter_map(|entry| {
+            let id = entry.get("id")?.as_str()?.to_string();
+            let mut model = entry.clone();
+            if model.get("name").is_none() {
+                model["name"] = serde_json::Value::String(id);
+            }
+            Some(model)
+        })
+        .collect();
+    models.sort_by(|a, b| a["id"].as_str().cmp(&b["id"].as_str()));
+    Ok(models)
+}
+
+/// whisper.cpp's language table, in its own order.
+const WHISPER_LANGUAGES: &[(&str, &str)] = &[
+    ("en", "English"),
+    ("zh", "Chinese"),
+    ("de", "German"),
+    ("es", "Spanish"),
+    ("ru", "Russian"),
+    ("ko", "Korean"),
+    ("fr", "French"),
+    ("ja", "Japanese"),
+    ("pt", "Portuguese"),
+    ("tr", "Turkish"),
+    ("pl", "Polish"),
+    ("ca", "Catalan"),
+    ("nl", "Dutch"),
+    ("ar", "Arabic"),
+    ("sv", "Swedish"),
+    ("it", "Italian"),
+    ("id", "Indonesian"),
+    ("hi", "Hindi"),
+    ("fi", "Finnish"),
+    ("vi", "Vietnamese"),
+    ("he", "Hebrew"),
+    ("uk", "Ukrainian"),
+    ("el", "Greek"),
+    ("ms", "Malay"),
+    ("cs", "Czech"),
+    ("ro", "Romanian"),
+    ("da", "Danish"),
+    ("hu", "Hungarian"),
+    ("ta", "Tamil"),
+    ("no", "Norwegian"),
+    ("th", "Thai"),
+    ("ur", "Urdu"),
+    ("hr", "Croatian"),
+    ("bg", "Bulgarian"),
+    ("lt", "Lithuanian"),
+    ("la", "Latin"),
+    ("mi", "Maori"),
+    ("ml", "Malayalam"),
+    ("cy", "Welsh"),
+    ("sk", "Slovak"),
+    ("te", "Telugu"),
+    ("fa", "Persian"),
+    ("lv", "Latvian"),
+    ("bn", "Bengali"),
+    ("sr", "Serbian"),
+    ("az", "Azerbaijani"),
+    ("sl", "Slovenian"),
+    ("kn", "Kannada"),
+    ("et", "Estonian"),
+    ("mk", "Macedonian"),
+    ("br", "Breton"),
+    ("eu", "Basque"),
+    ("is", "Icelandic"),
+    ("hy", "Armenian"),
+    ("ne", "Nepali"),
+    ("mn", "Mongolian"),
+    ("bs", "Bosnian"),
+    ("kk", "Kazakh"),
+    ("sq", "Albanian"),
+    ("sw", "Swahili"),
+    ("gl", "Galician"),
+    ("mr", "Marathi"),
+    ("pa", "Punjabi"),
+    ("si", "Sinhala"),
+    ("km", "Khmer"),
+    ("sn", "Shona"),
+    ("yo", "Yoruba"),
+    ("so", "Somali"),
+    ("af", "Afrikaans"),
+    ("oc", "Occitan"),
+    ("ka", "Georgian"),
+    ("be", "Belarusian"),
+    ("tg", "Tajik"),
+    ("sd", "Sindhi"),
+    ("gu", "Gujarati"),
+    ("am", "Amharic"),
+    ("yi", "Yiddish"),
+    ("lo", "Lao"),
+    ("uz", "Uzbek"),
+    ("fo", "Faroese"),
+    ("ht", "Haitian Creole"),
+    ("ps", "Pashto"),
+    ("tk", "Turkmen"),
+    ("nn", "Nynorsk"),
+    ("mt", "Maltese"),
+    ("sa", "Sanskrit"),
+    ("lb", "Luxembourgish"),
+    ("my", "Myanmar"),
+    ("bo", "Tibetan"),
+    ("tl", "Tagalog"),
+    ("mg", "Malagasy"),
+    ("as", "Assamese"),
+    ("tt", "Tatar"),
+    ("haw", "Hawaiian"),
+    ("ln", "Lingala"),
+    ("ha", "Hausa"),
+    ("ba", "Bashkir"),
+    ("jw", "Javanese"),
+    ("su", "Sundanese"),
+    ("yue", "Cantonese"),
+];
+
+/// Languages most remote transcription APIs handle well.
+const REMOTE_LANGUAGE_CODES: &[&str] = &[
+    "en", "zh", "de", "es", "fr", "it", "ja", "ko", "nl", "pl", "pt", "ru", "sv", "tr", "uk",
+    "ar", "hi", "id", "vi", "cs", "da", "fi", "el", "he", "hu", "no", "ro", "th",
+];
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LanguageOption {
+    code: String,
+    name: String,
+}
+
+/// Language choices for the configured provider, with `auto` first. English-only
+/// (`.en`) local models only offer English.
+#[tauri::command]
+async fn supported_languages(app: tauri::AppHandle) -> Result<Vec<LanguageOption>, String> {
+    let config = load_config(app.clone()).await?;
+
+    let codes: Vec<&str> = match config.transcription.provider {
+        TranscriptionProvider::OpenAICompatible => REMOTE_LANGUAGE_CODES.to_vec(),
+        TranscriptionProvider::Local | TranscriptionProvider::Auto => {
+            let model_name = models_root(&app, &config)
+                .and_then(|root| {
+                    resolve_model_path_with_selection(
+                        config.effective_model_path(),
+                        &config.transcription.local.model_name,
+                        &root,
+                    )
+                })
+            .ok()
+            .and_then(|path| path.file_name().map(|name| name.to_string_lossy().to_string()))
+            .unwrap_or_else(|| config.transcription.local.model_name.clone());
+
+            if is_english_only_model(&model_name) {
+                vec!["en"]
+            } else {
+                WHISPER_LANGUAGES.iter().map(|(code, _)| *code).collect()
+            }
+        }
+    };
+
+    let mut options = vec![LanguageOption {
+        code: "auto".to_string(),
+        name: "Auto-detect".to_string(),
+    }];
+    options.extend(codes.into_iter().filter_map(|code| {
+        WHISPER_LANGUAGES
+            .iter()
+            .find(|(known, _)| *known == code)
+            .map(|(code, name)| LanguageOption {
+                code: code.to_string(),
+                name: name.to_string(),
+            })
+    }));
+    Ok(options)
+}
+
+/// `ggml-base.en.bin`, `medium.en`, `small.en-q5_1` and friends.
+fn is_english_only_model(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    name.ends_with(".en") || name.contains(".en.") || name.contains(".en-")
+}
+
+#[tauri::command]
 async fn list_local_models(model_dir: String) -> Result<Vec<String>, String> {
     tauri::async_runtime::spawn_blocking(move || {
-        let trimmed = model_dir.trim();
-        if trimmed.is_empty() {
-            return Ok(Vec::new());
+        let trimmed = model_dir.trim();
+        if trimmed.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let path = Path::new(trimmed);
+        if path.is_file() {
+            let name = path
+                .file_name()
+                .and_then(|value| value.to_str())
+                .unwrap_or("")
+                .to_string();
+            return Ok(if name.is_empty() { Vec::new() } else { vec![name] });
+        }
+
+        if !path.is_dir() {
+            return Ok(Vec::new());
+        }
+
+        let mut names = Vec::new();
+        for entry in fs::read_dir(path)
+            .map_err(|err| format!("Failed to read model dir: {err}"))? {
+            let entry = entry.map_err(|err| format!("Failed to read model dir: {err}"))?;
+            let entry_path = entry.path();
+            if entry_path.extension().and_then(|ext| ext.to_str()) == Some("bin") {
+                if let Some(name) = entry_path.file_name().and_then(|value| value.to_str()) {
+                    names.push(name.to_string());
+                }
+            }
+        }
+
+        names.sort();
+        Ok(names)
+    })
+    .await
+    .map_err(|err| format!("Failed to list local models task: {err}"))?
+}
+
+#[tauri::command]
+async fn enhance_text(app: tauri::AppHandle, text: String, model: String) -> Result<String, String> {
+    tauri::async_runtime::spawn_blocking(move || enhance_text_sync(app, text, model))
+        .await
+        .map_err(|err| format!("Failed to enhance text task: {err}"))?
+}
+
+fn enhance_text_sync(app: tauri::AppHandle, text: String, model: String) -> Result<String, String> {
+    let temp_dir = voxii_temp_dir()?;
+
+    let id = uuid::Uuid::new_v4().to_string();
+    let input_path = temp_dir.join(format!("{id}_enhance.json"));
+
+    let payload = serde_json::json!({
+        "text": text,
+        "model": ai_task_model(&app, "enhance", model),
+        "systemPrompt": ai_system_prompt(&app, "enhance")
+    });
+
+    fs::write(&input_path, payload.to_string())
+        .map_err(|err| format!("Failed to write enhance payload: {err}"))?;
+
+    let script_path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("scripts")
+        .join("copilot-enhance.mjs");
+
+    if !script_path.exists() {
+        return Err(format!("Enhance script not found: {}", script_path.display()));
+    }
+
+    let _permit = acquire_ai_permit(&app, "enhance-queued", serde_json::json!({ "meetingId": null }))?;
+    let output = app
+        .state::<AppState>()
+        .child_jobs
+        .output(JobKind::Enhance, Command::new("node").arg(script_path).arg(&input_path))
+        .map_err(|err| format!("Failed to run Copilot SDK: {err}"))?
+        .ok_or("Enhance was cancelled")?;
+    log_process_exit("enhance", &output.status);
+
+    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+    let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+
+    if !output.status.success() {
+        return Err(format!(
+            "Copilot SDK failed ({}).\nstdout: {}\nstderr: {}",
+            describe_exit_status(&output.status),
+            stdout,
+            stderr
+        ));
+    }
+
+    Ok(final_script_content(&stdout))
+}
+
+#[tauri::command]
+fn start_enhance_stream(
+    app: tauri::AppHandle,
+    meeting_id: String,
+    selection_id: String,
+    text: String,
+    model: String,
+) -> Result<(), String> {
+    let temp_dir = voxii_temp_dir()?;
+
+    let id = uuid::Uuid::new_v4().to_string();
+    let input_path = temp_dir.join(format!("{id}_enhance.json"));
+
+    let payload = serde_json::json!({
+        "text": text,
+        "model": ai_task_model(&app, "enhance", model),
+        "systemPrompt": ai_system_prompt(&app, "enhance")
+    });
+
+    fs::write(&input_path, payload.to_string())
+        .map_err(|err| format!("Failed to write enhance payload: {err}"))?;
+
+    let script_path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("scripts")
+        .join("copilot-enhance.mjs");
+
+    if !script_path.exists() {
+        return Err(format!("Enhance script not found: {}", script_path.display()));
+    }
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let _permit = match acquire_ai_permit(
+            &app,
+            "enhance-queued",
+            serde_json::json!({ "meetingId": meeting_id, "selectionId": selection_id }),
+        ) {
+            Ok(permit) => permit,
+            Err(err) => {
+                let _ = app.emit("enhance-error", err);
+                return;
+            }
+        };
+        let mut child = match Command::new("node")
+            .env("STREAMING", "1")
+            .arg(script_path)
+            .arg(&input_path)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+        {
+            Ok(child) => child,
+            Err(err) => {
+                let _ = app.emit(
+                    "enhance-error",
+                    format!("Failed to start Copilot SDK: {err}"),
+                );
+                return;
+            }
+        };
+
+        if let Some(stderr) = child.stderr.take() {
+            let app_handle = app.clone();
+            tauri::async_runtime::spawn_blocking(move || {
+                for line in lossy_lines(stderr) {
+                    let _ = app_handle.emit("summary-log", line);
+                }
+            });
+        }
+
+        let mut final_text: Option<String> = None;
+
+        let stdout_pipe = child.stdout.take();
+        let run_id = app.state::<AppState>().child_jobs.track(JobKind::Enhance, child);
+        if let Some(stdout) = stdout_pipe {
+            for line in lossy_lines(stdout) {
+                let trimmed = line.trim_end().to_string();
+                if trimmed.is_empty() {
+                    continue;
+                }
+                if let Ok(value) = serde_json::from_str::<serde_json::Value>(&trimmed) {
+                    if value.get("type").and_then(|v| v.as_str()) == Some("final") {
+                        if let Some(content) = value.get("content").and_then(|v| v.as_str()) {
+                            final_text = Some(content.to_string());
+                        }
+                    }
+
+                    let payload = serde_json::json!({
+                        "meetingId": meeting_id,
+                        "selectionId": selection_id,
+                        "event": value
+                    });
+                    let _ = app.emit("enhance-delta", payload);
+                } else {
+                    let _ = app.emit("summary-log", trimmed);
+                }
+            }
+        }
+
+        // `None` when cancel_all_jobs killed it, which is no failure.
+        let child = app.state::<AppState>().child_jobs.untrack(&run_id);
+        if let Some(Ok(output)) = child.map(Child::wait_with_output) {
+            log_process_exit("enhance-stream", &output.status);
+            if !output.status.success() {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                let _ = app.emit(
+                    "enhance-error",
+                    format!(
+                        "Copilot SDK failed ({}): {stderr}",
+                        describe_exit_status(&output.status)
+                    ),
+                );
+            }
+        }
+
+        let _ = app.emit(
+            "enhance-done",
+            serde_json::json!({
+                "meetingId": meeting_id,
+                "selectionId": selection_id,
+                "text": final_text
+            }),
+        );
+    });
+
+    Ok(())
+}
+
+#[tauri::command]
+async fn clean_transcript(app: tauri::AppHandle, text: String, model: String) -> Result<String, String> {
+    tauri::async_runtime::spawn_blocking(move || clean_transcript_sync(app, text, model))
+        .await
+        .map_err(|err| format!("Failed to clean transcript task: {err}"))?
+}
+
+fn clean_transcript_sync(app: tauri::AppHandle, text: String, model: String) -> Result<String, String> {
+    let system_prompt = ai_system_prompt(&app, "clean");
+    run_clean_transcript(app, text, model, system_prompt)
+}
+
+fn run_clean_transcript(
+    app: tauri::AppHandle,
+    text: String,
+    model: String,
+    system_prompt: Option<String>,
+) -> Result<String, String> {
+    let temp_dir = voxii_temp_dir()?;
+
+    let id = uuid::Uuid::new_v4().to_string();
+    let input_path = temp_dir.join(format!("{id}_clean_transcript.json"));
+
+    let payload = serde_json::json!({
+        "text": text,
+        "model": ai_task_model(&app, "clean", model),
+        "systemPrompt": system_prompt
+    });
+
+    fs::write(&input_path, payload.to_string())
+        .map_err(|err| format!("Failed to write transcript payload: {err}"))?;
+
+    let script_path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("scripts")
+        .join("copilot-clean-transcript.mjs");
+
+    if !script_path.exists() {
+        return Err(format!(
+            "Clean transcript script not found: {}",
+            script_path.display()
+        ));
+    }
+
+    let _permit = acquire_ai_permit(
+        &app,
+        "clean-transcript-queued",
+        serde_json::json!({ "meetingId": null }),
+    )?;
+    let output = app
+        .state::<AppState>()
+        .child_jobs
+        .output(JobKind::Clean, Command::new("node").arg(script_path).arg(&input_path))
+        .map_err(|err| format!("Failed to run Copilot SDK: {err}"))?
+        .ok_or("Transcript cleanup was cancelled")?;
+    log_process_exit("clean-transcript", &output.status);
+
+    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+    let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+
+    if !output.status.success() {
+        return Err(format!(
+            "Copilot SDK failed ({}).\nstdout: {}\nstderr: {}",
+            describe_exit_status(&output.status),
+            stdout,
+            stderr
+        ));
+    }
+
+    Ok(final_script_content(&stdout))
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "lowercase")]
+enum WordEditKind {
+    Insert,
+    Delete,
+    Replace,
+}
+
+/// One changed run of words. `position` is the word index in the original text.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WordEdit {
+    kind: WordEditKind,
+    position: usize,
+    before: String,
+    after: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CleanTranscriptDiff {
+    cleaned: String,
+    diff: Vec<WordEdit>,
+}
+
+/// Like `clean_transcript`, but also reports what the cleanup changed.
+#[tauri::command]
+async fn clean_transcript_with_diff(
+    app: tauri::AppHandle,
+    text: String,
+    model: String,
+) -> Result<CleanTranscriptDiff, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let cleaned = clean_transcript_sync(app, text.clone(), model)?;
+        let diff = diff_words(&text, &cleaned);
+        Ok(CleanTranscriptDiff { cleaned, diff })
+    })
+    .await
+    .map_err(|err| format!("Failed to clean transcript task: {err}"))?
+}
+
+/// Above this many LCS cells the changed middle is reported as one replacement.
+const MAX_DIFF_CELLS: usize = 16_000_000;
+
+/// Word-level LCS diff, with adjacent deletions and insertions merged into
+/// replacements.
+fn diff_words(before: &str, after: &str) -> Vec<WordEdit> {
+    let a: Vec<&str> = before.split_whitespace().collect();
+    let b: Vec<&str> = after.split_whitespace().collect();
+
+    // Common prefix/suffix don't need the table.
+    let prefix = a.iter().zip(&b).take_while(|(x, y)| x == y).count();
+    let suffix = a[prefix..]
+        .iter()
+        .rev()
+        .zip(b[prefix..].iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+    let a_mid = &a[prefix..a.len() - suffix];
+    let b_mid = &b[prefix..b.len() - suffix];
+
+    let mut edits = Vec::new();
+    let mut push = |position: usize, removed: &[&str], added: &[&str]| {
+        let kind = match (removed.is_empty(), added.is_empty()) {
+            (true, true) => return,
+            (true, false) => WordEditKind::Insert,
+            (false, true) => WordEditKind::Delete,
+            (false, false) => WordEditKind::Replace,
+        };
+        edits.push(WordEdit {
+            kind,
+            position,
+            before: removed.join(" "),
+            after: added.join(" "),
+        });
+    };
+
+    let (n, m) = (a_mid.len(), b_mid.len());
+    if (n + 1).saturating_mul(m + 1) > MAX_DIFF_CELLS {
+        push(prefix, a_mid, b_mid);
+        return edits;
+    }
+
+    // lcs[i * (m + 1) + j] = LCS length of a_mid[i..] and b_mid[j..].
+    let width = m + 1;
+    let mut lcs = vec![0u32; (n + 1) * width];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i * width + j] = if a_mid[i] == b_mid[j] {
+                lcs[(i + 1) * width + j + 1] + 1
+            } else {
+                lcs[(i + 1) * width + j].max(lcs[i * width + j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let (mut removed_start, mut added_start) = (0, 0);
+    while i < n || j < m {
+        if i < n && j < m && a_mid[i] == b_mid[j] {
+            push(prefix + removed_start, &a_mid[removed_start..i], &b_mid[added_start..j]);
+            i += 1;
+            j += 1;
+            removed_start = i;
+            added_start = j;
+        } else if j == m || (i < n && lcs[(i + 1) * width + j] >= lcs[i * width + j + 1]) {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    push(prefix + removed_start, &a_mid[removed_start..], &b_mid[added_start..]);
+    edits
+}
+
+#[tauri::command]
+fn start_clean_transcript_stream(
+    app: tauri::AppHandle,
+    meeting_id: String,
+    text: String,
+    model: String,
+) -> Result<(), String> {
+    let temp_dir = voxii_temp_dir()?;
+
+    let id = uuid::Uuid::new_v4().to_string();
+    let input_path = temp_dir.join(format!("{id}_clean_transcript.json"));
+
+    let payload = serde_json::json!({
+        "text": text,
+        "model": ai_task_model(&app, "clean", model),
+        "systemPrompt": ai_system_prompt(&app, "clean")
+    });
+
+    fs::write(&input_path, payload.to_string())
+        .map_err(|err| format!("Failed to write transcript payload: {err}"))?;
+
+    let script_path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("scripts")
+        .join("copilot-clean-transcript.mjs");
+
+    if !script_path.exists() {
+        return Err(format!(
+            "Clean transcript script not found: {}",
+            script_path.display()
+        ));
+    }
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let _permit = match acquire_ai_permit(
+            &app,
+            "clean-transcript-queued",
+            serde_json::json!({ "meetingId": meeting_id }),
+        ) {
+            Ok(permit) => permit,
+            Err(err) => {
+                let _ = app.emit("clean-transcript-error", err);
+                return;
+            }
+        };
+        let mut child = match Command::new("node")
+            .env("STREAMING", "1")
+            .arg(script_path)
+            .arg(&input_path)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+        {
+            Ok(child) => child,
+            Err(err) => {
+                let _ = app.emit(
+                    "clean-transcript-error",
+                    format!("Failed to start Copilot SDK: {err}"),
+                );
+                return;
+            }
+        };
+
+        if let Some(stderr) = child.stderr.take() {
+            let app_handle = app.clone();
+            tauri::async_runtime::spawn_blocking(move || {
+                for line in lossy_lines(stderr) {
+                    let _ = app_handle.emit("summary-log", line);
+                }
+            });
+        }
+
+        let mut final_text: Option<String> = None;
+
+        let stdout_pipe = child.stdout.take();
+        let run_id = app.state::<AppState>().child_jobs.track(JobKind::Clean, child);
+        if let Some(stdout) = stdout_pipe {
+            for line in lossy_lines(stdout) {
+                let trimmed = line.trim_end().to_string();
+                if trimmed.is_empty() {
+                    continue;
+                }
+                if let Ok(value) = serde_json::from_str::<serde_json::Value>(&trimmed) {
+                    if value.get("type").and_then(|v| v.as_str()) == Some("final") {
+                        if let Some(content) = value.get("content").and_then(|v| v.as_str()) {
+                            final_text = Some(content.to_string());
+                        }
+                    }
+
+                    let payload = serde_json::json!({
+                        "meetingId": meeting_id,
+                        "event": value
+                    });
+                    let _ = app.emit("clean-transcript-delta", payload);
+                } else {
+                    let _ = app.emit("summary-log", trimmed);
+                }
+            }
+        }
+
+        // `None` when cancel_all_jobs killed it, which is no failure.
+        let child = app.state::<AppState>().child_jobs.untrack(&run_id);
+        if let Some(Ok(output)) = child.map(Child::wait_with_output) {
+            log_process_exit("clean-transcript-stream", &output.status);
+            if !output.status.success() {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                let _ = app.emit(
+                    "clean-transcript-error",
+                    format!(
+                        "Copilot SDK failed ({}): {stderr}",
+                        describe_exit_status(&output.status)
+                    ),
+                );
+            }
+        }
+
+        let _ = app.emit(
+            "clean-transcript-done",
+            serde_json::json!({
+                "meetingId": meeting_id,
+                "text": final_text
+            }),
+        );
+    });
+
+    Ok(())
+}
+
+#[tauri::command]
+async fn load_config(app: tauri::AppHandle) -> Result<AppConfig, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let path = config_path(&app)?;
+        if !path.exists() {
+            let default_config = AppConfig::default();
+            save_config(&path, &default_config)?;
+            return Ok(default_config);
+        }
+
+        let raw = fs::read_to_string(&path)
+            .map_err(|err| format!("Failed to read config: {err}"))?;
+        let mut config = serde_json::from_str::<AppConfig>(&raw)
+            .map_err(|err| format!("Failed to parse config: {err}"))?;
+        
+        // Migrate from v1 if needed
+        if config.version < 2 {
+            config.migrate_from_v1();
+            // Save migrated config
+            let _ = save_config(&path, &config);
+        }
+        
+        Ok(config)
+    })
+    .await
+    .map_err(|err| format!("Failed to load config task: {err}"))?
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct EffectiveConfig {
+    /// `auto` runs locally, as in `transcribe_with_settings`; so does
+    /// everything in offline mode.
+    provider: TranscriptionProvider,
+    offline_mode: bool,
+    /// `None` means no language hint is sent.
+    language: Option<String>,
+    whisper_path: String,
+    /// The binary that will run; `whisperError` says why when none was found.
+    whisper_binary: Option<String>,
+    whisper_error: Option<String>,
+    model_path: String,
+    model_name: String,
+    models_root: String,
+    /// The model file that will load; `modelError` says why when none was found.
+    model_file: Option<String>,
+    model_error: Option<String>,
+    openai_endpoint: String,
+    openai_model: String,
+    streaming_enabled: bool,
+    chunk_duration_ms: u32,
+    overlap_ms: u32,
+    overlap_match_min_tokens: usize,
+    overlap_match_max_tokens: usize,
+    ai_model: String,
+    summary_sections: Vec<String>,
+    export_dir: String,
+}
+
+/// The settings Voxii will actually use: after migration, legacy-field
+/// fallbacks and defaults, with whisper and model paths resolved on disk.
+#[tauri::command]
+async fn effective_config(app: tauri::AppHandle) -> Result<EffectiveConfig, String> {
+    let config = load_config(app.clone()).await?;
+    let models_root = models_root(&app, &config)?;
+    tauri::async_runtime::spawn_blocking(move || {
+        let split = |resolved: Result<PathBuf, String>| match resolved {
+            Ok(path) => (Some(path.to_string_lossy().to_string()), None),
+            Err(err) => (None, Some(err)),
+        };
+        let (whisper_binary, whisper_error) =
+            split(resolve_whisper_path(config.effective_whisper_path()));
+        let local = &config.transcription.local;
+        let (model_file, model_error) = split(resolve_model_path_with_selection(
+            config.effective_model_path(),
+            &local.model_name,
+            &models_root,
+        ));
+        let streaming = &config.transcription.streaming;
+        let (min_tokens, max_tokens) = streaming.overlap_match_tokens();
+        let provider = match config.transcription.provider {
+            _ if config.offline_mode => TranscriptionProvider::Local,
+            TranscriptionProvider::Auto => TranscriptionProvider::Local,
+            provider => provider,
+        };
+        Ok(EffectiveConfig {
+            provider,
+            offline_mode: config.offline_mode,
+            language: config.effective_language(provider).map(str::to_string),
+            whisper_path: config.effective_whisper_path().to_string(),
+            whisper_binary,
+            whisper_error,
+            model_path: config.effective_model_path().to_string(),
+            model_name: local.model_name.clone(),
+            models_root: models_root.to_string_lossy().to_string(),
+            model_file,
+            model_error,
+            openai_endpoint: config.transcription.openai_compatible.endpoint.clone(),
+            openai_model: config.transcription.openai_compatible.model.clone(),
+            streaming_enabled: streaming.enabled,
+            chunk_duration_ms: streaming.chunk_duration_ms,
+            overlap_ms: streaming.overlap_ms,
+            overlap_match_min_tokens: min_tokens,
+            overlap_match_max_tokens: max_tokens,
+            ai_model: config.ai.default_model.clone(),
+            summary_sections: config.ai.effective_summary_sections(),
+            export_dir: export_dir(&config).to_string_lossy().to_string(),
+        })
+    })
+    .await
+    .map_err(|err| format!("Failed to resolve config task: {err}"))?
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct MergedConfig {
+    config: AppConfig,
+    /// Dotted camelCase paths whose value came from `incoming`.
+    changed: Vec<String>,
+}
+
+/// Merge a config from another machine into the current one without saving.
+/// Only keys present in `incoming` are considered, and they win field by
+/// field unless empty (`""`, `null`, `[]`, `{}`), so a config synced without
+/// secrets keeps the local keys and missing settings keep local values.
+#[tauri::command]
+async fn merge_config(
+    app: tauri::AppHandle,
+    incoming: serde_json::Value,
+) -> Result<MergedConfig, String> {
+    let current = load_config(app).await?;
+    // Parsing checks the shape; the raw JSON still says which keys were set.
+    let typed = serde_json::from_value::<AppConfig>(incoming.clone())
+        .map_err(|err| format!("Failed to parse incoming config: {err}"))?;
+    let incoming = if typed.version < 2 {
+        let before = serde_json::to_value(&typed)
+            .map_err(|err| format!("Failed to serialize config: {err}"))?;
+        let mut migrated = typed;
+        migrated.migrate_from_v1();
+        let after = serde_json::to_value(&migrated)
+            .map_err(|err| format!("Failed to serialize config: {err}"))?;
+        present_config_fields(after, &before, &incoming)
+    } else {
+        incoming
+    };
+    let mut merged = serde_json::to_value(&current)
+        .map_err(|err| format!("Failed to serialize config: {err}"))?;
+    let mut changed = Vec::new();
+    merge_config_value(&mut merged, incoming, "", &mut changed);
+    let config = serde_json::from_value::<AppConfig>(merged)
+        .map_err(|err| format!("Failed to parse merged config: {err}"))?;
+    Ok(MergedConfig { config, changed })
+}
+
+/// The parts of a migrated config that the file set itself (`raw`) or that
+/// the migration filled in (differs from `before`), dropping serde defaults.
+fn present_config_fields(
+    after: serde_json::Value,
+    before: &serde_json::Value,
+    raw: &serde_json::Value,
+) -> serde_json::Value {
+    use serde_json::Value;
+    let Value::Object(fields) = after else {
+        return after;
+    };
+    let kept = fields
+        .into_iter()
+        .filter_map(|(key, value)| {
+            let before = before.get(&key).unwrap_or(&Value::Null);
+            match raw.get(&key) {
+                Some(raw) => Some((key, present_config_fields(value, before, raw))),
+                None if value != *before => Some((key, value)),
+                None => None,
+            }
+        })
+        .collect();
+    Value::Object(kept)
+}
+
+fn merge_config_value(
+    local: &mut serde_json::Value,
+    incoming: serde_json::Value,
+    path: &str,
+    changed: &mut Vec<String>,
+) {
+    use serde_json::Value;
+    let is_empty = |value: &Value| match value {
+        Value::Null => true,
+        Value::String(text) => text.trim().is_empty(),
+        Value::Array(items) => items.is_empty(),
+        Value::Object(fields) => fields.is_empty(),
+        _ => false,
+    };
+    match (local, incoming) {
+        (Value::Object(local), Value::Object(incoming)) => {
+            for (key, value) in incoming {
+                let path = if path.is_empty() { key.clone() } else { format!("{path}.{key}") };
+                match local.get_mut(&key) {
+                    Some(existing) => merge_config_value(existing, value, &path, changed),
+                    None if !is_empty(&value) => {
+                        local.insert(key, value);
+                        changed.push(path);
+                    }
+                    None => {}
+                }
+            }
+        }
+        (local, incoming) => {
+            if !is_empty(&incoming) && *local != incoming {
+                *local = incoming;
+                changed.push(path.to_string());
+            }
+        }
+    }
+}
+
+/// Emits `config-saved { version }` or `config-save-failed { error }` so other
+/// windows and background tasks can pick up settings changes.
+#[tauri::command]
+async fn save_config_command(
+    app: tauri::AppHandle,
+    config: AppConfig,
+) -> Result<(), String> {
+    let version = config.version;
+    let result = match validate_config(&config) {
+        Ok(()) => tauri::async_runtime::spawn_blocking({
+            let app = app.clone();
+            move || {
+                let path = config_path(&app)?;
+                save_config(&path, &config)
+            }
+        })
+        .await
+        .map_err(|err| format!("Failed to save config task: {err}"))
+        .and_then(|result| result),
+        Err(err) => Err(err),
+    };
+
+    match &result {
+        Ok(()) => {
+            let _ = app.emit("config-saved", serde_json::json!({ "version": version }));
+        }
+        Err(err) => {
+            let _ = app.emit("config-save-failed", serde_json::json!({ "error": err }));
+        }
+    }
+    result
+}
+
+/// Reject out-of-range values before they reach disk (and whisper).
+fn validate_config(config: &AppConfig) -> Result<(), String> {
+    let mut errors = Vec::new();
+    let local = &config.transcription.local;
+
+    let in_range = |value: Option<f32>, min: f32, max: f32| {
+        value.is_none_or(|value| value.is_finite() && (min..=max).contains(&value))
+    };
+    if !in_range(local.temperature, 0.0, 1.0) {
+        errors.push("transcription.local.temperature must be between 0 and 1");
+    }
+    if !in_range(local.entropy_thold, 0.0, f32::MAX) {
+        errors.push("transcription.local.entropyThold must be 0 or greater");
+    }
+    if !in_range(local.logprob_thold, f32::MIN, 0.0) {
+        errors.push("transcription.local.logprobThold must be 0 or less");
+    }
+    if !in_range(local.word_thold, 0.0, 1.0) {
+        errors.push("transcription.local.wordThold must be between 0 and 1");
+    }
+    if local.max_segment_len > MAX_SEGMENT_LEN_LIMIT {
+        errors.push("transcription.local.maxSegmentLen must be between 0 and 1000");
+    }
+    let (min_tokens, max_tokens) = config.transcription.streaming.overlap_match_tokens();
+    if min_tokens == 0 || min_tokens > max_tokens || max_tokens > MAX_OVERLAP_MATCH_TOKENS {
+        errors.push(
+            "transcription.streaming.overlapMatchMinTokens/overlapMatchMaxTokens must satisfy 1 <= min <= max <= 50",
+        );
+    }
+    if !in_range(config.transcription.streaming.low_confidence_threshold, 0.0, 1.0) {
+        errors.push("transcription.streaming.lowConfidenceThreshold must be between 0 and 1");
+    }
+    if config.ai.provider == AIProvider::OpenAICompatible
+        && config.ai.openai_compatible.endpoint.trim().is_empty()
+    {
+        errors.push("ai.openaiCompatible.endpoint is required when ai.provider is openai-compatible");
+    }
+    if config.ai.prompts.keys().any(|kind| !AI_PROMPT_KINDS.contains(&kind.as_str())) {
+        errors.push("ai.prompts keys must be one of summary, enhance, clean, actions, questions");
+    }
+    if config.ai.models.keys().any(|kind| !AI_PROMPT_KINDS.contains(&kind.as_str())) {
+        errors.push("ai.models keys must be one of summary, enhance, clean, actions, questions");
+    }
+    if config.export.redaction_patterns.iter().any(|pattern| Regex::new(pattern).is_err()) {
+        errors.push("export.redactionPatterns must all be valid regular expressions");
+    }
+    if config.server.enabled && config.server.token.trim().is_empty() {
+        errors.push("server.token is required when server.enabled is true");
+    }
+    if config.server.port == 0 {
+        errors.push("server.port must be between 1 and 65535");
+    }
+    if config.export.auto_export
+        && sync_export_extension(&config.export.auto_export_format).is_err()
+    {
+        errors.push("export.autoExportFormat must be markdown or html");
+    }
+    if config.ui.save_debounce_ms > MAX_SAVE_DEBOUNCE_MS {
+        errors.push("ui.saveDebounceMs must be between 0 and 10000");
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("Invalid config: {}", errors.join("; ")))
+    }
+}
+
+/// Re-run migration on the stored config, clear consumed legacy fields and
+/// save. An unparseable config is backed up and replaced with defaults.
+#[tauri::command]
+async fn repair_config(app: tauri::AppHandle) -> Result<ConfigRepairReport, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let path = config_path(&app)?;
+        if !path.exists() {
+            let config = AppConfig::default();
+            save_config(&path, &config)?;
+            return Ok(ConfigRepairReport {
+                changes: vec!["Created default config".to_string()],
+                config,
+            });
+        }
+
+        let raw = fs::read_to_string(&path)
+            .map_err(|err| format!("Failed to read config: {err}"))?;
+        let (mut config, mut changes) = match serde_json::from_str::<AppConfig>(&raw) {
+            Ok(config) => (config, Vec::new()),
+            Err(err) => {
+                let backup = path.with_extension("json.bak");
+                fs::write(&backup, &raw)
+                    .map_err(|err| format!("Failed to back up config: {err}"))?;
+                (
+                    AppConfig::default(),
+                    vec![format!(
+                        "Config was unreadable ({err}); backed up to {} and reset to defaults",
+                        backup.display()
+                    )],
+                )
+            }
+        };
+
+        changes.extend(config.migrate_from_v1());
+        save_config(&path, &config)?;
+        Ok(ConfigRepairReport { changes, config })
+    })
+    .await
+    .map_err(|err| format!("Failed to repair config task: {err}"))?
+}
+
+#[tauri::command]
+async fn load_meetings(app: tauri::AppHandle) -> Result<Vec<MeetingRecord>, String> {
+    tauri::async_runtime::spawn_blocking(move || load_meetings_sync(&app))
+        .await
+        .map_err(|err| format!("Failed to load meetings task: {err}"))?
+}
+
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+struct MeetingsNormalizeReport {
+    total: usize,
+    /// Records whose stored JSON changed (defaults filled, ids fixed, unknown keys dropped).
+    normalized: usize,
+    warnings: Vec<String>,
+    /// Copy of the file as it was, written before rewriting it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    backup_path: Option<String>,
+}
+
+/// Rewrite `meetings.json` in the current schema. Fields older files lack are
+/// filled in (including ones `load_meetings` requires, so files it rejects can
+/// be recovered) and duplicate ids are replaced. A record that still doesn't
+/// parse aborts without writing anything.
+#[tauri::command]
+async fn normalize_meetings(app: tauri::AppHandle) -> Result<MeetingsNormalizeReport, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        flush_pending_meetings(&app)?;
+        let path = meetings_path(&app)?;
+        if !path.exists() {
+            return Ok(MeetingsNormalizeReport::default());
+        }
+        let raw = fs::read_to_string(&path)
+            .map_err(|err| format!("Failed to read meetings: {err}"))?;
+        let records = serde_json::from_str::<Vec<serde_json::Value>>(&raw)
+            .map_err(|err| format!("Failed to parse meetings: {err}"))?;
+
+        let now = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true);
+        let mut report = MeetingsNormalizeReport {
+            total: records.len(),
+            ..Default::default()
+        };
+        let mut ids = std::collections::HashSet::new();
+        let mut meetings = Vec::with_capacity(records.len());
+        for (index, original) in records.into_iter().enumerate() {
+            let mut record = original.clone();
+            let object = record
+                .as_object_mut()
+                .ok_or_else(|| format!("Meeting #{index} is not an object"))?;
+            let missing = |object: &serde_json::Map<String, serde_json::Value>, key: &str| {
+                object.get(key).is_none_or(serde_json::Value::is_null)
+            };
+            for key in ["notes", "transcript", "summary"] {
+                if missing(object, key) {
+                    object.insert(key.to_string(), "".into());
+                }
+            }
+            if missing(object, "title") {
+                object.insert("title".to_string(), "Untitled meeting".into());
+            }
+            if missing(object, "id") {
+                object.insert("id".to_string(), uuid::Uuid::new_v4().to_string().into());
+            }
+            if missing(object, "createdAt") {
+                let created = object.get("updatedAt").cloned().filter(|v| !v.is_null());
+                object.insert("createdAt".to_string(), created.unwrap_or_else(|| now.clone().into()));
+            }
+            if missing(object, "updatedAt") {
+                let created = object["createdAt"].clone();
+                object.insert("updatedAt".to_string(), created);
+            }
+
+            let mut meeting = serde_json::from_value::<MeetingRecord>(record)
+                .map_err(|err| format!("Meeting #{index} is invalid: {err}"))?;
+            if meeting.id.trim().is_empty() || !ids.insert(meeting.id.clone()) {
+                let id = uuid::Uuid::new_v4().to_string();
+                report.warnings.push(format!(
+                    "Meeting #{index} ({}) had a missing or duplicate id {:?}; now {id}",
+                    meeting.title, meeting.id
+                ));
+                meeting.id = id.clone();
+                ids.insert(id);
+            }
+            if chrono::DateTime::parse_from_rfc3339(&meeting.created_at).is_err() {
+                report.warnings.push(format!(
+                    "Meeting {} has an unparseable createdAt {:?}",
+                    meeting.id, meeting.created_at
+                ));
+            }
+            if serde_json::to_value(&meeting).ok().as_ref() != Some(&original) {
+                report.normalized += 1;
+            }
+            meetings.push(meeting);
+        }
+
+        if report.normalized > 0 {
+            let backup = path.with_extension("json.bak");
+            fs::write(&backup, &raw)
+                .map_err(|err| format!("Failed to back up meetings: {err}"))?;
+            report.backup_path = Some(backup.to_string_lossy().to_string());
+            write_meetings_sync(&app, &mut meetings)?;
+        }
+        tracing::info!(
+            total = report.total,
+            normalized = report.normalized,
+            warnings = report.warnings.len(),
+            "meetings normalized"
+        );
+        Ok(report)
+    })
+    .await
+    .map_err(|err| format!("Failed to normalize meetings task: {err}"))?
+}
+
+/// With `ui.saveDebounceMs` set, the list is held and written once the window
+/// that the first held call opened has passed, so steady autosaves still land
+/// at that interval. Later calls in the window just replace the held list.
+#[tauri::command]
+async fn save_meetings(
+    app: tauri::AppHandle,
+    mut meetings: Vec<MeetingRecord>,
+) -> Result<SaveMeetingsResult, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let window = load_config_sync(&app)
+            .map(|config| config.ui.save_debounce_ms.min(MAX_SAVE_DEBOUNCE_MS))
+            .unwrap_or(0);
+        if window == 0 {
+            let written = write_meetings_sync(&app, &mut meetings)?;
+            if written {
+                queue_auto_export(&app, meetings);
+            }
+            return Ok(SaveMeetingsResult { written, queued: false });
+        }
+
+        let state = app.state::<AppState>();
+        let mut pending = state
+            .pending_meetings
+            .lock()
+            .map_err(|_| "Failed to acquire meetings lock")?;
+        if pending.replace(meetings).is_none() {
+            let app = app.clone();
+            std::thread::spawn(move || {
+                std::thread::sleep(Duration::from_millis(window));
+                if let Err(err) = flush_pending_meetings(&app) {
+                    tracing::error!("coalesced meetings save failed: {err}");
+                    let _ = app.emit("save-meetings-error", err);
+                }
+            });
+        }
+        Ok(SaveMeetingsResult { written: false, queued: true })
+    })
+    .await
+    .map_err(|err| format!("Failed to save meetings task: {err}"))?
+}
+
+/// Write a held `save_meetings` list, if any. The lock stays held through the
+/// write so readers never see the file from before it. A failed write keeps
+/// the list held for the next flush.
+fn flush_pending_meetings(app: &tauri::AppHandle) -> Result<bool, String> {
+    let state = app.state::<AppState>();
+    let mut pending = state
+        .pending_meetings
+        .lock()
+        .map_err(|_| "Failed to acquire meetings lock")?;
+    let Some(mut meetings) = pending.take() else {
+        return Ok(false);
+    };
+    match write_meetings_sync(app, &mut meetings) {
+        Ok(written) => {
+            if written {
+                queue_auto_export(app, meetings);
+            }
+            Ok(written)
+        }
+        Err(err) => {
+            *pending = Some(meetings);
+            Err(err)
+        }
+    }
+}
+
+#[tauri::command]
+async fn delete_meeting(app: tauri::AppHandle, meeting_id: String) -> Result<(), String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let mut meetings = load_meetings_sync(&app)?;
+        let index = meetings
+            .iter()
+            .position(|meeting| meeting.id == meeting_id)
+            .ok_or_else(|| format!("Meeting not found: {meeting_id}"))?;
+        let removed = meetings.remove(index);
+        write_meetings_sync(&app, &mut meetings)?;
+
+        if let Some(relative) = removed.audio_path.as_deref() {
+            let audio_file = voxii_data_dir(&app)?.join(relative);
+            if audio_file.is_file() {
+                fs::remove_file(&audio_file)
+                    .map_err(|err| format!("Failed to delete meeting audio: {err}"))?;
+            }
+        }
+
+        let _ = app.emit("meeting-deleted", serde_json::json!({ "meetingId": meeting_id }));
+        Ok(())
+    })
+    .await
+    .map_err(|err| format!("Failed to delete meeting task: {err}"))?
+}
+
+/// Drop a meeting's transcript (and anything derived from it) while keeping
+/// summary, notes, action items and decisions. Returns the bytes freed.
+#[tauri::command]
+async fn purge_transcript(
+    app: tauri::AppHandle,
+    meeting_id: String,
+    include_audio: Option<bool>,
+) -> Result<u64, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let mut meetings = load_meetings_sync(&app)?;
+        let meeting = meetings
+            .iter_mut()
+            .find(|meeting| meeting.id == meeting_id)
+            .ok_or_else(|| format!("Meeting not found: {meeting_id}"))?;
+
+        let record_size = |meeting: &MeetingRecord| {
+            serde_json::to_vec(meeting).map(|bytes| bytes.len() as u64).unwrap_or(0)
+        };
+        let before = record_size(meeting);
+        meeting.transcript.clear();
+        // Segments, history and chapter offsets all carry or point into the
+        // transcript text, so they go with it.
+        meeting.segments.clear();
+        meeting.transcript_history.clear();
+        meeting.chapters.clear();
+        meeting.formatted_transcript = None;
+        meeting.formatted_source_hash = None;
+
+        let mut audio_file = None;
+        if include_audio.unwrap_or(false) {
+            if let Some(relative) = meeting.audio_path.take() {
+                audio_file = Some(voxii_data_dir(&app)?.join(relative));
+            }
+        }
+
+        meeting.updated_at =
+            chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true);
+        let mut freed = before.saturating_sub(record_size(meeting));
+        let updated = meeting.clone();
+        write_meetings_sync(&app, &mut meetings)?;
+
+        if let Some(audio_file) = audio_file.filter(|path| path.is_file()) {
+            let size = fs::metadata(&audio_file).map(|meta| meta.len()).unwrap_or(0);
+            fs::remove_file(&audio_file)
+                .map_err(|err| format!("Failed to delete meeting audio: {err}"))?;
+            freed += size;
+        }
+
+        let _ = app.emit("meeting-updated", &updated);
+        Ok(freed)
+    })
+    .await
+    .map_err(|err| format!("Failed to purge transcript task: {err}"))?
+}
+
+/// Content hash of a meeting as currently stored (see `content_hash`), for
+/// cheap change detection without comparing whole records.
+#[tauri::command]
+async fn meeting_content_hash(app: tauri::AppHandle, meeting_id: String) -> Result<String, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let meetings = load_meetings_sync(&app)?;
+        let meeting = meetings
+            .iter()
+            .find(|meeting| meeting.id == meeting_id)
+            .ok_or_else(|| format!("Meeting not found: {meeting_id}"))?;
+        Ok(content_hash(meeting))
+    })
+    .await
+    .map_err(|err| format!("Failed to hash meeting task: {err}"))?
+}
+
+/// Describe how a child process exited. On Unix a process killed by a signal
+/// has no exit code, so report the signal instead of a misleading `-1`.
+fn describe_exit_status(status: &ExitStatus) -> String {
+    if let Some(code) = status.code() {
+        return format!("code {code}");
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::ExitStatusExt;
+        if let Some(signal) = status.signal() {
+            let hint = match signal {
+                9 => " (likely OOM)",
+                6 => " (aborted)",
+                11 => " (segmentation fault)",
+                _ => "",
+            };
+            return format!("terminated by signal {signal}{hint}");
+        }
+    }
+
+    "code -1".to_string()
+}
+
+/// Trim whitespace and one pair of surrounding quotes, as left by pasting a
+/// path copied from a file manager ("Copy as path" on Windows).
+fn unquote_path(input: &str) -> &str {
+    let trimmed = input.trim();
+    ['"', '\'']
+        .iter()
+        .find_map(|quote| trimmed.strip_prefix(*quote)?.strip_suffix(*quote))
+        .map(str::trim)
+        .unwrap_or(trimmed)
+}
+
+fn resolve_whisper_path(input: &str) -> Result<PathBuf, String> {
+    let input = unquote_path(input);
+    if input.is_empty() {
+        return Err("Whisper path not configured".to_string());
+    }
+    
+    let path = Path::new(input);
+    if path.is_file() {
+        return Ok(path.to_path_buf());
+    }
+    if path.is_dir() {
+        // Cross-platform binary candidates
+        #[cfg(target_os = "windows")]
+        let candidates = [
+            "whisper-cli.exe",
+            "main.exe",
+            "whisper.exe",
+        ];
+        #[cfg(target_os = "macos")]
+        let candidates = [
+            "whisper-cli",
+            "whisper",
+            "main",
+        ];
+        #[cfg(target_os = "linux")]
+        let candidates = [
+            "whisper-cli",
+            "whisper",
+            "main",
+        ];
+        #[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
+        let candidates = [
+            "whisper-cli",
+            "whisper",
+            "main",
+        ];
+        
+        for name in candidates {
+            let candidate = path.join(name);
+            if candidate.is_file() {
+                return Ok(candidate);
+            }
+        }
+    }
+    Err(format!(
+        "Whisper binary not found. Provide the whisper binary path or folder containing it. Got: {}",
+        input
+    ))
+}
+
+/// Where `./…` and `models/…` model paths are resolved from.
+fn models_root(app: &tauri::AppHandle, config: &AppConfig) -> Result<PathBuf, String> {
+    let data_dir = voxii_data_dir(app)?;
+    let root = config.transcription.local.models_root.trim();
+    if root.is_empty() {
+        Ok(data_dir)
+    } else {
+        // A relative root is itself taken relative to the app data dir.
+        Ok(data_dir.join(root))
+    }
+}
+
+/// Make a portable model path absolute. Only `./…` and `models/…` are treated
+/// as relative to `root`; anything else is returned as written.
+fn expand_model_path(input: &str, root: &Path) -> PathBuf {
+    // Match prefixes with either separator but join the original text, so
+    // backslashes inside a Unix file name survive.
+    let normalized = input.replace('\\', "/");
+    if normalized.starts_with("./") {
+        return root.join(&input[2..]);
+    }
+    if normalized.starts_with("models/") {
+        return root.join(input);
+    }
+    PathBuf::from(input)
+}
+
+fn resolve_model_path_with_selection(
+    base_path: &str,
+    selection: &str,
+    models_root: &Path,
+) -> Result<PathBuf, String> {
+    let base_trimmed = unquote_path(base_path);
+    if base_trimmed.is_empty() {
+        return Err("Model path not configured".to_string());
+    }
+
+    let selection_trimmed = unquote_path(selection);
+    if !selection_trimmed.is_empty() {
+        let selection_path = expand_model_path(selection_trimmed, models_root);
+        if selection_path.is_file() {
+            return Ok(selection_path);
+        }
+    }
+
+    let base = expand_model_path(base_trimmed, models_root);
+    let base = base.as_path();
+    if !selection_trimmed.is_empty() && base.is_dir() {
+        let candidate = base.join(selection_trimmed);
+        if candidate.is_file() {
+            return Ok(candidate);
+        }
+        // Friendly names like `medium.en` map to `ggml-medium.en.bin`.
+        if let Some(model) = known_whisper_model(selection_trimmed) {
+            let candidate = base.join(model.file_name());
+            if candidate.is_file() {
+                return Ok(candidate);
+            }
+            return Err(format!(
+                "Model {} is not downloaded yet (expected {}). Download it from {}",
+                model.name,
+                candidate.display(),
+                model.url()
+            ));
+        }
+        return Err(format!(
+            "Selected model not found: {}",
+            candidate.display()
+        ));
+    }
+
+    resolve_model_path(base)
+}
+
+/// Official whisper.cpp ggml models, by friendly name, with their approximate
+/// download size in MB.
+const WHISPER_MODELS: &[(&str, u64)] = &[
+    ("tiny", 75),
+    ("tiny.en", 75),
+    ("base", 142),
+    ("base.en", 142),
+    ("small", 466),
+    ("small.en", 466),
+    ("small.en-q8_0", 252),
+    ("medium", 1533),
+    ("medium.en", 1533),
+    ("medium-q8_0", 785),
+    ("medium.en-q5_0", 514),
+    ("medium.en-q8_0", 785),
+    ("large-v3", 3095),
+    ("large-v3-q5_0", 1080),
+    ("large-v3-turbo", 1624),
+    ("large-v3-turbo-q8_0", 874),
+];
+
+struct KnownWhisperModel {
+    name: &'static str,
+    size_mb: u64,
+}
+
+impl KnownWhisperModel {
+    fn file_name(&self) -> String {
+        format!("ggml-{}.bin", self.name)
+    }
+
+    fn url(&self) -> String {
+        format!(
+            "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/{}",
+            self.file_name()
+        )
+    }
+}
+
+fn known_whisper_model(name: &str) -> Option<KnownWhisperModel> {
+    let name = name.trim().trim_start_matches("ggml-").trim_end_matches(".bin");
+    WHISPER_MODELS
+        .iter()
+        .find(|(known, _)| known.eq_ignore_ascii_case(name))
+        .map(|&(name, size_mb)| KnownWhisperModel { name, size_mb })
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WhisperModelEntry {
+    name: String,
+    file_name: String,
+    url: String,
+    installed: bool,
+}
+
+/// The model catalog, marking which files already exist in `model_dir`.
+#[tauri::command]
+async fn known_models(model_dir: String) -> Result<Vec<WhisperModelEntry>, String> {
+    let dir = PathBuf::from(model_dir.trim());
+    Ok(WHISPER_MODELS
+        .iter()
+        .map(|&(name, size_mb)| {
+            let model = KnownWhisperModel { name, size_mb };
+            WhisperModelEntry {
+                name: model.name.to_string(),
+                installed: !model_dir.trim().is_empty() && dir.join(model.file_name()).is_file(),
+                file_name: model.file_name(),
+                url: model.url(),
+            }
+        })
+        .collect())
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ModelCatalogEntry {
+    name: String,
+    file_name: String,
+    installed: bool,
+    /// Bytes on disk when installed, otherwise the approximate download size.
+    size: u64,
+    download_url: Option<String>,
+    /// Installed file that isn't in the catalog.
+    custom: bool,
+}
+
+/// The model catalog merged with what's on disk in `model_dir`: catalog
+/// entries marked installed or not, then any other `.bin` files as `custom`.
+#[tauri::command]
+async fn model_catalog(model_dir: String) -> Result<Vec<ModelCatalogEntry>, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let trimmed = model_dir.trim();
+        let dir = Path::new(trimmed);
+        let on_disk = |file_name: &str| -> Option<u64> {
+            if trimmed.is_empty() {
+                return None;
+            }
+            fs::metadata(dir.join(file_name))
+                .ok()
+                .filter(|meta| meta.is_file())
+                .map(|meta| meta.len())
+        };
+
+        let mut entries: Vec<ModelCatalogEntry> = WHISPER_MODELS
+            .iter()
+            .map(|&(name, size_mb)| {
+                let model = KnownWhisperModel { name, size_mb };
+                let file_name = model.file_name();
+                let installed_size = on_disk(&file_name);
+                ModelCatalogEntry {
+                    name: model.name.to_string(),
+                    installed: installed_size.is_some(),
+                    size: installed_size.unwrap_or(model.size_mb * 1_000_000),
+                    download_url: Some(model.url()),
+                    file_name,
+                    custom: false,
+                }
+            })
+            .collect();
+
+        if dir.is_dir() && !trimmed.is_empty() {
+            let mut custom = Vec::new();
+            for entry in fs::read_dir(dir)
+                .map_err(|err| format!("Failed to read model dir: {err}"))?
+                .flatten()
+            {
+                let path = entry.path();
+                if path.extension().and_then(|ext| ext.to_str()) != Some("bin") {
+                    continue;
+                }
+                let Some(file_name) = path.file_name().and_then(|name| name.to_str()) else {
+                    continue;
+                };
+                if entries.iter().any(|known| known.file_name == file_name) {
+                    continue;
+                }
+                custom.push(ModelCatalogEntry {
+                    name: file_name.trim_end_matches(".bin").to_string(),
+                    file_name: file_name.to_string(),
+                    installed: true,
+                    size: entry.metadata().map(|meta| meta.len()).unwrap_or(0),
+                    download_url: None,
+                    custom: true,
+                });
+            }
+            custom.sort_by(|a, b| a.file_name.cmp(&b.file_name));
+            entries.extend(custom);
+        }
+
+        Ok(entries)
+    })
+    .await
+    .map_err(|err| format!("Failed to build model catalog task: {err}"))?
+}
+
+/// When given a directory, prefer a sensible default model if present.
+/// This keeps startup simple (point to the models folder) while allowing
+/// better tradeoffs than always picking the largest file.
+const PREFERRED_MODEL_FILES: [&str; 8] = [
+    "ggml-medium.en-q8_0.bin",
+    "ggml-medium.en.bin",
+    "ggml-medium.en-q5_0.bin",
+    "ggml-medium-q8_0.bin",
+    "ggml-medium.bin",
+    "ggml-small.en-q8_0.bin",
+    "ggml-small.en.bin",
+    "ggml-base.en.bin",
+];
+
+/// The first `PREFERRED_MODEL_FILES` entry next to `failed` other than
+/// `failed` itself.
+fn fallback_model_path(failed: &Path) -> Option<PathBuf> {
+    let dir = failed.parent()?;
+    let failed_name = failed.file_name()?.to_string_lossy().to_ascii_lowercase();
+    PREFERRED_MODEL_FILES
+        .iter()
+        .filter(|name| **name != failed_name)
+        .map(|name| dir.join(name))
+        .find(|candidate| candidate.is_file())
+}
+
+/// whisper.cpp's messages for a missing, truncated or corrupt model file.
+fn whisper_model_load_failed(stderr: &str) -> bool {
+    let stderr = stderr.to_ascii_lowercase();
+    [
+        "failed to load model",
+        "bad magic",
+        "failed to initialize whisper context",
+        "invalid model data",
+        "not all tensors loaded",
+    ]
+    .iter()
+    .any(|marker| stderr.contains(marker))
+}
+
+fn resolve_model_path(path: &Path) -> Result<PathBuf, String> {
+    if path.is_file() {
+        return Ok(path.to_path_buf());
+    }
+    let search_dirs = if path.is_dir() {
+        vec![path.to_path_buf(), path.join("models")]
+    } else {
+        vec![]
+    };
+
+    let mut candidates: Vec<(PathBuf, u64, String)> = Vec::new();
+    for dir in search_dirs {
+        if let Ok(entries) = fs::read_dir(&dir) {
+            for entry in entries.flatten() {
+                let p = entry.path();
+                if !p.extension().is_some_and(|e| e.eq_ignore_ascii_case("bin")) {
+                    continue;
+                }
+                let size = match p.metadata() {
+                    Ok(meta) => meta.len(),
+                    Err(_) => continue,
+                };
+                let file_name = entry.file_name().to_string_lossy().to_ascii_lowercase();
+                candidates.push((p, size, file_name));
+            }
+        }
+    }
+
+    for preferred in PREFERRED_MODEL_FILES {
+        let preferred = preferred.to_ascii_lowercase();
+        if let Some((p, _, _)) = candidates.iter().find(|(_, _, name)| *name == preferred) {
+            return Ok(p.to_path_buf());
+        }
+    }
+
+    // Fallback: pick the largest .bin in the folder.
+    if let Some((p, _, _)) = candidates.into_iter().max_by_key(|(_, size, _)| *size) {
+        return Ok(p);
+    }
+
+    Err(format!(
+        "Model not found. Provide a .bin file or a folder containing ggml-*.bin models. Got: {}",
+        path.display()
+    ))
+}
+
+// ============================================================================
+// Period Reports
+// ============================================================================
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PeriodReport {
+    report: String,
+    meeting_count: usize,
+    /// Set when the report was also written to the export dir.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
+}
+
+/// Roll up the summaries, decisions and action items of meetings created between
+/// `start_date` and `end_date` (inclusive, `YYYY-MM-DD`) into one report,
+/// streaming `report-delta` events like `summary-delta`.
+#[tauri::command]
+async fn generate_period_report(
+    app: tauri::AppHandle,
+    start_date: String,
+    end_date: String,
+    model: String,
+    export: Option<bool>,
+) -> Result<PeriodReport, String> {
+    let parse = |date: &str| {
+        chrono::NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d")
+            .map_err(|err| format!("Invalid date {date:?} (expected YYYY-MM-DD): {err}"))
+    };
+    let (start, end) = (parse(&start_date)?, parse(&end_date)?);
+    if start > end {
+        return Err(format!("Start date {start} is after end date {end}"));
+    }
+    let (start_date, end_date) = (start.to_string(), end.to_string());
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let config = load_config_sync(&app)?;
+        let mut meetings: Vec<MeetingRecord> = load_meetings_sync(&app)?
+            .into_iter()
+            .filter(|meeting| {
+                let date = meeting.created_at.split('T').next().unwrap_or_default();
+                (start_date.as_str()..=end_date.as_str()).contains(&date)
+                    && (!meeting.summary.trim().is_empty()
+                        || !meeting.action_items.is_empty()
+                        || !meeting.decisions.is_empty())
+            })
+            .collect();
+        if meetings.is_empty() {
+            return Err(format!(
+                "No meetings with a summary, decisions or action items between {start_date} and {end_date}"
+            ));
+        }
+        meetings.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+
+        let temp_dir = voxii_temp_dir()?;
+        let input_path = temp_dir.join(format!("{}_report.json", uuid::Uuid::new_v4()));
+        let payload = serde_json::json!({
+            "model": model,
+            "prompt": render_period_report_prompt(&start_date, &end_date, &meetings),
+            "systemPrompt": ai_system_prompt(&app, "summary")
+        });
+        fs::write(&input_path, payload.to_string())
+            .map_err(|err| format!("Failed to write report payload: {err}"))?;
+
+        // The summary script sends `payload.prompt` as-is.
+        let script_path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("scripts")
+            .join("copilot-summary.mjs");
+        if !script_path.exists() {
+            return Err(format!("Copilot summary script not found: {}", script_path.display()));
+        }
+
+        let range = serde_json::json!({ "startDate": start_date, "endDate": end_date });
+        let _permit = acquire_ai_permit(&app, "report-queued", range.clone())?;
+        let mut child = Command::new("node")
+            .env("STREAMING", "1")
+            .arg(script_path)
+            .arg(&input_path)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|err| format!("Failed to start Copilot SDK: {err}"))?;
+
+        let stderr_pipe = child.stderr.take();
+        let stderr_reader = std::thread::spawn(move || {
+            let mut stderr = Vec::new();
+            if let Some(mut pipe) = stderr_pipe {
+                let _ = pipe.read_to_end(&mut stderr);
+            }
+            stderr
+        });
+
+        let mut report: Option<String> = None;
+        if let Some(stdout) = child.stdout.take() {
+            for line in lossy_lines(stdout) {
+                let Ok(event) = serde_json::from_str::<serde_json::Value>(line.trim()) else {
+                    continue;
+                };
+                if event.get("type").and_then(|v| v.as_str()) == Some("final") {
+                    report = event.get("content").and_then(|v| v.as_str()).map(str::to_string);
+                }
+                let _ = app.emit(
+                    "report-delta",
+                    serde_json::json!({
+                        "startDate": start_date,
+                        "endDate": end_date,
+                        "event": event
+                    }),
+                );
+            }
+        }
+
+        let status = child
+            .wait()
+            .map_err(|err| format!("Failed to wait for Copilot SDK: {err}"))?;
+        log_process_exit("report", &status);
+        let stderr = stderr_reader.join().unwrap_or_default();
+        if !status.success() {
+            return Err(format!(
+                "Copilot SDK failed ({}): {}",
+                describe_exit_status(&status),
+                String::from_utf8_lossy(&stderr)
+            ));
+        }
+        let report = report
+            .map(|report| report.trim().to_string())
+            .filter(|report| !report.is_empty())
+            .ok_or("Copilot SDK returned an empty report")?;
+
+        let path = if export.unwrap_or(false) {
+            let export_path = export_dir(&config);
+            let file_path = export_path.join(format!("{start_date} to {end_date} - Report.md"));
+            fs::create_dir_all(&export_path)
+                .and_then(|_| fs::write(&file_path, &report))
+                .map_err(|err| describe_export_error(&err))?;
+            Some(file_path.to_string_lossy().to_string())
+        } else {
+            None
+        };
+
+        let _ = app.emit("report-done", range);
+        Ok(PeriodReport {
+            report,
+            meeting_count: meetings.len(),
+            path,
+        })
+    })
+    .await
+    .map_err(|err| format!("Failed to generate report task: {err}"))?
+}
+
+fn render_period_report_prompt(start: &str, end: &str, meetings: &[MeetingRecord]) -> String {
+    let mut sources = String::new();
+    for meeting in meetings {
+        let date = meeting.created_at.split('T').next().unwrap_or_default();
+        sources.push_str(&format!("### {} ({date})\n", meeting.title.trim()));
+        if !meeting.summary.trim().is_empty() {
+            sources.push_str(&format!("Summary:\n{}\n", meeting.summary.trim()));
+        }
+        if !meeting.decisions.is_empty() {
+            sources.push_str("Decisions:\n");
+            for decision in &meeting.decisions {
+                sources.push_str(&format!("- {}\n", decision.decision.trim()));
+            }
+        }
+        if !meeting.action_items.is_empty() {
+            sources.push_str("Action items:\n");
+            for item in &meeting.action_items {
+                let done = if item.status == "completed" { "x" } else { " " };
+                let mut details = Vec::new();
+                if let Some(assignee) = item.assignee.as_deref().filter(|a| !a.is_empty()) {
+                    details.push(format!("owner: {assignee}"));
+                }
+                if let Some(due) = item.due_date.as_deref().filter(|d| !d.is_empty()) {
+                    details.push(format!("due: {due}"));
+                }
+                let details = if details.is_empty() {
+                    String::new()
+                } else {
+                    format!(" ({})", details.join(", "))
+                };
+                sources.push_str(&format!("- [{done}] {}{details}\n", item.task.trim()));
+            }
+        }
+        sources.push('\n');
+    }
+    format!(
+        "You are a meeting assistant. Write a rollup report in Markdown for the {} meetings held from {start} to {end}, with these sections, in this order, each as a \"## \" heading:\n- Highlights\n- Decisions\n- Action Items\n- Risks and Open Questions\n\nRules:\n- Use short bullet points\n- Merge items that recur across meetings and name the meeting when it helps\n- List open action items with their owner and due date; mention completed ones only briefly\n- Be factual, no speculation\n- Keep names and numbers accurate\n- If a section has no content, write \"- None\"\n\nMeetings:\n\n{}Return only Markdown.",
+        meetings.len(),
+        sources
+    )
+}
+
+// ============================================================================
+// Action Items Extraction
+// ============================================================================
+
+/// An action-item extraction, registered before it waits for an AI permit.
+/// `run_id` lets a superseded run tell it was replaced; `child` is set once
+/// the script is running.
+struct ActionItemJob {
+    run_id: String,
+    cancel: Arc<AtomicBool>,
+    child: Option<Child>,
+}
+
+impl ActionItemJob {
+    /// Stop the run: a queued one skips its script, a running one is killed.
+    fn stop(mut self) {
+        self.cancel.store(true, Ordering::SeqCst);
+        if let Some(mut child) = self.child.take() {
+            // Already exited is fine; the job is gone from the map either way.
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+    }
+}
+
+#[tauri::command]
+fn extract_action_items(
+    app: tauri::AppHandle,
+    meeting_id: String,
+    transcript: String,
+    notes: String,
+    model: String,
+) -> Result<(), String> {
+    let temp_dir = voxii_temp_dir()?;
+
+    let id = uuid::Uuid::new_v4().to_string();
+    let input_path = temp_dir.join(format!("{id}_actions.json"));
+
+    let payload = serde_json::json!({
+        "transcript": transcript,
+        "notes": notes,
+        "model": ai_task_model(&app, "actions", model),
+        "systemPrompt": ai_system_prompt(&app, "actions")
+    });
+
+    fs::write(&input_path, payload.to_string())
+        .map_err(|err| format!("Failed to write actions payload: {err}"))?;
+
+    let script_path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("scripts")
+        .join("copilot-actions.mjs");
+
+    if !script_path.exists() {
+        return Err(format!(
+            "Actions script not found: {}",
+            script_path.display()
+        ));
+    }
+
+    // A new extraction for the same meeting supersedes the queued or running
+    // one; registering before the permit lets cancel reach a queued run.
+    let run_id = uuid::Uuid::new_v4().to_string();
+    let cancel = Arc::new(AtomicBool::new(false));
+    let previous = app
+        .state::<AppState>()
+        .action_item_jobs
+        .lock()
+        .map_err(|_| "Failed to acquire lock")?
+        .insert(
+            meeting_id.clone(),
+            ActionItemJob {
+                run_id: run_id.clone(),
+                cancel: cancel.clone(),
+                child: None,
+            },
+        );
+    if let Some(previous) = previous {
+        previous.stop();
+    }
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let state = app.state::<AppState>();
+        let _permit = match acquire_ai_permit(
+            &app,
+            "actions-queued",
+            serde_json::json!({ "meetingId": meeting_id }),
+        ) {
+            Ok(permit) => permit,
+            Err(err) => {
+                if let Ok(mut jobs) = state.action_item_jobs.lock() {
+                    if jobs.get(&meeting_id).is_some_and(|job| job.run_id == run_id) {
+                        jobs.remove(&meeting_id);
+                    }
+                }
+                let _ = app.emit(
+                    "actions-error",
+                    serde_json::json!({ "meetingId": meeting_id, "error": err }),
+                );
+                return;
+            }
+        };
+        // Cancelled or superseded while queued; whoever did it reported it.
+        if cancel.load(Ordering::SeqCst) {
+            return;
+        }
+        let mut child = match Command::new("node")
+            .arg(&script_path)
+            .arg(&input_path)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+        {
+            Ok(child) => child,
+            Err(err) => {
+                if let Ok(mut jobs) = state.action_item_jobs.lock() {
+                    if jobs.get(&meeting_id).is_some_and(|job| job.run_id == run_id) {
+                        jobs.remove(&meeting_id);
+                    }
+                }
+                let _ = app.emit(
+                    "actions-error",
+                    serde_json::json!({
+                        "meetingId": meeting_id,
+                        "error": format!("Failed to run actions script: {err}")
+                    }),
+                );
+                return;
+            }
+        };
+        let stdout_pipe = child.stdout.take();
+        let stderr_pipe = child.stderr.take();
+
+        // Hand the child to the registered job, unless it was cancelled or
+        // superseded between the permit check and the spawn.
+        let mut child = Some(child);
+        if let Ok(mut jobs) = state.action_item_jobs.lock() {
+            if let Some(job) = jobs.get_mut(&meeting_id).filter(|job| job.run_id == run_id) {
+                job.child = child.take();
+            }
+        }
+        if let Some(mut child) = child {
+            let _ = child.kill();
+            let _ = child.wait();
+            return;
+        }
+
+        let stderr_reader = std::thread::spawn(move || {
+            let mut stderr = Vec::new();
+            if let Some(mut pipe) = stderr_pipe {
+                let _ = pipe.read_to_end(&mut stderr);
+            }
+            stderr
+        });
+        let mut stdout = Vec::new();
+        if let Some(mut pipe) = stdout_pipe {
+            let _ = pipe.read_to_end(&mut stdout);
+        }
+        let stderr = stderr_reader.join().unwrap_or_default();
+
+        // Gone from the map means cancelled or superseded; whoever removed it
+        // already reported that.
+        let child = state.action_item_jobs.lock().ok().and_then(|mut jobs| {
+            match jobs.get(&meeting_id) {
+                Some(job) if job.run_id == run_id => {
+                    jobs.remove(&meeting_id).and_then(|job| job.child)
+                }
+                _ => None,
+            }
+        });
+        let Some(mut child) = child else {
+            return;
+        };
+        let output = match child.wait() {
+            Ok(status) => std::process::Output { status, stdout, stderr },
+            Err(err) => {
+                let _ = app.emit(
+                    "actions-error",
+                    serde_json::json!({
+                        "meetingId": meeting_id,
+                        "error": format!("Failed to wait for actions script: {err}")
+                    }),
+                );
+                return;
+            }
+        };
+
+        log_process_exit("actions", &output.status);
+        let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+        
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let _ = app.emit(
+                "actions-error",
+                serde_json::json!({
+                    "meetingId": meeting_id,
+                    "error": format!(
+                        "Actions extraction failed ({}): {stderr}",
+                        describe_exit_status(&output.status)
+                    )
+                }),
+            );
+            return;
+        }
+
+        // Parse the JSON output
+        match serde_json::from_str::<serde_json::Value>(stdout.trim()) {
+            Ok(result) => {
+                let (items, warnings) = normalize_action_items(result);
+                if warnings > 0 {
+                    tracing::warn!(warnings, "repaired action items from model output");
+                }
+                let _ = app.emit(
+                    "actions-done",
+                    serde_json::json!({
+                        "meetingId": meeting_id,
+                        "actions": { "items": items },
+                        "warnings": warnings
+                    }),
+                );
+            }
+            Err(err) => {
+                let _ = app.emit(
+                    "actions-error",
+                    serde_json::json!({
+                        "meetingId": meeting_id,
+                        "error": format!("Failed to parse actions: {err}")
+                    }),
+                );
+            }
+        }
+    });
+
+    Ok(())
+}
+
+/// Turn the actions script's JSON (`{ "items": [...] }` or a bare array) into
+/// well-formed `ActionItem`s, counting every repair: a missing `id` gets a
+/// UUID, `priority`/`status` outside the allowed values are mapped or
+/// defaulted, and entries without a task are dropped.
+fn normalize_action_items(result: serde_json::Value) -> (Vec<ActionItem>, usize) {
+    let entries = match result {
+        serde_json::Value::Array(entries) => entries,
+        mut other => match other.get_mut("items").map(serde_json::Value::take) {
+            Some(serde_json::Value::Array(entries)) => entries,
+            _ => return (Vec::new(), 1),
+        },
+    };
+
+    let mut warnings = 0;
+    let mut items = Vec::with_capacity(entries.len());
+    for entry in &entries {
+        let text = |key: &str| {
+            entry
+                .get(key)
+                .and_then(|v| v.as_str())
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(str::to_string)
+        };
+        let Some(task) = text("task") else {
+            warnings += 1;
+            continue;
+        };
+        let id = text("id").unwrap_or_else(|| {
+            warnings += 1;
+            uuid::Uuid::new_v4().to_string()
+        });
+        let (priority, exact) = action_priority(text("priority").as_deref());
+        warnings += usize::from(!exact);
+        let (status, exact) = action_status(text("status").as_deref());
+        warnings += usize::from(!exact);
+        items.push(ActionItem {
+            id,
+            task,
+            assignee: text("assignee"),
+            due_date: text("dueDate"),
+            priority: priority.to_string(),
+            status: status.to_string(),
+            context: text("context"),
+        });
+    }
+    (items, warnings)
+}
+
+/// `high`, `medium` or `low`, and whether the input already was one of them
+/// (ignoring case).
+fn action_priority(value: Option<&str>) -> (&'static str, bool) {
+    let Some(value) = value.map(str::to_lowercase) else {
+        return ("medium", false);
+    };
+    match value.as_str() {
+        "high" => ("high", true),
+        "medium" => ("medium", true),
+        "low" => ("low", true),
+        "urgent" | "critical" | "highest" | "p0" | "p1" => ("high", false),
+        "lowest" | "minor" | "p3" | "p4" => ("low", false),
+        _ => ("medium", false),
+    }
+}
+
+/// `pending` or `completed`, and whether the input already was one of them
+/// (ignoring case).
+fn action_status(value: Option<&str>) -> (&'static str, bool) {
+    let Some(value) = value.map(str::to_lowercase) else {
+        return ("pending", false);
+    };
+    match value.as_str() {
+        "pending" => ("pending", true),
+        "completed" => ("completed", true),
+        "done" | "complete" | "closed" | "finished" | "resolved" => ("completed", false),
+        _ => ("pending", false),
+    }
+}
+
+/// Stop a queued or running action-item extraction. Returns whether one was
+/// pending.
+#[tauri::command]
+async fn cancel_action_items(
+    app: tauri::AppHandle,
+    state: State<'_, AppState>,
+    meeting_id: String,
+) -> Result<bool, String> {
+    let job = state
+        .action_item_jobs
+        .lock()
+        .map_err(|_| "Failed to acquire lock")?
+        .remove(&meeting_id);
+    let Some(job) = job else {
+        return Ok(false);
+    };
+    job.stop();
+    let _ = app.emit("actions-cancelled", serde_json::json!({ "meetingId": meeting_id }));
+    Ok(true)
+}
+
+#[derive(Serialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+struct CancelledJobs {
+    /// Meeting ids whose queued or running action-item extraction was stopped.
+    action_items: Vec<String>,
+    summary_batch: bool,
+    /// Killed summary, enhance, cleanup and whisper processes, by kind.
+    summaries: usize,
+    enhancements: usize,
+    cleanups: usize,
+    transcriptions: usize,
+}
+
+/// Stop every cancellable background job: queued action-item extractions are
+/// dropped, running ones are killed along with summary, enhance, cleanup and
+/// local whisper processes, and a summary batch is told to stop after its
+/// current meeting. Other AI runs and remote transcriptions aren't tracked and
+/// finish on their own. Emits one `all-jobs-cancelled` event, also when
+/// nothing was running.
+#[tauri::command]
+async fn cancel_all_jobs(
+    app: tauri::AppHandle,
+    state: State<'_, AppState>,
+) -> Result<CancelledJobs, String> {
+    let jobs: Vec<(String, ActionItemJob)> = state
+        .action_item_jobs
+        .lock()
+        .map_err(|_| "Failed to acquire lock")?
+        .drain()
+        .collect();
+    let mut cancelled = CancelledJobs::default();
+    for (meeting_id, job) in jobs {
+        job.stop();
+        cancelled.action_items.push(meeting_id);
+    }
+    cancelled.action_items.sort();
+
+    if let Some(cancel) = state
+        .summary_batch
+        .lock()
+        .map_err(|_| "Failed to acquire lock")?
+        .as_ref()
+    {
+        cancel.store(true, Ordering::SeqCst);
+        cancelled.summary_batch = true;
+    }
+
+    for kind in state.child_jobs.kill_all() {
+        *match kind {
+            JobKind::Summary => &mut cancelled.summaries,
+            JobKind::Enhance => &mut cancelled.enhancements,
+            JobKind::Clean => &mut cancelled.cleanups,
+            JobKind::Whisper => &mut cancelled.transcriptions,
+        } += 1;
+    }
+
+    let _ = app.emit("all-jobs-cancelled", &cancelled);
+    Ok(cancelled)
+}
+
+// ============================================================================
+// Decision Extraction
+// ============================================================================
+
+#[tauri::command]
+async fn extract_decisions(
+    app: tauri::AppHandle,
+    meeting_id: String,
+    model: String,
+) -> Result<Vec<Decision>, String> {
+    let script_path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("scripts")
+        .join("copilot-decisions.mjs");
+
+    if !script_path.exists() {
+        return Err(format!(
+            "Decisions script not found: {}",
+            script_path.display()
+        ));
+    }
+
+    let result = tauri::async_runtime::spawn_blocking({
+        let app = app.clone();
+        let meeting_id = meeting_id.clone();
+        move || -> Result<Vec<Decision>, String> {
+            let (transcript, notes) = load_meetings_sync(&app)?
+                .into_iter()
+                .find(|meeting| meeting.id == meeting_id)
+                .map(|meeting| (meeting.transcript, meeting.notes))
+                .ok_or_else(|| format!("Meeting not found: {meeting_id}"))?;
+            if transcript.trim().is_empty() && notes.trim().is_empty() {
+                return Err("Meeting has no transcript or notes to analyze".to_string());
+            }
+
+            let temp_dir = voxii_temp_dir()?;
+            let input_path =
+                temp_dir.join(format!("{}_decisions.json", uuid::Uuid::new_v4()));
+            let payload = serde_json::json!({
+                "transcript": transcript,
+                "notes": notes,
+                "model": model
+            });
+            fs::write(&input_path, payload.to_string())
+                .map_err(|err| format!("Failed to write decisions payload: {err}"))?;
+
+            let _permit = acquire_ai_permit(
+                &app,
+                "decisions-queued",
+                serde_json::json!({ "meetingId": meeting_id }),
+            )?;
+            let output = Command::new("node")
+                .arg(&script_path)
+                .arg(&input_path)
+                .output()
+                .map_err(|err| format!("Failed to run decisions script: {err}"))?;
+            log_process_exit("decisions", &output.status);
+            if !output.status.success() {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                return Err(format!(
+                    "Decision extraction failed ({}): {stderr}",
+                    describe_exit_status(&output.status)
+                ));
+            }
+
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            let mut parsed: serde_json::Value = serde_json::from_str(stdout.trim())
+                .map_err(|err| format!("Failed to parse decisions: {err}"))?;
+            let decisions: Vec<Decision> =
+                serde_json::from_value(parsed["decisions"].take())
+                    .map_err(|err| format!("Failed to parse decisions: {err}"))?;
+            let decisions: Vec<Decision> = decisions
+                .into_iter()
+                .filter(|item| !item.decision.trim().is_empty())
+                .collect();
+
+            // Re-read so edits saved while the model was running aren't lost.
+            let mut meetings = load_meetings_sync(&app)?;
+            let meeting = meetings
+                .iter_mut()
+                .find(|meeting| meeting.id == meeting_id)
+                .ok_or_else(|| format!("Meeting not found: {meeting_id}"))?;
+            meeting.decisions = decisions.clone();
+            let updated = meeting.clone();
+            write_meetings_sync(&app, &mut meetings)?;
+            let _ = app.emit("meeting-updated", &updated);
+            Ok(decisions)
+        }
+    })
+    .await
+    .map_err(|err| format!("Failed to extract decisions task: {err}"))?;
+
+    match &result {
+        Ok(decisions) => {
+            let _ = app.emit(
+                "decisions-done",
+                serde_json::json!({ "meetingId": meeting_id, "decisions": decisions }),
+            );
+        }
+        Err(err) => {
+            let _ = app.emit(
+                "decisions-error",
+                serde_json::json!({ "meetingId": meeting_id, "error": err }),
+            );
+        }
+    }
+    result
+}
+
+// ============================================================================
+// Question Extraction
+// ============================================================================
+
+/// Pull the questions raised in a meeting, answered or not, into `questions`.
+/// Emits `questions-queued` while waiting for an AI slot, then
+/// `questions-done` or `questions-error`.
+#[tauri::command]
+async fn extract_questions(
+    app: tauri::AppHandle,
+    meeting_id: String,
+    model: String,
+) -> Result<Vec<Question>, String> {
+    let script_path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("scripts")
+        .join("copilot-questions.mjs");
+
+    if !script_path.exists() {
+        return Err(format!(
+            "Questions script not found: {}",
+            script_path.display()
+        ));
+    }
+
+    let result = tauri::async_runtime::spawn_blocking({
+        let app = app.clone();
+        let meeting_id = meeting_id.clone();
+        move || -> Result<Vec<Question>, String> {
+            let (transcript, notes) = load_meetings_sync(&app)?
+                .into_iter()
+                .find(|meeting| meeting.id == meeting_id)
+                .map(|meeting| (meeting.transcript, meeting.notes))
+                .ok_or_else(|| format!("Meeting not found: {meeting_id}"))?;
+            if transcript.trim().is_empty() && notes.trim().is_empty() {
+                return Err("Meeting has no transcript or notes to analyze".to_string());
+            }
+
+            let temp_dir = voxii_temp_dir()?;
+            let input_path =
+                temp_dir.join(format!("{}_questions.json", uuid::Uuid::new_v4()));
+            let payload = serde_json::json!({
+                "transcript": transcript,
+                "notes": notes,
+                "model": ai_task_model(&app, "questions", model),
+                "systemPrompt": ai_system_prompt(&app, "questions")
+            });
+            fs::write(&input_path, payload.to_string())
+                .map_err(|err| format!("Failed to write questions payload: {err}"))?;
+
+            let _permit = acquire_ai_permit(
+                &app,
+                "questions-queued",
+                serde_json::json!({ "meetingId": meeting_id }),
+            )?;
+            let output = Command::new("node")
+                .arg(&script_path)
+                .arg(&input_path)
+                .output()
+                .map_err(|err| format!("Failed to run questions script: {err}"))?;
+            log_process_exit("questions", &output.status);
+            if !output.status.success() {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                return Err(format!(
+                    "Question extraction failed ({}): {stderr}",
+                    describe_exit_status(&output.status)
+                ));
+            }
+
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            let mut parsed: serde_json::Value = serde_json::from_str(stdout.trim())
+                .map_err(|err| format!("Failed to parse questions: {err}"))?;
+            let questions: Vec<Question> =
+                serde_json::from_value(parsed["questions"].take())
+                    .map_err(|err| format!("Failed to parse questions: {err}"))?;
+            let questions: Vec<Question> = questions
+                .into_iter()
+                .filter(|item| !item.question.trim().is_empty())
+                .collect();
+
+            // Re-read so edits saved while the model was running aren't lost.
+            let mut meetings = load_meetings_sync(&app)?;
+            let meeting = meetings
+                .iter_mut()
+                .find(|meeting| meeting.id == meeting_id)
+                .ok_or_else(|| format!("Meeting not found: {meeting_id}"))?;
+            meeting.questions = questions.clone();
+            let updated = meeting.clone();
+            write_meetings_sync(&app, &mut meetings)?;
+            let _ = app.emit("meeting-updated", &updated);
+            Ok(questions)
+        }
+    })
+    .await
+    .map_err(|err| format!("Failed to extract questions task: {err}"))?;
+
+    match &result {
+        Ok(questions) => {
+            let _ = app.emit(
+                "questions-done",
+                serde_json::json!({ "meetingId": meeting_id, "questions": questions }),
+            );
+        }
+        Err(err) => {
+            let _ = app.emit(
+                "questions-error",
+                serde_json::json!({ "meetingId": meeting_id, "error": err }),
+            );
+        }
+    }
+    result
+}
+
+// ============================================================================
+// Topic Segmentation
+// ============================================================================
+
+#[tauri::command]
+async fn segment_topics(
+    app: tauri::AppHandle,
+    meeting_id: String,
+    model: String,
+) -> Result<Vec<TopicChapter>, String> {
+    let script_path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("scripts")
+        .join("copilot-topics.mjs");
+
+    if !script_path.exists() {
+        return Err(format!("Topics script not found: {}", script_path.display()));
+    }
+
+    let result = tauri::async_runtime::spawn_blocking({
+        let app = app.clone();
+        let meeting_id = meeting_id.clone();
+        move || -> Result<Vec<TopicChapter>, String> {
+            let (transcript, segments) = load_meetings_sync(&app)?
+                .into_iter()
+                .find(|meeting| meeting.id == meeting_id)
+                .map(|meeting| (meeting.transcript, meeting.segments))
+                .ok_or_else(|| format!("Meeting not found: {meeting_id}"))?;
+            if transcript.trim().is_empty() {
+                return Err("Meeting has no transcript to segment".to_string());
+            }
+
+            let temp_dir = voxii_temp_dir()?;
+            let input_path = temp_dir.join(format!("{}_topics.json", uuid::Uuid::new_v4()));
+            let payload = serde_json::json!({
+                "transcript": transcript,
+                "model": model
+            });
+            fs::write(&input_path, payload.to_string())
+                .map_err(|err| format!("Failed to write topics payload: {err}"))?;
+
+            let _permit = acquire_ai_permit(
+                &app,
+                "topics-queued",
+                serde_json::json!({ "meetingId": meeting_id }),
+            )?;
+            let mut child = Command::new("node")
+                .arg(&script_path)
+                .arg(&input_path)
+                .stdout(Stdio::piped())
+                .stderr(Stdio::piped())
+                .spawn()
+                .map_err(|err| format!("Failed to run topics script: {err}"))?;
+
+            let mut boundaries: Option<serde_json::Value> = None;
+            if let Some(stdout) = child.stdout.take() {
+                for line in lossy_lines(stdout) {
+                    let Ok(value) = serde_json::from_str::<serde_json::Value>(line.trim()) else {
+                        continue;
+                    };
+                    if value.get("type").and_then(|v| v.as_str()) == Some("final") {
+                        boundaries = value.get("content").cloned();
+                    } else {
+                        let _ = app.emit(
+                            "topics-delta",
+                            serde_json::json!({
+                                "meetingId": meeting_id,
+                                "event": value
+                            }),
+                        );
+                    }
+                }
+            }
+
+            let output = child
+                .wait_with_output()
+                .map_err(|err| format!("Failed to wait for topics script: {err}"))?;
+            log_process_exit("topics", &output.status);
+            if !output.status.success() {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                return Err(format!(
+                    "Topic segmentation failed ({}): {stderr}",
+                    describe_exit_status(&output.status)
+                ));
+            }
+
+            let mut chapters = locate_chapters(&transcript, boundaries.as_ref());
+            if chapters.is_empty() {
+                return Err("Topic segmentation returned no chapters".to_string());
+            }
+            attach_chapter_timings(&mut chapters, &transcript, &segments);
+
+            // Re-read so edits saved while the model was running aren't lost.
+            let mut meetings = load_meetings_sync(&app)?;
+            let meeting = meetings
+                .iter_mut()
+                .find(|meeting| meeting.id == meeting_id)
+                .ok_or_else(|| format!("Meeting not found: {meeting_id}"))?;
+            if meeting.transcript != transcript {
+                return Err("Transcript changed during topic segmentation".to_string());
+            }
+            meeting.chapters = chapters.clone();
+            let updated = meeting.clone();
+            write_meetings_sync(&app, &mut meetings)?;
+            let _ = app.emit("meeting-updated", &updated);
+            Ok(chapters)
+        }
+    })
+    .await
+    .map_err(|err| format!("Failed to segment topics task: {err}"))?;
+
+    match &result {
+        Ok(chapters) => {
+            let _ = app.emit(
+                "topics-done",
+                serde_json::json!({ "meetingId": meeting_id, "chapters": chapters }),
+            );
+        }
+        Err(err) => {
+            let _ = app.emit(
+                "topics-error",
+                serde_json::json!({ "meetingId": meeting_id, "error": err }),
+            );
+        }
+    }
+    result
+}
+
+/// Turn the model's `{ title, startQuote }` list into contiguous char ranges.
+/// Quotes are matched in order; ones that can't be found are folded into the
+/// previous chapter.
+fn locate_chapters(transcript: &str, boundaries: Option<&serde_json::Value>) -> Vec<TopicChapter> {
+    let Some(items) = boundaries
+        .and_then(|value| value.get("chapters"))
+        .and_then(|value| value.as_array())
+    else {
+        return Vec::new();
+    };
+
+    let haystack = transcript.to_lowercase();
+    let mut starts: Vec<(String, usize)> = Vec::new();
+    let mut search_from = 0;
+    for item in items {
+        let title = item
+            .get("title")
+            .and_then(|v| v.as_str())
+            .map(str::trim)
+            .unwrap_or_default();
+        if title.is_empty() {
+            continue;
+        }
+        let quote = item
+            .get("startQuote")
+            .and_then(|v| v.as_str())
+            .map(|quote| quote.trim().to_lowercase())
+            .unwrap_or_default();
+
+        let byte_start = if starts.is_empty() {
+            Some(0)
+        } else if quote.is_empty() {
+            None
+        } else {
+            haystack
+                .get(search_from..)
+                .and_then(|rest| rest.find(&quote))
+                .map(|offset| search_from + offset)
+        };
+        if let Some(byte_start) = byte_start {
+            search_from = byte_start + quote.len().max(1);
+            starts.push((title.to_string(), byte_start));
+        }
+    }
+
+    // Lowercasing can shift byte offsets for some scripts; fall back to a
+    // single chapter rather than guessing.
+    if haystack.len() != transcript.len() {
+        starts.truncate(1);
+    }
+
+    let total_chars = transcript.chars().count();
+    let mut chapters: Vec<TopicChapter> = starts
+        .iter()
+        .map(|(title, byte_start)| TopicChapter {
+            title: title.clone(),
+            start_char: transcript
+                .get(..*byte_start)
+                .map_or(0, |prefix| prefix.chars().count()),
+            end_char: total_chars,
+            start_ms: None,
+            end_ms: None,
+        })
+        .collect();
+    for index in 1..chapters.len() {
+        chapters[index - 1].end_char = chapters[index].start_char;
+    }
+    chapters.retain(|chapter| chapter.end_char > chapter.start_char);
+    chapters
+}
+
+/// Give chapters timestamps by finding each segment's text in the transcript
+/// and taking the segment that contains the chapter's start (and end).
+fn attach_chapter_timings(chapters: &mut [TopicChapter], transcript: &str, segments: &[TranscriptSegment]) {
+    let mut spans = Vec::new();
+    let mut search_from = 0;
+    for segment in segments {
+        let text = segment.text.trim();
+        if text.is_empty() {
+            continue;
+        }
+        let Some(offset) = transcript.get(search_from..).and_then(|rest| rest.find(text)) else {
+            continue;
+        };
+        let byte_start = search_from + offset;
+        search_from = byte_start + text.len();
+        let start_char = transcript[..byte_start].chars().count();
+        spans.push((start_char, start_char + text.chars().count(), segment));
+    }
+    if spans.is_empty() {
+        return;
+    }
+
+    for chapter in chapters {
+        chapter.start_ms = spans
+            .iter()
+            .rev()
+            .find(|(start, _, _)| *start <= chapter.start_char)
+            .or(spans.first())
+            .map(|(_, _, segment)| segment.start_ms);
+        chapter.end_ms = spans
+            .iter()
+            .find(|(_, end, _)| *end >= chapter.end_char)
+            .or(spans.last())
+            .map(|(_, _, segment)| segment.end_ms);
+    }
+}
+
+// ============================================================================
+// Audio Analysis
+// ============================================================================
+
+/// 10ms windows quieter than this (RMS, dBFS) count as silence.
+const SILENCE_THRESHOLD_DB: f64 = -50.0;
+/// This many consecutive full-scale samples are treated as clipping.
+const CLIPPING_RUN: usize = 3;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AudioAnalysis {
+    duration_ms: u64,
+    leading_silence_ms: u64,
+    trailing_silence_ms: u64,
+    /// Loudest sample in dBFS; `None` for digital silence.
+    peak_db: Option<f64>,
+    clipping: bool,
+}
+
+/// Level check on a recording without transcribing it: dead air at either
+/// end, peak level and clipping. Needs 16-bit PCM WAV.
+#[tauri::command]
+async fn analyze_audio(audio_base64: String) -> Result<AudioAnalysis, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let audio = decode_audio_input(&audio_base64)?;
+        let wav = parse_pcm16_wav(&audio.bytes)
+            .ok_or("Only 16-bit PCM WAV audio can be analyzed")?;
+        Ok(analyze_pcm16(&wav))
+    })
+    .await
+    .map_err(|err| format!("Failed to analyze audio task: {err}"))?
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AudioValidation {
+    /// Container from the magic bytes; `None` when unrecognized.
+    format: Option<String>,
+    size_bytes: usize,
+    /// WAV header fields; other containers aren't parsed.
+    sample_rate: Option<u32>,
+    channels: Option<u16>,
+    bits_per_sample: Option<u16>,
+    duration_ms: Option<u64>,
+    /// Local whisper reads it as-is; otherwise it needs converting or the
+    /// OpenAI-compatible provider.
+    whisper_compatible: bool,
+    warnings: Vec<String>,
+}
+
+/// Check a payload before transcribing it: decodes, sniffs the container and
+/// reads the WAV header. Only undecodable base64 is an error; everything else
+/// is reported in the result.
+#[tauri::command]
+async fn validate_audio(
+    app: tauri::AppHandle,
+    audio_base64: String,
+) -> Result<AudioValidation, String> {
+    let config = load_config(app).await?;
+    tauri::async_runtime::spawn_blocking(move || {
+        let mut warnings = Vec::new();
+        if let Err(err) = check_audio_payload_size(&config, &audio_base64) {
+            warnings.push(err);
+        }
+        let audio = decode_audio_input(&audio_base64)?;
+        let format = match audio_file_extension(&audio.bytes) {
+            "bin" => None,
+            extension => Some(extension.to_string()),
+        };
+
+        // A data URL's declared type wins when transcribing, so flag disagreements.
+        if let Some(sniffed) = format.as_deref().filter(|f| *f != audio.format.extension()) {
+            warnings.push(format!(
+                "Declared type {} does not match the {sniffed} contents",
+                audio.format.mime()
+            ));
+        }
+
+        let header = wav_header(&audio.bytes);
+        let mut whisper_compatible = format.is_some() && audio.format.whisper_readable();
+        match (format.as_deref(), header) {
+            (None, _) => warnings.push("Unrecognized audio format".to_string()),
+            (Some("wav"), None) => {
+                whisper_compatible = false;
+                warnings.push("WAV header is missing its fmt or data chunk".to_string());
+            }
+            (Some("wav"), Some(header)) => {
+                if header.format_tag != 1 {
+                    whisper_compatible = false;
+                    warnings.push(format!(
+                        "WAV uses codec {:#06x}; whisper expects PCM",
+                        header.format_tag
+                    ));
+                }
+                if header.sample_rate != 16_000 || header.channels != 1 {
+                    warnings.push(format!(
+                        "{} Hz, {} channel(s); whisper resamples to 16 kHz mono",
+                        header.sample_rate, header.channels
+                    ));
+                }
+            }
+            (Some(other), _) if !whisper_compatible => {
+                warnings.push(format!("Local whisper cannot read {other} audio"));
+            }
+            _ => {}
+        }
+
+        Ok(AudioValidation {
+            format,
+            size_bytes: audio.bytes.len(),
+            sample_rate: header.map(|header| header.sample_rate),
+            channels: header.map(|header| header.channels),
+            bits_per_sample: header.map(|header| header.bits_per_sample),
+            duration_ms: wav_duration_ms(&audio.bytes),
+            whisper_compatible,
+            warnings,
+        })
+    })
+    .await
+    .map_err(|err| format!("Failed to validate audio task: {err}"))?
+}
+
+#[derive(Clone, Copy)]
+struct WavHeader {
+    format_tag: u16,
+    channels: u16,
+    sample_rate: u32,
+    bits_per_sample: u16,
+}
+
+/// The `fmt ` chunk of a WAV file, if it is followed by a `data` chunk.
+fn wav_header(bytes: &[u8]) -> Option<WavHeader> {
+    wav_chunks(bytes).map(|(header, _)| header)
+}
+
+/// Walk the RIFF chunks of a WAV file to the `fmt ` header and the `data`
+/// payload. A data size past the end of the buffer (as left by streaming
+/// writers) is cut to what is there.
+fn wav_chunks(bytes: &[u8]) -> Option<(WavHeader, &[u8])> {
+    let u16_at = |offset: usize| {
+        bytes
+            .get(offset..offset + 2)
+            .map(|b| u16::from_le_bytes([b[0], b[1]]))
+    };
+    let u32_at = |offset: usize| {
+        bytes
+            .get(offset..offset + 4)
+            .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+    };
+    if !bytes.starts_with(b"RIFF") || bytes.get(8..12) != Some(b"WAVE".as_slice()) {
+        return None;
+    }
+
+    let mut header = None;
+    let mut offset = 12;
+    while let (Some(id), Some(size)) = (bytes.get(offset..offset + 4), u32_at(offset + 4)) {
+        let body = offset + 8;
+        match id {
+            b"fmt " => {
+                header = Some(WavHeader {
+                    format_tag: u16_at(body)?,
+                    channels: u16_at(body + 2)?,
+                    sample_rate: u32_at(body + 4)?,
+                    bits_per_sample: u16_at(body + 14)?,
+                });
+            }
+            b"data" => {
+                let end = body.saturating_add(size as usize).min(bytes.len());
+                return Some((header?, bytes.get(body..end)?));
+            }
+            _ => {}
+        }
+        offset = body + size as usize + (size as usize & 1);
+    }
+    None
+}
+
+struct Pcm16Wav {
+    channels: u16,
+    sample_rate: u32,
+    samples: Vec<i16>,
+}
+
+fn parse_pcm16_wav(bytes: &[u8]) -> Option<Pcm16Wav> {
+    let (header, data) = wav_chunks(bytes)?;
+    if header.format_tag != 1
+        || header.bits_per_sample != 16
+        || header.channels == 0
+        || header.sample_rate == 0
+    {
+        return None;
+    }
+    let samples = data
+        .chunks_exact(2)
+        .map(|b| i16::from_le_bytes([b[0], b[1]]))
+        .collect();
+    Some(Pcm16Wav {
+        channels: header.channels,
+        sample_rate: header.sample_rate,
+        samples,
+    })
+}
+
+fn analyze_pcm16(wav: &Pcm16Wav) -> AudioAnalysis {
+    let channels = wav.channels as usize;
+    let frames = wav.samples.len() / channels;
+    let ms_for = |frames: usize| frames as u64 * 1000 / wav.sample_rate as u64;
+
+    let peak = wav.samples.iter().map(|s| s.unsigned_abs()).max().unwrap_or(0);
+    let peak_db = (peak > 0).then(|| 20.0 * (peak as f64 / 32768.0).log10());
+
+    let mut run = 0;
+    let clipping = wav.samples.iter().any(|sample| {
+        run = if sample.unsigned_abs() >= i16::MAX as u16 { run + 1 } else { 0 };
+        run >= CLIPPING_RUN
+    });
+
+    // Per-window loudness over 10ms frames.
+    let window = (wav.sample_rate as usize / 100).max(1) * channels;
+    let threshold = 32768.0 * 10f64.powf(SILENCE_THRESHOLD_DB / 20.0);
+    let loud: Vec<bool> = wav
+        .samples
+        .chunks(window)
+        .map(|chunk| {
+            let energy: f64 = chunk.iter().map(|s| (*s as f64).powi(2)).sum();
+            (energy / chunk.len() as f64).sqrt() >= threshold
+        })
+        .collect();
+    let window_frames = window / channels;
+    let (leading, trailing) = match loud.iter().position(|loud| *loud) {
+        Some(first) => {
+            let last = loud.iter().rposition(|loud| *loud).unwrap_or(first);
+            let trailing_frames = frames.saturating_sub((last + 1) * window_frames);
+            (ms_for(first * window_frames), ms_for(trailing_frames))
+        }
+        None => (ms_for(frames), ms_for(frames)),
+    };
+
+    AudioAnalysis {
+        duration_ms: ms_for(frames),
+        leading_silence_ms: leading,
+        trailing_silence_ms: trailing,
+        peak_db,
+        clipping,
+    }
+}
+
+/// Shortest chunk `find_chunk_boundaries` accepts.
+const MIN_CHUNK_MS: u64 = 1000;
+/// Pauses shorter than this don't count as a split point.
+const MIN_PAUSE_MS: u64 = 150;
+/// Windows this far below the median level (dB) count as a pause.
+const PAUSE_BELOW_MEDIAN_DB: f64 = 15.0;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ChunkBoundary {
+    ms: u64,
+    /// `false` when no pause was found near the target and this is the
+    /// fixed-duration fallback.
+    silence: bool,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ChunkBoundaries {
+    duration_ms: u64,
+    /// Split points, ascending; excludes 0 and the end of the audio.
+    boundaries: Vec<ChunkBoundary>,
+}
+
+/// Suggest split points roughly `targetChunkMs` apart, moved to the middle
+/// of the nearest pause within a quarter chunk of each target. Each target
+/// is measured from the previous split. Needs 16-bit PCM WAV.
+#[tauri::command]
+async fn find_chunk_boundaries(
+    audio_base64: String,
+    target_chunk_ms: u64,
+) -> Result<ChunkBoundaries, String> {
+    if target_chunk_ms < MIN_CHUNK_MS {
+        return Err(format!("targetChunkMs must be at least {MIN_CHUNK_MS}"));
+    }
+    tauri::async_runtime::spawn_blocking(move || {
+        let audio = decode_audio_input(&audio_base64)?;
+        let wav = parse_pcm16_wav(&audio.bytes)
+            .ok_or("Only 16-bit PCM WAV audio can be analyzed")?;
+        Ok(chunk_boundaries(&wav, target_chunk_ms))
+    })
+    .await
+    .map_err(|err| format!("Failed to find chunk boundaries task: {err}"))?
+}
+
+fn chunk_boundaries(wav: &Pcm16Wav, target_ms: u64) -> ChunkBoundaries {
+    const WINDOW_MS: u64 = 10;
+    let channels = wav.channels as usize;
+    let duration_ms = (wav.samples.len() / channels) as u64 * 1000 / wav.sample_rate as u64;
+
+    // Per-window RMS in dBFS. Room noise sits well above digital silence, so
+    // a pause is anything well below the recording's median level.
+    let window = (wav.sample_rate as usize / 100).max(1) * channels;
+    let levels: Vec<f64> = wav
+        .samples
+        .chunks(window)
+        .map(|chunk| {
+            let energy: f64 = chunk.iter().map(|s| (*s as f64).powi(2)).sum();
+            let rms = (energy / chunk.len() as f64).sqrt();
+            20.0 * (rms.max(1.0) / 32768.0).log10()
+        })
+        .collect();
+    let mut sorted = levels.clone();
+    sorted.sort_by(f64::total_cmp);
+    let median = sorted.get(sorted.len() / 2).copied().unwrap_or(SILENCE_THRESHOLD_DB);
+    let threshold = (median - PAUSE_BELOW_MEDIAN_DB).max(SILENCE_THRESHOLD_DB);
+
+    // Centers (ms) of quiet runs long enough to split on.
+    let mut pauses = Vec::new();
+    let mut run_start = None;
+    for (index, level) in levels.iter().chain([&f64::INFINITY]).enumerate() {
+        match (run_start, *level < threshold) {
+            (None, true) => run_start = Some(index),
+            (Some(start), false) => {
+                if (index - start) as u64 * WINDOW_MS >= MIN_PAUSE_MS {
+                    pauses.push((start + index) as u64 * WINDOW_MS / 2);
+                }
+                run_start = None;
+            }
+            _ => {}
+        }
+    }
+
+    let reach = target_ms / 4;
+    let mut boundaries = Vec::new();
+    let mut previous = 0;
+    while previous + target_ms < duration_ms {
+        let target = previous + target_ms;
+        let pause = pauses
+            .iter()
+            .copied()
+            .filter(|ms| ms.abs_diff(target) <= reach && *ms > previous && *ms < duration_ms)
+            .min_by_key(|ms| ms.abs_diff(target));
+        let boundary = ChunkBoundary {
+            ms: pause.unwrap_or(target),
+            silence: pause.is_some(),
+        };
+        previous = boundary.ms;
+        boundaries.push(boundary);
+    }
+
+    ChunkBoundaries {
+        duration_ms,
+        boundaries,
+    }
+}
+
+// ============================================================================
+// Meeting Stats
+// ============================================================================
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SpeakerStats {
+    speaker: String,
+    words: usize,
+    /// Only known when the labels come from timed segments.
+    talk_ms: Option<u64>,
+}
+
+/// Any field may be missing: duration needs segment timings, speakers need
+/// speaker labels (see `split_speaker_label`) in the transcript.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct MeetingStats {
+    duration_ms: Option<u64>,
+    word_count: usize,
+    words_per_minute: Option<f64>,
+    segment_count: usize,
+    speakers: Vec<SpeakerStats>,
+}
+
+#[tauri::command]
+async fn meeting_stats(app: tauri::AppHandle, meeting_id: String) -> Result<MeetingStats, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let meeting = load_meetings_sync(&app)?
+            .into_iter()
+            .find(|meeting| meeting.id == meeting_id)
+            .ok_or_else(|| format!("Meeting not found: {meeting_id}"))?;
+        Ok(compute_meeting_stats(&meeting))
+    })
+    .await
+    .map_err(|err| format!("Failed to compute meeting stats task: {err}"))?
+}
+
+fn compute_meeting_stats(meeting: &MeetingRecord) -> MeetingStats {
+    let word_count = meeting.transcript.split_whitespace().count();
+    let duration_ms = meeting
+        .segments
+        .iter()
+        .map(|segment| segment.end_ms)
+        .max()
+        .filter(|end| *end > 0);
+    let words_per_minute = duration_ms.map(|ms| word_count as f64 * 60_000.0 / ms as f64);
+
+    // A label carries over to following unlabelled text until the next one.
+    let mut speakers: Vec<SpeakerStats> = Vec::new();
+    let mut tally = |speaker: &str, words: usize, talk_ms: Option<u64>| {
+        let index = match speakers.iter().position(|stats| stats.speaker == speaker) {
+            Some(index) => index,
+            None => {
+                speakers.push(SpeakerStats {
+                    speaker: speaker.to_string(),
+                    words: 0,
+                    talk_ms: talk_ms.map(|_| 0),
+                });
+                speakers.len() - 1
+            }
+        };
+        let stats = &mut speakers[index];
+        stats.words += words;
+        if let (Some(total), Some(ms)) = (stats.talk_ms.as_mut(), talk_ms) {
+            *total += ms;
+        }
+    };
+    let mut current: Option<String> = None;
+    if meeting.segments.is_empty() {
+        for line in meeting.transcript.lines() {
+            let (label, text) = split_speaker_label(line);
+            if let Some(label) = label {
+                current = Some(label.to_string());
+            }
+            if let Some(speaker) = current.as_deref() {
+                tally(speaker, text.split_whitespace().count(), None);
+            }
+        }
+    } else {
+        for segment in &meeting.segments {
+            let (label, text) = split_speaker_label(&segment.text);
+            if let Some(label) = label {
+                current = Some(label.to_string());
+            }
+            if let Some(speaker) = current.as_deref() {
+                let talk_ms = segment.end_ms.saturating_sub(segment.start_ms);
+                tally(speaker, text.split_whitespace().count(), Some(talk_ms));
+            }
+        }
+    }
+    speakers.sort_by(|a, b| b.talk_ms.cmp(&a.talk_ms).then(b.words.cmp(&a.words)));
+
+    MeetingStats {
+        duration_ms,
+        word_count,
+        words_per_minute,
+        segment_count: meeting.segments.len(),
+        speakers,
+    }
+}
+
+/// Split a leading speaker label off a line. Only the forms this app and
+/// diarization tools write count: `Speaker 2: …`, `SPEAKER_01: …`,
+/// `[SPEAKER_01] …` and a bracketed name such as `[Alice Smith]: …` (how
+/// subtitle voices are imported). A plain `Note: …` is ordinary text.
+fn split_speaker_label(line: &str) -> (Option<&str>, &str) {
+    static LABEL: OnceLock<Regex> = OnceLock::new();
+    let pattern = LABEL.get_or_init(|| {
+        Regex::new(
+            r"^\s*(?:\[(SPEAKER_\d+)\]:?|\[([^\[\]:]{1,40})\]:|((?i:speaker)[ _]\w{1,3}):)\s+",
+        )
+        .expect("valid regex")
+    });
+    match pattern.captures(line) {
+        Some(captures) => {
+            let label = (1..=3)
+                .find_map(|group| captures.get(group))
+                .map(|m| m.as_str().trim());
+            (label, &line[captures[0].len()..])
+        }
+        None => (None, line),
+    }
+}
+
+#[derive(Serialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+enum SummaryRisk {
+    Low,
+    Medium,
+    High,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SummaryRiskReport {
+    /// The highest level any reason reached.
+    risk: SummaryRisk,
+    reasons: Vec<String>,
+}
+
+/// Heuristic check for a summary that may be made up: little or
+/// low-confidence source text, a summary out of proportion to the transcript,
+/// or figures the transcript never mentions. No AI call.
+#[tauri::command]
+async fn assess_summary_risk(
+    app: tauri::AppHandle,
+    meeting_id: String,
+) -> Result<SummaryRiskReport, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let meeting = load_meetings_sync(&app)?
+            .into_iter()
+            .find(|meeting| meeting.id == meeting_id)
+            .ok_or_else(|| format!("Meeting not found: {meeting_id}"))?;
+        if meeting.summary.trim().is_empty() {
+            return Err("Meeting has no summary to assess".to_string());
+        }
+        Ok(summary_risk(&meeting))
+    })
+    .await
+    .map_err(|err| format!("Failed to assess summary task: {err}"))?
+}
+
+fn summary_risk(meeting: &MeetingRecord) -> SummaryRiskReport {
+    let mut reasons: Vec<(SummaryRisk, String)> = Vec::new();
+
+    let transcript_words = meeting.transcript.split_whitespace().count();
+    if transcript_words < 30 {
+        reasons.push((SummaryRisk::High, format!("Transcript has only {transcript_words} words")));
+    } else if transcript_words < 150 {
+        reasons.push((SummaryRisk::Medium, format!("Transcript is short ({transcript_words} words)")));
+    }
+
+    if let Some(confidence) = average_confidence(&meeting.segments) {
+        let percent = (confidence * 100.0).round();
+        if confidence < 0.5 {
+            reasons.push((SummaryRisk::High, format!("Transcription confidence is low ({percent}%)")));
+        } else if confidence < 0.7 {
+            reasons.push((SummaryRisk::Medium, format!("Transcription confidence is fair ({percent}%)")));
+        }
+    }
+
+    // Summaries normally condense; one approaching the transcript's length has
+    // filled gaps from somewhere.
+    let summary_words = meeting.summary.split_whitespace().count();
+    let ratio = summary_words as f64 / transcript_words.max(1) as f64;
+    if ratio >= 1.0 {
+        reasons.push((
+            SummaryRisk::High,
+            format!("Summary ({summary_words} words) is as long as the transcript"),
+        ));
+    } else if ratio >= 0.5 && transcript_words >= 30 {
+        reasons.push((
+            SummaryRisk::Medium,
+            format!("Summary is {:.0}% of the transcript's length", ratio * 100.0),
+        ));
+    }
+
+    static FIGURE: OnceLock<Regex> = OnceLock::new();
+    static LIST_MARKER: OnceLock<Regex> = OnceLock::new();
+    let figure = FIGURE.get_or_init(|| Regex::new(r"\b\d+(?:[.,:]\d+)*").expect("valid regex"));
+    // "1. ", "2) " and numbered headings like "## 3.1 Risks" aren't figures.
+    let list_marker = LIST_MARKER.get_or_init(|| {
+        Regex::new(r"^\s*(?:#+\s*\d+(?:\.\d+)*[.)]?|[-*+]?\s*\d+(?:\.\d+)*[.)])\s+")
+            .expect("valid regex")
+    });
+    // "1,000" and "1000" are the same figure.
+    let normalize = |value: &str| value.replace(',', "");
+    let spoken: std::collections::HashSet<String> = figure
+        .find_iter(&meeting.transcript)
+        .map(|m| normalize(m.as_str()))
+        .collect();
+    let mut unsupported: Vec<&str> = meeting
+        .summary
+        .lines()
+        .map(|line| match list_marker.find(line) {
+            Some(marker) => &line[marker.end()..],
+            None => line,
+        })
+        .flat_map(|line| figure.find_iter(line).map(|m| m.as_str()))
+        .filter(|value| !spoken.contains(&normalize(value)))
+        .collect();
+    unsupported.sort_unstable();
+    unsupported.dedup();
+    if !unsupported.is_empty() {
+        let examples = unsupported.iter().take(5).copied().collect::<Vec<_>>().join(", ");
+        reasons.push((
+            SummaryRisk::Medium,
+            format!("Figures not found in the transcript: {examples}"),
+        ));
+    }
+
+    SummaryRiskReport {
+        risk: reasons.iter().map(|(risk, _)| *risk).max().unwrap_or(SummaryRisk::Low),
+        reasons: reasons.into_iter().map(|(_, reason)| reason).collect(),
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ModelUsage {
+    provider: String,
+    model: String,
+    meetings: usize,
+    /// Latest `updatedAt` among those meetings.
+    last_used: String,
+    /// For local models, whether the file is still in the models folder.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    installed: Option<bool>,
+}
+
+/// Distinct transcription provider/model pairs recorded on meetings, most
+/// used first. Only meetings transcribed since `transcriptionModel` was
+/// recorded count; summary models aren't stored, so they can't be listed.
+#[tauri::command]
+async fn models_in_use(app: tauri::AppHandle) -> Result<Vec<ModelUsage>, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let config = load_config_sync(&app)?;
+        let models_root = models_root(&app, &config)?;
+        let mut usage: Vec<ModelUsage> = Vec::new();
+        for meeting in load_meetings_sync(&app)? {
+            let Some(model) = meeting.transcription_model.filter(|model| !model.trim().is_empty())
+            else {
+                continue;
+            };
+            let provider = meeting.transcription_provider.unwrap_or_default();
+            match usage
+                .iter_mut()
+                .find(|entry| entry.provider == provider && entry.model == model)
+            {
+                Some(entry) => {
+                    entry.meetings += 1;
+                    if meeting.updated_at > entry.last_used {
+                        entry.last_used = meeting.updated_at;
+                    }
+                }
+                None => usage.push(ModelUsage {
+                    installed: (provider == "local").then(|| models_root.join(&model).is_file()),
+                    provider,
+                    model,
+                    meetings: 1,
+                    last_used: meeting.updated_at,
+                }),
+            }
+        }
+        usage.sort_by(|a, b| b.meetings.cmp(&a.meetings).then_with(|| a.model.cmp(&b.model)));
+        Ok(usage)
+    })
+    .await
+    .map_err(|err| format!("Failed to list models in use task: {err}"))?
+}
+
+// ============================================================================
+// Transcript Evaluation
+// ============================================================================
+
+/// Normalization applied to both texts before scoring.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WerOptions {
+    #[serde(default = "default_true")]
+    ignore_case: bool,
+    /// Drop punctuation, keeping apostrophes inside words ("don't").
+    #[serde(default = "default_true")]
+    ignore_punctuation: bool,
+}
+
+impl Default for WerOptions {
+    fn default() -> Self {
+        Self {
+            ignore_case: true,
+            ignore_punctuation: true,
+        }
+    }
+}
+
+#[derive(Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+enum AlignKind {
+    Match,
+    Substitute,
+    Insert,
+    Delete,
+}
+
+/// One step of the alignment; `reference` is missing for insertions and
+/// `hypothesis` for deletions.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AlignedWord {
+    kind: AlignKind,
+    reference: Option<String>,
+    hypothesis: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WerReport {
+    /// (substitutions + insertions + deletions) / reference words; can exceed 1.
+    wer: f64,
+    reference_words: usize,
+    hypothesis_words: usize,
+    hits: usize,
+    substitutions: usize,
+    insertions: usize,
+    deletions: usize,
+    alignment: Vec<AlignedWord>,
+}
+
+/// Word error rate of `hypothesis` against the ground-truth `reference`.
+#[tauri::command]
+async fn compute_wer(
+    hypothesis: String,
+    reference: String,
+    options: Option<WerOptions>,
+) -> Result<WerReport, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let options = options.unwrap_or_default();
+        let reference = wer_tokens(&reference, &options);
+        let hypothesis = wer_tokens(&hypothesis, &options);
+        if reference.is_empty() {
+            return Err("Reference transcript has no words".to_string());
+        }
+        let alignment = align_words(&reference, &hypothesis)?;
+
+        let count = |kind: AlignKind| alignment.iter().filter(|word| word.kind == kind).count();
+        let (hits, substitutions) = (count(AlignKind::Match), count(AlignKind::Substitute));
+        let (insertions, deletions) = (count(AlignKind::Insert), count(AlignKind::Delete));
+        Ok(WerReport {
+            wer: (substitutions + insertions + deletions) as f64 / reference.len() as f64,
+            reference_words: reference.len(),
+            hypothesis_words: hypothesis.len(),
+            hits,
+            substitutions,
+            insertions,
+            deletions,
+            alignment,
+        })
+    })
+    .await
+    .map_err(|err| format!("Failed to compute WER task: {err}"))?
+}
+
+fn wer_tokens(text: &str, options: &WerOptions) -> Vec<String> {
+    let text = if options.ignore_case {
+        text.to_lowercase()
+    } else {
+        text.to_string()
+    };
+    if !options.ignore_punctuation {
+        return text.split_whitespace().map(str::to_string).collect();
+    }
+    // Hyphens and slashes split words; a quote is only kept between letters.
+    let spaced: String = text
+        .chars()
+        .map(|c| if c.is_alphanumeric() || c == '\'' || c == '\u{2019}' { c } else { ' ' })
+        .collect();
+    spaced
+        .split_whitespace()
+        .map(|word| word.trim_matches(|c| c == '\'' || c == '\u{2019}'))
+        .filter(|word| !word.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Minimum-edit alignment (Levenshtein, unit costs). Ties prefer a match or
+/// substitution over an insertion/deletion pair.
+fn align_words(reference: &[String], hypothesis: &[String]) -> Result<Vec<AlignedWord>, String> {
+    let (n, m) = (reference.len(), hypothesis.len());
+    if (n + 1).saturating_mul(m + 1) > MAX_DIFF_CELLS {
+        return Err(format!(
+            "Transcripts are too long to align ({n} x {m} words); score shorter excerpts"
+        ));
+    }
+
+    // cost[i * (m + 1) + j] = edit distance of reference[i..] and hypothesis[j..].
+    let width = m + 1;
+    let mut cost = vec![0u32; (n + 1) * width];
+    for i in (0..=n).rev() {
+        for j in (0..=m).rev() {
+            cost[i * width + j] = if i == n {
+                (m - j) as u32
+            } else if j == m {
+                (n - i) as u32
+            } else {
+                let diagonal = cost[(i + 1) * width + j + 1]
+                    + u32::from(reference[i] != hypothesis[j]);
+                diagonal
+                    .min(cost[(i + 1) * width + j] + 1)
+                    .min(cost[i * width + j + 1] + 1)
+            };
+        }
+    }
+
+    let mut alignment = Vec::with_capacity(n.max(m));
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        let here = cost[i * width + j];
+        if i < n && j < m {
+            let same = reference[i] == hypothesis[j];
+            if here == cost[(i + 1) * width + j + 1] + u32::from(!same) {
+                alignment.push(AlignedWord {
+                    kind: if same { AlignKind::Match } else { AlignKind::Substitute },
+                    reference: Some(reference[i].clone()),
+                    hypothesis: Some(hypothesis[j].clone()),
+                });
+                i += 1;
+                j += 1;
+                continue;
+            }
+        }
+        if i < n && (j == m || here == cost[(i + 1) * width + j] + 1) {
+            alignment.push(AlignedWord {
+                kind: AlignKind::Delete,
+                reference: Some(reference[i].clone()),
+                hypothesis: None,
+            });
+            i += 1;
+        } else {
+            alignment.push(AlignedWord {
+                kind: AlignKind::Insert,
+                reference: None,
+                hypothesis: Some(hypothesis[j].clone()),
+            });
+            j += 1;
+        }
+    }
+    Ok(alignment)
+}
+
+// ============================================================================
+// Transcript Formatting
+// ============================================================================
+
+/// A pause at least this long after a finished sentence starts a paragraph.
+const PARAGRAPH_PAUSE_MS: u64 = 1500;
+/// A pause this long starts a paragraph even mid-sentence.
+const PARAGRAPH_LONG_PAUSE_MS: u64 = 4000;
+/// Paragraphs past this many words break at the next sentence end.
+const PARAGRAPH_MAX_WORDS: usize = 120;
+/// Sentences per paragraph when there are no timings to go by.
+const PARAGRAPH_SENTENCES: usize = 4;
+
+/// Regroup a meeting's transcript into paragraphs without an AI call: by
+/// pauses between segments when timings exist, otherwise by sentences (and
+/// speaker turns). With `store` the result is saved as `formattedTranscript`.
+#[tauri::command]
+async fn paragraphize_transcript(
+    app: tauri::AppHandle,
+    meeting_id: String,
+    store: Option<bool>,
+) -> Result<String, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let mut meetings = load_meetings_sync(&app)?;
+        let meeting = meetings
+            .iter_mut()
+            .find(|meeting| meeting.id == meeting_id)
+            .ok_or_else(|| format!("Meeting not found: {meeting_id}"))?;
+        if meeting.transcript.trim().is_empty() {
+            return Err("Meeting has no transcript to format".to_string());
+        }
+
+        let formatted = if meeting.segments.is_empty() {
+            paragraphs_from_text(&meeting.transcript)
+        } else {
+            paragraphs_from_segments(&meeting.segments)
+        };
+        if store.unwrap_or(false) {
+            meeting.formatted_transcript = Some(formatted.clone());
+            meeting.formatted_source_hash = Some(transcript_hash(&meeting.transcript));
+            meeting.updated_at =
+                chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true);
+            let updated = meeting.clone();
+            write_meetings_sync(&app, &mut meetings)?;
+            let _ = app.emit("meeting-updated", &updated);
+        }
+        Ok(formatted)
+    })
+    .await
+    .map_err(|err| format!("Failed to format transcript task: {err}"))?
+}
+
+fn ends_sentence(text: &str) -> bool {
+    text.trim_end()
+        .trim_end_matches(['"', '\'', ')', '\u{201D}', '\u{2019}'])
+        .ends_with(['.', '?', '!', '\u{2026}', '\u{3002}', '\u{FF1F}', '\u{FF01}'])
+}
+
+fn paragraphs_from_segments(segments: &[TranscriptSegment]) -> String {
+    let mut paragraphs = Vec::new();
+    let mut current = String::new();
+    let mut words = 0;
+    let mut previous_end = 0;
+    for segment in segments {
+        let text = segment.text.trim();
+        if text.is_empty() {
+            continue;
+        }
+        if !current.is_empty() {
+            let gap = segment.start_ms.saturating_sub(previous_end);
+            let sentence_done = ends_sentence(&current);
+            if gap >= PARAGRAPH_LONG_PAUSE_MS
+                || (sentence_done && (gap >= PARAGRAPH_PAUSE_MS || words >= PARAGRAPH_MAX_WORDS))
+            {
+                paragraphs.push(std::mem::take(&mut current));
+                words = 0;
+            } else {
+                current.push(' ');
+            }
+        }
+        current.push_str(text);
+        words += text.split_whitespace().count();
+        previous_end = segment.end_ms;
+    }
+    if !current.is_empty() {
+        paragraphs.push(current);
+    }
+    paragraphs.join("\n\n")
+}
+
+/// Sentence-based fallback. Choppy lines are joined, but blank lines and
+/// `Speaker:` labels still start a new paragraph.
+fn paragraphs_from_text(text: &str) -> String {
+    let mut blocks = Vec::new();
+    let mut current = String::new();
+    for line in text.lines().map(str::trim) {
+        let new_turn = split_speaker_label(line).0.is_some();
+        if (line.is_empty() || new_turn) && !current.is_empty() {
+            blocks.push(std::mem::take(&mut current));
+        }
+        if !line.is_empty() {
+            if !current.is_empty() {
+                current.push(' ');
+            }
+            current.push_str(line);
+        }
+    }
+    if !current.is_empty() {
+        blocks.push(current);
+    }
+
+    let mut paragraphs = Vec::new();
+    for block in blocks {
+        let mut paragraph = String::new();
+        let (mut words, mut sentences) = (0, 0);
+        for word in block.split_whitespace() {
+            if !paragraph.is_empty() {
+                paragraph.push(' ');
+            }
+            paragraph.push_str(word);
+            words += 1;
+            let sentence_end = ends_sentence(word);
+            sentences += usize::from(sentence_end);
+            // Unpunctuated run-ons still get broken up, just less neatly.
+            if (sentence_end && (sentences >= PARAGRAPH_SENTENCES || words >= PARAGRAPH_MAX_WORDS))
+                || words >= PARAGRAPH_MAX_WORDS * 2
+            {
+                paragraphs.push(std::mem::take(&mut paragraph));
+                (words, sentences) = (0, 0);
+            }
+        }
+        if !paragraph.is_empty() {
+            paragraphs.push(paragraph);
+        }
+    }
+    paragraphs.join("\n\n")
+}
+
+// ============================================================================
+// Meeting Audio
+// ============================================================================
+
+#[tauri::command]
+async fn attach_audio(
+    app: tauri::AppHandle,
+    meeting_id: String,
+    audio_base64: String,
+) -> Result<MeetingRecord, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let audio = decode_audio_input(&audio_base64)?;
+
+        let mut meetings = load_meetings_sync(&app)?;
+        let meeting = meetings
+            .iter_mut()
+            .find(|meeting| meeting.id == meeting_id)
+            .ok_or_else(|| format!("Meeting not found: {meeting_id}"))?;
+
+        let data_dir = voxii_data_dir(&app)?;
+        let audio_dir = data_dir.join("audio");
+        fs::create_dir_all(&audio_dir)
+            .map_err(|err| format!("Failed to create audio dir: {err}"))?;
+
+        let relative = format!("audio/{}.{}", meeting.id, audio.format.extension());
+        fs::write(data_dir.join(&relative), &audio.bytes)
+            .map_err(|err| format!("Failed to write meeting audio: {err}"))?;
+
+        // A re-recording may change the container; drop the stale file.
+        if let Some(previous) = meeting.audio_path.as_deref() {
+            if previous != relative {
+                let _ = fs::remove_file(data_dir.join(previous));
+            }
+        }
+
+        meeting.audio_path = Some(relative);
+        let updated = meeting.clone();
+        write_meetings_sync(&app, &mut meetings)?;
+
+        let _ = app.emit("meeting-updated", &updated);
+        Ok(updated)
+    })
+    .await
+    .map_err(|err| format!("Failed to attach audio task: {err}"))?
+}
+
+#[tauri::command]
+async fn get_audio_path(
+    app: tauri::AppHandle,
+    meeting_id: String,
+) -> Result<Option<String>, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let meetings = load_meetings_sync(&app)?;
+        let meeting = meetings
+            .iter()
+            .find(|meeting| meeting.id == meeting_id)
+            .ok_or_else(|| format!("Meeting not found: {meeting_id}"))?;
+
+        let Some(relative) = meeting.audio_path.as_deref() else {
+            return Ok(None);
+        };
+        let path = voxii_data_dir(&app)?.join(relative);
+        if !path.is_file() {
+            return Err(format!("Meeting audio is missing: {}", path.display()));
+        }
+        Ok(Some(path.to_string_lossy().to_string()))
+    })
+    .await
+    .map_err(|err| format!("Failed to get audio path task: {err}"))?
+}
+
+/// Re-run transcription on a meeting's attached recording with the current
+/// settings, keeping the old transcript in `transcript_history`.
+#[tauri::command]
+async fn retranscribe_meeting(
+    app: tauri::AppHandle,
+    meeting_id: String,
+    provider_override: Option<String>,
+) -> Result<MeetingRecord, String> {
+    let progress = |stage: &str| {
+        let _ = app.emit(
+            "retranscribe-progress",
+            serde_json::json!({ "meetingId": meeting_id, "stage": stage }),
+        );
+    };
+
+    let result = async {
+        progress("loading");
+        let audio = tauri::async_runtime::spawn_blocking({
+            let app = app.clone();
+            let meeting_id = meeting_id.clone();
+            move || -> Result<AudioInput, String> {
+                let meeting = load_meetings_sync(&app)?
+                    .into_iter()
+                    .find(|meeting| meeting.id == meeting_id)
+                    .ok_or_else(|| format!("Meeting not found: {meeting_id}"))?;
+                let relative = meeting.audio_path.ok_or(
+                    "This meeting has no recording attached, so it can't be re-transcribed",
+                )?;
+                let bytes = fs::read(voxii_data_dir(&app)?.join(&relative))
+                    .map_err(|err| format!("Failed to read meeting audio: {err}"))?;
+                let format = AudioFormat::sniff(&bytes);
+                Ok(AudioInput { bytes, format })
+            }
+        })
+        .await
+        .map_err(|err| format!("Failed to load meeting audio task: {err}"))??;
+
+        progress("transcribing");
+        let response =
+            transcribe_with_settings(
+                &app,
+                audio,
+                None,
+                provider_override.as_deref(),
+                Some(&meeting_id),
+            )
+            .await?;
+
+        progress("saving");
+        tauri::async_runtime::spawn_blocking({
+            let app = app.clone();
+            let meeting_id = meeting_id.clone();
+            move || {
+                let mut meetings = load_meetings_sync(&app)?;
+                let meeting = meetings
+                    .iter_mut()
+                    .find(|meeting| meeting.id == meeting_id)
+                    .ok_or_else(|| format!("Meeting not found: {meeting_id}"))?;
+
+                let now = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true);
+                if !meeting.transcript.trim().is_empty() {
+                    let previous = std::mem::take(&mut meeting.transcript);
+                    meeting.transcript_history.push(TranscriptRevision {
+                        transcript: previous,
+                        replaced_at: now.clone(),
+                    });
+                }
+                meeting.transcript = response.transcript.trim().to_string();
+                meeting.transcription_provider = Some(response.provider.clone());
+                meeting.transcription_model =
+                    Some(response.model.clone()).filter(|model| !model.is_empty());
+                // Cleaned transcripts no longer line up with whisper's segments.
+                meeting.segments = if response.raw_transcript.is_none() {
+                    response.segments
+                } else {
+                    Vec::new()
+                };
+                // Chapter offsets and formatting belong to the old transcript.
+                meeting.chapters.clear();
+                meeting.formatted_transcript = None;
+                meeting.formatted_source_hash = None;
+                meeting.updated_at = now;
+
+                let updated = meeting.clone();
+                write_meetings_sync(&app, &mut meetings)?;
+                Ok(updated)
+            }
+        })
+        .await
+        .map_err(|err| format!("Failed to save re-transcription task: {err}"))?
+    }
+    .await;
+
+    match &result {
+        Ok(updated) => {
+            let _ = app.emit("meeting-updated", updated);
+            let _ = app.emit("retranscribe-done", serde_json::json!({ "meetingId": meeting_id }));
+        }
+        Err(err) => {
+            let _ = app.emit(
+                "retranscribe-error",
+                serde_json::json!({ "meetingId": meeting_id, "error": err }),
+            );
+        }
+    }
+    result
+}
+
+/// Pick a file extension from the container's magic bytes.
+fn audio_file_extension(bytes: &[u8]) -> &'static str {
+    if bytes.starts_with(b"RIFF") && bytes.get(8..12) == Some(b"WAVE".as_slice()) {
+        "wav"
+    } else if bytes.starts_with(&[0x1A, 0x45, 0xDF, 0xA3]) {
+        "webm"
+    } else if bytes.starts_with(b"OggS") {
+        "ogg"
+    } else if bytes.starts_with(b"ID3")
+        || [[0xFF, 0xFB], [0xFF, 0xF3], [0xFF, 0xF2]].iter().any(|sync| bytes.starts_with(sync))
+    {
+        "mp3"
+    } else if bytes.get(4..8) == Some(b"ftyp".as_slice()) {
+        "m4a"
+    } else if bytes.starts_with(b"fLaC") {
+        "flac"
+    } else {
+        "bin"
+    }
+}
+
+// ============================================================================
+// Backup
+// ============================================================================
+
+const BACKUP_FORMAT: &str = "voxii-backup";
+const BACKUP_VERSION: u32 = 1;
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BackupManifest {
+    format: String,
+    version: u32,
+    app_version: String,
+    meeting_count: usize,
+    audio_files: Vec<String>,
+    includes_secrets: bool,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BackupImportReport {
+    meetings_added: usize,
+    meetings_updated: usize,
+    meetings_skipped: usize,
+    audio_files: usize,
+    config_restored: bool,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum BackupConflict {
+    /// Keep local data when it already exists.
+    Skip,
+    /// Replace local data with the bundle's.
+    Overwrite,
+    /// Keep whichever meeting was updated most recently.
+    Merge,
+}
+
+/// Bundle config, meetings and attached audio into a single zip. API keys are
+/// blanked unless `include_secrets` is set.
+#[tauri::command]
+async fn export_backup(
+    app: tauri::AppHandle,
+    path: String,
+    include_secrets: Option<bool>,
+) -> Result<BackupManifest, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let include_secrets = include_secrets.unwrap_or(false);
+        let mut config = load_config_sync(&app)?;
+        config.migrate_from_v1();
+        if !include_secrets {
+            config.transcription.openai_compatible.api_key.clear();
+            config.ai.openai_compatible.api_key.clear();
+            config.server.token.clear();
+        }
+        let meetings = load_meetings_sync(&app)?;
+        let data_dir = voxii_data_dir(&app)?;
+
+        let audio_files: Vec<String> = meetings
+            .iter()
+            .filter_map(|meeting| meeting.audio_path.clone())
+            .filter(|relative| data_dir.join(relative).is_file())
+            .collect();
+
+        let manifest = BackupManifest {
+            format: BACKUP_FORMAT.to_string(),
+            version: BACKUP_VERSION,
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            meeting_count: meetings.len(),
+            audio_files,
+            includes_secrets: include_secrets,
+        };
+
+        let file = fs::File::create(&path)
+            .map_err(|err| format!("Failed to create backup file: {err}"))?;
+        let mut zip = zip::ZipWriter::new(file);
+        let options = zip::write::SimpleFileOptions::default()
+            .compression_method(zip::CompressionMethod::Deflated);
+
+        let mut add_entry = |name: &str, bytes: &[u8]| -> Result<(), String> {
+            zip.start_file(name, options)
+                .and_then(|_| zip.write_all(bytes).map_err(Into::into))
+                .map_err(|err| format!("Failed to write {name} to backup: {err}"))
+        };
+
+        add_entry(
+            "manifest.json",
+            &serde_json::to_vec_pretty(&manifest)
+                .map_err(|err| format!("Failed to serialize backup manifest: {err}"))?,
+        )?;
+        add_entry(
+            "config.json",
+            &serde_json::to_vec_pretty(&config)
+                .map_err(|err| format!("Failed to serialize config: {err}"))?,
+        )?;
+        add_entry(
+            "meetings.json",
+            &serde_json::to_vec_pretty(&meetings)
+                .map_err(|err| format!("Failed to serialize meetings: {err}"))?,
+        )?;
+        for relative in &manifest.audio_files {
+            let bytes = fs::read(data_dir.join(relative))
+                .map_err(|err| format!("Failed to read meeting audio {relative}: {err}"))?;
+            add_entry(relative, &bytes)?;
+        }
+
+        zip.finish()
+            .map_err(|err| format!("Failed to finalize backup: {err}"))?;
+        Ok(manifest)
+    })
+    .await
+    .map_err(|err| format!("Failed to export backup task: {err}"))?
+}
+
+/// Restore a bundle written by `export_backup`. `conflict` is `skip`,
+/// `overwrite` or `merge` and decides what happens to meetings (and the config)
+/// that already exist locally.
+#[tauri::command]
+async fn import_backup(
+    app: tauri::AppHandle,
+    path: String,
+    conflict: Option<String>,
+) -> Result<BackupImportReport, String> {
+    let conflict = match conflict.as_deref().unwrap_or("skip") {
+        "skip" => BackupConflict::Skip,
+        "overwrite" => BackupConflict::Overwrite,
+        "merge" => BackupConflict::Merge,
+        other => return Err(format!("Unknown conflict mode: {other}")),
+    };
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let file = fs::File::open(&path)
+            .map_err(|err| format!("Failed to open backup: {err}"))?;
+        let mut archive = zip::ZipArchive::new(file)
+            .map_err(|err| format!("Invalid backup file: {err}"))?;
+
+        let mut read_entry = |name: &str| -> Result<Vec<u8>, String> {
+            let mut entry = archive
+                .by_name(name)
+                .map_err(|_| format!("Invalid backup: missing {name}"))?;
+            let mut bytes = Vec::new();
+            std::io::Read::read_to_end(&mut entry, &mut bytes)
+                .map_err(|err| format!("Failed to read {name} from backup: {err}"))?;
+            Ok(bytes)
+        };
+
+        let manifest: BackupManifest = serde_json::from_slice(&read_entry("manifest.json")?)
+            .map_err(|err| format!("Invalid backup manifest: {err}"))?;
+        if manifest.format != BACKUP_FORMAT {
+            return Err("Invalid backup: not a Voxii backup".to_string());
+        }
+        if manifest.version > BACKUP_VERSION {
+            return Err(format!(
+                "Backup version {} is newer than this app supports",
+                manifest.version
+            ));
+        }
+
+        let mut imported_config: AppConfig = serde_json::from_slice(&read_entry("config.json")?)
+            .map_err(|err| format!("Invalid backup config: {err}"))?;
+        imported_config.migrate_from_v1();
+        let imported_meetings: Vec<MeetingRecord> =
+            serde_json::from_slice(&read_entry("meetings.json")?)
+                .map_err(|err| format!("Invalid backup meetings: {err}"))?;
+
+        // Only restore audio the manifest declares, and only inside `audio/`.
+        let mut audio = HashMap::new();
+        for relative in &manifest.audio_files {
+            let safe = Path::new(relative)
+                .strip_prefix("audio")
+                .ok()
+                .and_then(|rest| rest.file_name().map(|name| Path::new(name) == rest))
+                .unwrap_or(false);
+            if !safe {
+                return Err(format!("Invalid backup: unexpected audio path {relative}"));
+            }
+            audio.insert(relative.clone(), read_entry(relative)?);
+        }
+
+        let data_dir = voxii_data_dir(&app)?;
+        let mut meetings = load_meetings_sync(&app)?;
+        let mut report = BackupImportReport {
+            meetings_added: 0,
+            meetings_updated: 0,
+            meetings_skipped: 0,
+            audio_files: 0,
+            config_restored: false,
+        };
+
+        for mut incoming in imported_meetings {
+            if incoming
+                .audio_path
+                .as_ref()
+                .is_some_and(|relative| !audio.contains_key(relative))
+            {
+                incoming.audio_path = None;
+            }
+
+            let position = meetings.iter().position(|meeting| meeting.id == incoming.id);
+            let replace = match (position, conflict) {
+                (None, _) => true,
+                (Some(_), BackupConflict::Skip) => false,
+                (Some(_), BackupConflict::Overwrite) => true,
+                // ISO-8601 timestamps compare correctly as strings.
+                (Some(index), BackupConflict::Merge) => {
+                    incoming.updated_at > meetings[index].updated_at
+                }
+            };
+            if !replace {
+                report.meetings_skipped += 1;
+                continue;
+            }
+
+            if let Some(relative) = incoming.audio_path.as_ref() {
+                let target = data_dir.join(relative);
+                if let Some(parent) = target.parent() {
+                    fs::create_dir_all(parent)
+                        .map_err(|err| format!("Failed to create audio dir: {err}"))?;
+                }
+                fs::write(&target, &audio[relative])
+                    .map_err(|err| format!("Failed to restore meeting audio: {err}"))?;
+                report.audio_files += 1;
+            }
+
+            match position {
+                Some(index) => {
+                    meetings[index] = incoming;
+                    report.meetings_updated += 1;
+                }
+                None => {
+                    meetings.push(incoming);
+                    report.meetings_added += 1;
+                }
+            }
+        }
+        write_meetings_sync(&app, &mut meetings)?;
+
+        let path = config_path(&app)?;
+        if conflict != BackupConflict::Skip || !path.exists() {
+            // Bundles exported without secrets shouldn't wipe the local key.
+            if let Ok(current) = load_config_sync(&app) {
+                let transcription_key = &mut imported_config.transcription.openai_compatible.api_key;
+                if transcription_key.is_empty() {
+                    *transcription_key = current.transcription.openai_compatible.api_key;
+                }
+                let ai_key = &mut imported_config.ai.openai_compatible.api_key;
+                if ai_key.is_empty() {
+                    *ai_key = current.ai.openai_compatible.api_key;
+                }
+                let server_token = &mut imported_config.server.token;
+                if server_token.is_empty() {
+                    *server_token = current.server.token;
+                }
+            }
+            save_config(&path, &imported_config)?;
+            report.config_restored = true;
+        }
+
+        Ok(report)
+    })
+    .await
+    .map_err(|err| format!("Failed to import backup task: {err}"))?
+}
+
+// ============================================================================
+// Export
+// ============================================================================
+
+#[tauri::command]
+async fn export_meeting_markdown(
+    app: tauri::AppHandle,
+    meeting: MeetingRecord,
+    include_transcript: bool,
+    include_timestamps: Option<bool>,
+    frontmatter: Option<bool>,
+) -> Result<MarkdownExport, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let config = load_config_sync(&app)?;
+        let mut md = render_meeting_markdown(
+            &meeting,
+            &config,
+            include_transcript,
+            include_timestamps.unwrap_or(false),
+        );
+        if frontmatter.unwrap_or(config.export.frontmatter) {
+            md.insert_str(0, &render_front_matter(&meeting));
+        }
+
+        // Save to file. The passed meeting may be newer than the stored copy.
+        let mut meetings = load_meetings_sync(&app)?;
+        meetings.retain(|other| other.id != meeting.id);
+        meetings.push(meeting.clone());
+        let stems = export_stem_counts(&meetings);
+        let export_path = export_dir(&config);
+        let file_path = export_path.join(export_file_name(&meeting, &stems, "md"));
+
+        // A failed write still hands the rendered markdown back so the user
+        // can copy it instead of losing the export.
+        let written = fs::create_dir_all(&export_path)
+            .and_then(|_| fs::write(&file_path, &md));
+        if written.is_ok() && config.export.sidecar_metadata {
+            // The markdown is what the user asked for; a missing sidecar is only logged.
+            let meta_path = file_path.with_extension("meta.json");
+            if let Err(err) = fs::write(&meta_path, export_metadata(&meeting).to_string()) {
+                tracing::warn!("sidecar {} not written: {err}", meta_path.display());
+            }
+        }
+        Ok(match written {
+            Ok(()) => MarkdownExport {
+                path: Some(file_path.to_string_lossy().to_string()),
+                content: md,
+                error: None,
+            },
+            Err(err) => {
+                tracing::warn!("export to {} failed: {err}", file_path.display());
+                MarkdownExport {
+                    path: None,
+                    content: md,
+                    error: Some(describe_export_error(&err)),
+                }
+            }
+        })
+    })
+    .await
+    .map_err(|err| format!("Failed to export: {err}"))?
+}
+
+/// Write a self-contained `<export dir>/<date> - <title>.html` for sharing by
+/// email or printing to PDF. Returns the path.
+#[tauri::command]
+async fn export_meeting_html(
+    app: tauri::AppHandle,
+    meeting_id: String,
+    include_transcript: Option<bool>,
+    include_timestamps: Option<bool>,
+) -> Result<String, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let config = load_config_sync(&app)?;
+        let meetings = load_meetings_sync(&app)?;
+        let stems = export_stem_counts(&meetings);
+        let meeting = meetings
+            .into_iter()
+            .find(|meeting| meeting.id == meeting_id)
+            .ok_or_else(|| format!("Meeting not found: {meeting_id}"))?;
+        let html = render_meeting_html(
+            &meeting,
+            &config,
+            include_transcript.unwrap_or(true),
+            include_timestamps.unwrap_or(false),
+        );
+
+        let export_path = export_dir(&config);
+        let file_path = export_path.join(export_file_name(&meeting, &stems, "html"));
+        fs::create_dir_all(&export_path)
+            .and_then(|_| fs::write(&file_path, html))
+            .map_err(|err| describe_export_error(&err))?;
+        Ok(file_path.to_string_lossy().to_string())
+    })
+    .await
+    .map_err(|err| format!("Failed to export HTML task: {err}"))?
+}
+
+/// Write just the transcript to `<export dir>/<date> - <title>.transcript.txt`,
+/// one `[mm:ss]`-prefixed line per segment when asked and timings exist.
+#[tauri::command]
+async fn export_transcript(
+    app: tauri::AppHandle,
+    meeting_id: String,
+    include_timestamps: bool,
+) -> Result<String, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let config = load_config_sync(&app)?;
+        let meetings = load_meetings_sync(&app)?;
+        let stems = export_stem_counts(&meetings);
+        let meeting = meetings
+            .into_iter()
+            .find(|meeting| meeting.id == meeting_id)
+            .ok_or_else(|| format!("Meeting not found: {meeting_id}"))?;
+        if meeting.transcript.trim().is_empty() {
+            return Err("Meeting has no transcript to export".to_string());
         }
 
-        let path = Path::new(trimmed);
-        if path.is_file() {
-            let name = path
-                .file_name()
-                .and_then(|value| value.to_str())
-                .unwrap_or("")
-                .to_string();
-            return Ok(if name.is_empty() { Vec::new() } else { vec![name] });
+        let mut text = String::new();
+        if include_timestamps && !meeting.segments.is_empty() {
+            for segment in &meeting.segments {
+                text.push_str(&format!(
+                    "[{}] {}\n",
+                    format_timestamp(segment.start_ms),
+                    segment.text.trim()
+                ));
+            }
+        } else {
+            text.push_str(meeting.transcript.trim());
+            text.push('\n');
         }
 
-        if !path.is_dir() {
-            return Ok(Vec::new());
-        }
+        let export_path = export_dir(&config);
+        let file_path = export_path.join(export_file_name(&meeting, &stems, "transcript.txt"));
+        fs::create_dir_all(&export_path)
+            .and_then(|_| fs::write(&file_path, text))
+            .map_err(|err| describe_export_error(&err))?;
+        Ok(file_path.to_string_lossy().to_string())
+    })
+    .await
+    .map_err(|err| format!("Failed to export transcript task: {err}"))?
+}
 
-        let mut names = Vec::new();
-        for entry in fs::read_dir(path)
-            .map_err(|err| format!("Failed to read model dir: {err}"))? {
-            let entry = entry.map_err(|err| format!("Failed to read model dir: {err}"))?;
-            let entry_path = entry.path();
-            if entry_path.extension().and_then(|ext| ext.to_str()) == Some("bin") {
-                if let Some(name) = entry_path.file_name().and_then(|value| value.to_str()) {
-                    names.push(name.to_string());
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RedactedExport {
+    path: String,
+    redactions: usize,
+}
+
+/// Write a copy with emails, phone numbers and `export.redactionPatterns`
+/// matches masked, for sharing outside the team. `format` is `txt` (the
+/// transcript) or `markdown` (the whole meeting). The stored meeting is not
+/// changed.
+#[tauri::command]
+async fn export_redacted_transcript(
+    app: tauri::AppHandle,
+    meeting_id: String,
+    format: Option<String>,
+) -> Result<RedactedExport, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let config = load_config_sync(&app)?;
+        let meetings = load_meetings_sync(&app)?;
+        let stems = export_stem_counts(&meetings);
+        let meeting = meetings
+            .into_iter()
+            .find(|meeting| meeting.id == meeting_id)
+            .ok_or_else(|| format!("Meeting not found: {meeting_id}"))?;
+
+        let (text, extension) = match format.as_deref().unwrap_or("txt") {
+            "txt" | "text" => {
+                if meeting.transcript.trim().is_empty() {
+                    return Err("Meeting has no transcript to export".to_string());
                 }
+                (format!("{}\n", meeting.transcript.trim()), "redacted.txt")
             }
-        }
+            "markdown" | "md" => (
+                render_meeting_markdown(&meeting, &config, true, false),
+                "redacted.md",
+            ),
+            other => return Err(format!("Unsupported export format: {other}")),
+        };
+        let (text, redactions) = redact_pii(&text, &config.export.redaction_patterns)?;
 
-        names.sort();
-        Ok(names)
+        let export_path = export_dir(&config);
+        let file_path = export_path.join(export_file_name(&meeting, &stems, extension));
+        fs::create_dir_all(&export_path)
+            .and_then(|_| fs::write(&file_path, text))
+            .map_err(|err| describe_export_error(&err))?;
+        Ok(RedactedExport {
+            path: file_path.to_string_lossy().to_string(),
+            redactions,
+        })
     })
     .await
-    .map_err(|err| format!("Failed to list local models task: {err}"))?
+    .map_err(|err| format!("Failed to export redacted transcript task: {err}"))?
 }
 
-#[tauri::command]
-fn enhance_text(text: String, model: String) -> Result<String, String> {
-    let temp_dir = std::env::temp_dir().join("voxii");
-    fs::create_dir_all(&temp_dir)
-        .map_err(|err| format!("Failed to create temp dir: {err}"))?;
+/// Mask contact details plus any `custom` regex matches; returns the text and
+/// how many spans were replaced. Emails go first so their digits aren't
+/// mistaken for phone numbers.
+fn redact_pii(text: &str, custom: &[String]) -> Result<(String, usize), String> {
+    static BUILT_IN: OnceLock<Vec<(Regex, &'static str)>> = OnceLock::new();
+    let built_in = BUILT_IN.get_or_init(|| {
+        vec![
+            (
+                Regex::new(r"(?i)\b[a-z0-9._%+-]+@[a-z0-9-]+(?:\.[a-z0-9-]+)*\.[a-z]{2,}\b")
+                    .expect("valid regex"),
+                "[EMAIL]",
+            ),
+            (
+                Regex::new(r"\+\d{1,3}(?:[\s.-]?\(?\d{2,4}\)?){2,5}\b|(?:\(\d{3}\)\s?|\b\d{3}[\s.-])\d{3}[\s.-]\d{4}\b")
+                    .expect("valid regex"),
+                "[PHONE]",
+            ),
+        ]
+    });
+    let custom = custom
+        .iter()
+        .map(|pattern| {
+            Regex::new(pattern)
+                .map(|regex| (regex, "[REDACTED]"))
+                .map_err(|err| format!("Invalid redaction pattern {pattern:?}: {err}"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
 
-    let id = uuid::Uuid::new_v4().to_string();
-    let input_path = temp_dir.join(format!("{id}_enhance.json"));
+    let mut redacted = text.to_string();
+    let mut count = 0;
+    for (pattern, replacement) in built_in.iter().chain(&custom) {
+        count += pattern.find_iter(&redacted).count();
+        redacted = pattern.replace_all(&redacted, *replacement).into_owned();
+    }
+    Ok((redacted, count))
+}
 
-    let payload = serde_json::json!({
-        "text": text,
-        "model": model
-    });
+/// File types Voxii writes to the export dir.
+const EXPORT_EXTENSIONS: [&str; 9] =
+    ["md", "docx", "pdf", "html", "json", "txt", "srt", "vtt", "ics"];
+const DEFAULT_RECENT_EXPORTS: usize = 20;
+const MAX_RECENT_EXPORTS: usize = 200;
 
-    fs::write(&input_path, payload.to_string())
-        .map_err(|err| format!("Failed to write enhance payload: {err}"))?;
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RecentExport {
+    name: String,
+    path: String,
+    size: u64,
+    modified_at: String,
+}
 
-    let script_path = Path::new(env!("CARGO_MANIFEST_DIR"))
-        .join("scripts")
-        .join("copilot-enhance.mjs");
+/// Newest files in the configured export dir (top level only), skipping
+/// `.meta.json` sidecars. An export dir that doesn't exist yet is empty.
+#[tauri::command]
+async fn list_recent_exports(
+    app: tauri::AppHandle,
+    limit: Option<usize>,
+) -> Result<Vec<RecentExport>, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let config = load_config_sync(&app)?;
+        let limit = limit.unwrap_or(DEFAULT_RECENT_EXPORTS).min(MAX_RECENT_EXPORTS);
+        let entries = match fs::read_dir(export_dir(&config)) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(format!("Failed to read export directory: {err}")),
+        };
 
-    if !script_path.exists() {
-        return Err(format!("Enhance script not found: {}", script_path.display()));
+        let mut files: Vec<(SystemTime, RecentExport)> = entries
+            .filter_map(Result::ok)
+            .filter_map(|entry| {
+                let path = entry.path();
+                let name = entry.file_name().to_string_lossy().to_string();
+                let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+                if !EXPORT_EXTENSIONS.contains(&extension.as_str()) || name.ends_with(".meta.json") {
+                    return None;
+                }
+                let metadata = entry.metadata().ok().filter(|metadata| metadata.is_file())?;
+                let modified = metadata.modified().ok()?;
+                let modified_at = chrono::DateTime::<chrono::Utc>::from(modified)
+                    .to_rfc3339_opts(chrono::SecondsFormat::Millis, true);
+                Some((
+                    modified,
+                    RecentExport {
+                        name,
+                        path: path.to_string_lossy().to_string(),
+                        size: metadata.len(),
+                        modified_at,
+                    },
+                ))
+            })
+            .collect();
+        files.sort_by_key(|(modified, _)| std::cmp::Reverse(*modified));
+        Ok(files.into_iter().take(limit).map(|(_, file)| file).collect())
+    })
+    .await
+    .map_err(|err| format!("Failed to list exports task: {err}"))?
+}
+
+/// Open the configured export dir in the system file manager, creating it first.
+#[tauri::command]
+async fn open_exports_folder(app: tauri::AppHandle) -> Result<String, String> {
+    let config = load_config(app.clone()).await?;
+    let dir = export_dir(&config);
+    fs::create_dir_all(&dir).map_err(|err| describe_export_error(&err))?;
+    let dir = dir.to_string_lossy().to_string();
+    app.opener()
+        .open_path(dir.clone(), None::<&str>)
+        .map_err(|err| format!("Failed to open export folder: {err}"))?;
+    Ok(dir)
+}
+
+/// Select an exported file in the file manager. Only paths inside the export
+/// dir are accepted.
+#[tauri::command]
+async fn reveal_export(app: tauri::AppHandle, path: String) -> Result<(), String> {
+    let config = load_config(app.clone()).await?;
+    let dir = export_dir(&config)
+        .canonicalize()
+        .map_err(|err| format!("Failed to resolve export directory: {err}"))?;
+    let file = Path::new(&path)
+        .canonicalize()
+        .map_err(|err| format!("Failed to resolve export file: {err}"))?;
+    if !file.starts_with(&dir) {
+        return Err(format!("Not an export file: {path}"));
     }
+    app.opener()
+        .reveal_item_in_dir(&file)
+        .map_err(|err| format!("Failed to reveal export: {err}"))
+}
 
-    let output = Command::new("node")
-        .arg(script_path)
-        .arg(&input_path)
-        .output()
-        .map_err(|err| format!("Failed to run Copilot SDK: {err}"))?;
+/// Machine-readable companion to an exported file, for re-associating it
+/// with the meeting.
+fn export_metadata(meeting: &MeetingRecord) -> serde_json::Value {
+    serde_json::json!({
+        "id": meeting.id,
+        "title": meeting.title,
+        "createdAt": meeting.created_at,
+        "updatedAt": meeting.updated_at,
+        "actionItemCount": meeting.action_items.len(),
+        "transcriptionProvider": meeting.transcription_provider,
+        "transcriptionModel": meeting.transcription_model,
+        "exportedAt": chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+    })
+}
 
-    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
-    let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+/// Write the meeting's timed segments as `srt` or `vtt` subtitles next to the
+/// other exports. `timecode_offset_ms` shifts every cue (e.g. to match a clip's
+/// position in the master video; the result is clamped at zero) and cues
+/// longer than `max_cue_duration_ms` are split.
+#[tauri::command]
+async fn export_subtitles(
+    app: tauri::AppHandle,
+    meeting_id: String,
+    format: String,
+    timecode_offset_ms: Option<i64>,
+    max_cue_duration_ms: Option<u64>,
+) -> Result<String, String> {
+    let vtt = match format.trim().to_ascii_lowercase().as_str() {
+        "srt" => false,
+        "vtt" | "webvtt" => true,
+        other => return Err(format!("Unknown subtitle format: {other} (use srt or vtt)")),
+    };
 
-    if !output.status.success() {
-        return Err(format!(
-            "Copilot SDK failed (code {}).\nstdout: {}\nstderr: {}",
-            output.status.code().unwrap_or(-1),
-            stdout,
-            stderr
-        ));
+    tauri::async_runtime::spawn_blocking(move || {
+        let config = load_config_sync(&app)?;
+        let meetings = load_meetings_sync(&app)?;
+        let stems = export_stem_counts(&meetings);
+        let meeting = meetings
+            .into_iter()
+            .find(|meeting| meeting.id == meeting_id)
+            .ok_or_else(|| format!("Meeting not found: {meeting_id}"))?;
+        if meeting.segments.is_empty() {
+            return Err("Meeting has no timed segments to export as subtitles".to_string());
+        }
+
+        let cues = subtitle_cues(
+            &meeting.segments,
+            timecode_offset_ms.unwrap_or(0),
+            max_cue_duration_ms.filter(|max| *max > 0),
+        );
+        let mut out = String::new();
+        if vtt {
+            out.push_str("WEBVTT\n\n");
+        }
+        for (index, (start, end, text)) in cues.iter().enumerate() {
+            if !vtt {
+                out.push_str(&format!("{}\n", index + 1));
+            }
+            out.push_str(&format!(
+                "{} --> {}\n{text}\n\n",
+                format_cue_time(*start, vtt),
+                format_cue_time(*end, vtt)
+            ));
+        }
+
+        let export_path = export_dir(&config);
+        let extension = if vtt { "vtt" } else { "srt" };
+        let file_path = export_path.join(export_file_name(&meeting, &stems, extension));
+        fs::create_dir_all(&export_path)
+            .and_then(|_| fs::write(&file_path, out))
+            .map_err(|err| describe_export_error(&err))?;
+        Ok(file_path.to_string_lossy().to_string())
+    })
+    .await
+    .map_err(|err| format!("Failed to export subtitles task: {err}"))?
+}
+
+/// Shifted `(start_ms, end_ms, text)` cues, with overlong segments split into
+/// evenly timed pieces at word boundaries.
+fn subtitle_cues(
+    segments: &[TranscriptSegment],
+    offset_ms: i64,
+    max_cue_ms: Option<u64>,
+) -> Vec<(u64, u64, String)> {
+    let shift = |ms: u64| (ms as i64).saturating_add(offset_ms).max(0) as u64;
+    let mut cues = Vec::new();
+    for segment in segments {
+        let text = segment.text.trim();
+        if text.is_empty() {
+            continue;
+        }
+        let (start, end) = (shift(segment.start_ms), shift(segment.end_ms));
+        // A negative offset can push whole cues before zero.
+        if end == start && segment.end_ms > segment.start_ms {
+            continue;
+        }
+        let duration = end.saturating_sub(start);
+        let words: Vec<&str> = text.split_whitespace().collect();
+        let pieces = match max_cue_ms {
+            Some(max) if duration > max => duration.div_ceil(max).min(words.len() as u64).max(1),
+            _ => 1,
+        } as usize;
+
+        for piece in 0..pieces {
+            let first = words.len() * piece / pieces;
+            let last = words.len() * (piece + 1) / pieces;
+            cues.push((
+                start + duration * piece as u64 / pieces as u64,
+                start + duration * (piece as u64 + 1) / pieces as u64,
+                words[first..last].join(" "),
+            ));
+        }
     }
+    cues
+}
+
+/// `HH:MM:SS,mmm` for SRT, `HH:MM:SS.mmm` for WebVTT.
+fn format_cue_time(ms: u64, vtt: bool) -> String {
+    let (hours, minutes) = (ms / 3_600_000, ms / 60_000 % 60);
+    let (seconds, millis) = (ms / 1000 % 60, ms % 1000);
+    let separator = if vtt { '.' } else { ',' };
+    format!("{hours:02}:{minutes:02}:{seconds:02}{separator}{millis:03}")
+}
 
-    Ok(stdout.trim().to_string())
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SubtitleImport {
+    meeting: MeetingRecord,
+    cues: usize,
+    /// Cues dropped for a missing or unreadable timing line, or no text.
+    skipped: usize,
 }
 
+/// Create a meeting from an `.srt` or `.vtt` file: every cue becomes a timed
+/// segment and the transcript is rebuilt from them. `title` defaults to the
+/// file name. Emits `meeting-created`.
 #[tauri::command]
-fn start_enhance_stream(
+async fn import_subtitles(
     app: tauri::AppHandle,
-    meeting_id: String,
-    selection_id: String,
-    text: String,
-    model: String,
-) -> Result<(), String> {
-    let temp_dir = std::env::temp_dir().join("voxii");
-    fs::create_dir_all(&temp_dir)
-        .map_err(|err| format!("Failed to create temp dir: {err}"))?;
+    path: String,
+    title: Option<String>,
+) -> Result<SubtitleImport, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let bytes = fs::read(&path).map_err(|err| format!("Failed to read subtitles: {err}"))?;
+        let (segments, skipped) = parse_subtitle_cues(&String::from_utf8_lossy(&bytes));
+        if segments.is_empty() {
+            return Err(format!("No subtitle cues found ({skipped} malformed)"));
+        }
 
-    let id = uuid::Uuid::new_v4().to_string();
-    let input_path = temp_dir.join(format!("{id}_enhance.json"));
+        let title = title
+            .map(|title| title.trim().to_string())
+            .filter(|title| !title.is_empty())
+            .or_else(|| {
+                Path::new(&path)
+                    .file_stem()
+                    .map(|stem| stem.to_string_lossy().to_string())
+            })
+            .unwrap_or_else(|| "Untitled meeting".to_string());
+        let now = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true);
+        let meeting = MeetingRecord {
+            id: uuid::Uuid::new_v4().to_string(),
+            title,
+            notes: String::new(),
+            transcript: transcript_from_cues(&segments),
+            summary: String::new(),
+            action_items: Vec::new(),
+            created_at: now.clone(),
+            updated_at: now,
+            audio_path: None,
+            chapters: Vec::new(),
+            decisions: Vec::new(),
+            questions: Vec::new(),
+            segments,
+            tags: Vec::new(),
+            transcription_provider: None,
+            transcription_model: None,
+            transcript_history: Vec::new(),
+            summary_history: Vec::new(),
+            formatted_transcript: None,
+            formatted_source_hash: None,
+            content_hash: None,
+        };
 
-    let payload = serde_json::json!({
-        "text": text,
-        "model": model
-    });
+        let mut meetings = load_meetings_sync(&app)?;
+        meetings.insert(0, meeting);
+        write_meetings_sync(&app, &mut meetings)?;
+        let meeting = meetings.swap_remove(0);
+        let _ = app.emit("meeting-created", &meeting);
+        Ok(SubtitleImport {
+            cues: meeting.segments.len(),
+            meeting,
+            skipped,
+        })
+    })
+    .await
+    .map_err(|err| format!("Failed to import subtitles task: {err}"))?
+}
 
-    fs::write(&input_path, payload.to_string())
-        .map_err(|err| format!("Failed to write enhance payload: {err}"))?;
+/// Cues of an SRT or WebVTT file, plus how many were skipped. VTT header,
+/// `NOTE`, `STYLE` and `REGION` blocks are ignored; markup such as `<i>` is
+/// stripped and a `<v Name>` voice tag becomes a `[Name]:` label.
+fn parse_subtitle_cues(content: &str) -> (Vec<TranscriptSegment>, usize) {
+    static TAG: OnceLock<Regex> = OnceLock::new();
+    let tag = TAG.get_or_init(|| Regex::new(r"<[^>]*>").expect("valid regex"));
+    static VOICE: OnceLock<Regex> = OnceLock::new();
+    let voice = VOICE.get_or_init(|| Regex::new(r"^<v(?:\.[\w.-]+)*\s+([^>]+)>").expect("valid regex"));
+
+    let content = content.trim_start_matches('\u{feff}').replace("\r\n", "\n").replace('\r', "\n");
+    let mut segments = Vec::new();
+    let mut skipped = 0;
+    for block in content.split("\n\n") {
+        let lines: Vec<&str> = block.lines().map(str::trim).filter(|line| !line.is_empty()).collect();
+        let Some(first) = lines.first() else {
+            continue;
+        };
+        if ["WEBVTT", "NOTE", "STYLE", "REGION"]
+            .iter()
+            .any(|keyword| first.starts_with(keyword))
+        {
+            continue;
+        }
+        // SRT numbers cues and VTT may name them; either way the timing line
+        // comes first or second.
+        let Some(timing) = lines.iter().take(2).position(|line| line.contains("-->")) else {
+            skipped += 1;
+            continue;
+        };
+        let Some((start, end)) = lines[timing].split_once("-->").and_then(|(start, rest)| {
+            let end = rest.split_whitespace().next()?;
+            Some((parse_cue_time(start.trim())?, parse_cue_time(end)?))
+        }) else {
+            skipped += 1;
+            continue;
+        };
 
-    let script_path = Path::new(env!("CARGO_MANIFEST_DIR"))
-        .join("scripts")
-        .join("copilot-enhance.mjs");
+        let text = lines[timing + 1..]
+            .iter()
+            .map(|line| {
+                let labelled = voice.replace(line, "[$1]: ");
+                tag.replace_all(&labelled, "").trim().to_string()
+            })
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ");
+        if text.is_empty() || end < start {
+            skipped += 1;
+            continue;
+        }
+        segments.push(TranscriptSegment {
+            start_ms: start,
+            end_ms: end,
+            text: html_unescape(&text),
+            confidence: None,
+            language: None,
+        });
+    }
+    (segments, skipped)
+}
 
-    if !script_path.exists() {
-        return Err(format!("Enhance script not found: {}", script_path.display()));
+/// `HH:MM:SS,mmm`, `HH:MM:SS.mmm` or VTT's short `MM:SS.mmm`.
+fn parse_cue_time(value: &str) -> Option<u64> {
+    let (clock, millis) = value.split_once([',', '.'])?;
+    if millis.len() != 3 {
+        return None;
+    }
+    let millis: u64 = millis.parse().ok()?;
+    let parts: Vec<u64> = clock
+        .split(':')
+        .map(|part| part.parse().ok())
+        .collect::<Option<_>>()?;
+    let (hours, minutes, seconds) = match parts[..] {
+        [hours, minutes, seconds] => (hours, minutes, seconds),
+        [minutes, seconds] => (0, minutes, seconds),
+        _ => return None,
+    };
+    if minutes >= 60 || seconds >= 60 {
+        return None;
     }
+    Some(((hours * 60 + minutes) * 60 + seconds) * 1000 + millis)
+}
+
+/// The entities subtitle editors commonly write.
+fn html_unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&nbsp;", " ")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Cue texts joined with spaces; a new line starts whenever a cue opens with
+/// a speaker label.
+fn transcript_from_cues(segments: &[TranscriptSegment]) -> String {
+    let mut transcript = String::new();
+    for segment in segments {
+        if !transcript.is_empty() {
+            let labelled = split_speaker_label(&segment.text).0.is_some();
+            transcript.push(if labelled { '\n' } else { ' ' });
+        }
+        transcript.push_str(&segment.text);
+    }
+    transcript
+}
 
+/// Write the meeting's action items as iCalendar to-dos (`.ics`) for
+/// Reminders/Calendar apps. Items need a `YYYY-MM-DD` due date unless
+/// `include_undated` is set, in which case the rest become undated to-dos.
+#[tauri::command]
+async fn export_action_items_ics(
+    app: tauri::AppHandle,
+    meeting_id: String,
+    include_undated: Option<bool>,
+) -> Result<String, String> {
     tauri::async_runtime::spawn_blocking(move || {
-        let mut child = match Command::new("node")
-            .env("STREAMING", "1")
-            .arg(script_path)
-            .arg(&input_path)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()
-        {
-            Ok(child) => child,
-            Err(err) => {
-                let _ = app.emit(
-                    "enhance-error",
-                    format!("Failed to start Copilot SDK: {err}"),
-                );
-                return;
+        let config = load_config_sync(&app)?;
+        let meetings = load_meetings_sync(&app)?;
+        let stems = export_stem_counts(&meetings);
+        let meeting = meetings
+            .into_iter()
+            .find(|meeting| meeting.id == meeting_id)
+            .ok_or_else(|| format!("Meeting not found: {meeting_id}"))?;
+
+        let stamp = chrono::Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+        let mut lines = vec![
+            "BEGIN:VCALENDAR".to_string(),
+            "VERSION:2.0".to_string(),
+            "PRODID:-//Voxii//Action Items//EN".to_string(),
+            "CALSCALE:GREGORIAN".to_string(),
+        ];
+        let mut todos = 0;
+        for item in &meeting.action_items {
+            let due = item
+                .due_date
+                .as_deref()
+                .and_then(|due| chrono::NaiveDate::parse_from_str(due.trim(), "%Y-%m-%d").ok());
+            if due.is_none() && !include_undated.unwrap_or(false) {
+                continue;
             }
-        };
 
-        if let Some(stderr) = child.stderr.take() {
-            let app_handle = app.clone();
-            tauri::async_runtime::spawn_blocking(move || {
-                let reader = BufReader::new(stderr);
-                for line in reader.lines().flatten() {
-                    let _ = app_handle.emit("summary-log", line);
+            let mut description = vec![format!("From meeting: {}", meeting.title)];
+            if let Some(assignee) = item.assignee.as_deref().filter(|value| !value.trim().is_empty()) {
+                description.push(format!("Assignee: {assignee}"));
+            }
+            // Unparseable due dates ("next Friday") are kept as text.
+            if let (None, Some(raw)) = (due, item.due_date.as_deref()) {
+                description.push(format!("Due: {raw}"));
+            }
+            if let Some(context) = item.context.as_deref().filter(|value| !value.trim().is_empty()) {
+                description.push(context.to_string());
+            }
+
+            lines.push("BEGIN:VTODO".to_string());
+            lines.push(format!("UID:{}-{}@voxii", meeting.id, item.id));
+            lines.push(format!("DTSTAMP:{stamp}"));
+            lines.push(format!("SUMMARY:{}", ics_text(&item.task)));
+            lines.push(format!("DESCRIPTION:{}", ics_text(&description.join("\n"))));
+            if let Some(due) = due {
+                lines.push(format!("DUE;VALUE=DATE:{}", due.format("%Y%m%d")));
+            }
+            let priority = match item.priority.as_str() {
+                "high" => 1,
+                "low" => 9,
+                _ => 5,
+            };
+            lines.push(format!("PRIORITY:{priority}"));
+            let status = if item.status == "completed" { "COMPLETED" } else { "NEEDS-ACTION" };
+            lines.push(format!("STATUS:{status}"));
+            lines.push("END:VTODO".to_string());
+            todos += 1;
+        }
+        lines.push("END:VCALENDAR".to_string());
+        if todos == 0 {
+            return Err("Meeting has no action items with due dates to export".to_string());
+        }
+
+        let ics: String = lines.iter().map(|line| fold_ics_line(line) + "\r\n").collect();
+        let export_path = export_dir(&config);
+        let file_path = export_path.join(export_file_name(&meeting, &stems, "ics"));
+        fs::create_dir_all(&export_path)
+            .and_then(|_| fs::write(&file_path, ics))
+            .map_err(|err| describe_export_error(&err))?;
+        Ok(file_path.to_string_lossy().to_string())
+    })
+    .await
+    .map_err(|err| format!("Failed to export action items task: {err}"))?
+}
+
+/// Escape an iCalendar TEXT value (RFC 5545 3.3.11).
+fn ics_text(value: &str) -> String {
+    value
+        .trim()
+        .replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+}
+
+/// Fold lines longer than 75 octets, without splitting a UTF-8 character.
+fn fold_ics_line(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len() + line.len() / 74 * 3);
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > 75 {
+            folded.push_str("\r\n ");
+            // The leading space counts toward the continuation line's width.
+            width = 1;
+        }
+        folded.push(c);
+        width += c.len_utf8();
+    }
+    folded
+}
+
+/// Hugo/Jekyll-style YAML front-matter block, ending with a blank line.
+fn render_front_matter(meeting: &MeetingRecord) -> String {
+    let mut yaml = String::from("---\n");
+    yaml.push_str(&format!("title: {}\n", yaml_string(&meeting.title)));
+    yaml.push_str(&format!("date: {}\n", yaml_string(&meeting.created_at)));
+    let tags: Vec<String> = meeting
+        .tags
+        .iter()
+        .map(|tag| tag.trim())
+        .filter(|tag| !tag.is_empty())
+        .map(yaml_string)
+        .collect();
+    yaml.push_str(&format!("tags: [{}]\n", tags.join(", ")));
+    yaml.push_str(&format!("action_item_count: {}\n", meeting.action_items.len()));
+    yaml.push_str("---\n\n");
+    yaml
+}
+
+/// Double-quoted YAML scalar, safe for colons, quotes and newlines.
+fn yaml_string(value: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+fn render_meeting_markdown(
+    meeting: &MeetingRecord,
+    config: &AppConfig,
+    include_transcript: bool,
+    include_timestamps: bool,
+) -> String {
+    let mut md = String::new();
+
+    // Header
+    md.push_str(&format!("# {}\n\n", meeting.title));
+    md.push_str(&format!("**Date:** {}  \n", meeting.created_at));
+    md.push_str(&format!("**Last Updated:** {}\n\n", meeting.updated_at));
+
+    // Summary
+    if !meeting.summary.is_empty() {
+        md.push_str("---\n\n");
+        let order = config.ai.effective_summary_sections();
+        match split_summary_sections(&meeting.summary, &order) {
+            Some(sections) => {
+                for (name, body) in sections {
+                    md.push_str(&format!("## {name}\n\n{body}\n\n"));
                 }
-            });
+            }
+            None => {
+                md.push_str(&meeting.summary);
+                md.push_str("\n\n");
+            }
+        }
+    }
+
+    // Decisions
+    if !meeting.decisions.is_empty() {
+        md.push_str("## Decisions\n\n");
+        for item in &meeting.decisions {
+            md.push_str(&format!("- {}", item.decision.trim()));
+            if let Some(owner) = item.owner.as_deref().filter(|o| !o.trim().is_empty()) {
+                md.push_str(&format!(" ({})", owner.trim()));
+            }
+            md.push('\n');
+            if let Some(rationale) = item.rationale.as_deref().filter(|r| !r.trim().is_empty()) {
+                md.push_str(&format!("  - *Why:* {}\n", rationale.trim()));
+            }
+        }
+        md.push('\n');
+    }
+
+    // Action Items
+    if !meeting.action_items.is_empty() {
+        md.push_str("## Action Items\n\n");
+        for item in &meeting.action_items {
+            let checkbox = if item.status == "completed" { "[x]" } else { "[ ]" };
+            let assignee = item.assignee.as_deref().unwrap_or("Unassigned");
+            let due = item.due_date.as_deref().map(|d| format!(" (due: {})", d)).unwrap_or_default();
+            md.push_str(&format!("- {} **{}**: {}{}\n", checkbox, assignee, item.task, due));
+        }
+        md.push('\n');
+    }
+
+    // Questions
+    if !meeting.questions.is_empty() {
+        md.push_str("## Questions\n\n");
+        for item in &meeting.questions {
+            md.push_str(&format!("- {}", item.question.trim()));
+            if let Some(asked_by) = item.asked_by.as_deref().filter(|a| !a.trim().is_empty()) {
+                md.push_str(&format!(" ({})", asked_by.trim()));
+            }
+            md.push('\n');
+            match item.answer.as_deref().filter(|a| item.answered && !a.trim().is_empty()) {
+                Some(answer) => md.push_str(&format!("  - *Answer:* {}\n", answer.trim())),
+                None if item.answered => {}
+                None => md.push_str("  - *Open*\n"),
+            }
+        }
+        md.push('\n');
+    }
+
+    // Notes
+    if !meeting.notes.is_empty() {
+        md.push_str("## Notes\n\n");
+        md.push_str(&meeting.notes);
+        md.push_str("\n\n");
+    }
+
+    // Transcript (optional)
+    if include_transcript && !meeting.transcript.is_empty() {
+        md.push_str("## Transcript\n\n");
+        md.push_str("<details>\n<summary>Click to expand transcript</summary>\n\n");
+        if include_timestamps && !meeting.segments.is_empty() {
+            for segment in &meeting.segments {
+                md.push_str(&format!(
+                    "[{}] {}  \n",
+                    format_timestamp(segment.start_ms),
+                    segment.text.trim()
+                ));
+            }
+        } else {
+            md.push_str(export_transcript_text(meeting));
+        }
+        md.push_str("\n\n</details>\n\n");
+    }
+
+    // Footer
+    md.push_str("---\n*Generated by Voxii*\n");
+    md
+}
+
+/// Inline styles for HTML exports; nothing is loaded from outside the file.
+const EXPORT_HTML_STYLE: &str = "
+body { font: 15px/1.55 -apple-system, 'Segoe UI', Roboto, Helvetica, Arial, sans-serif; color: #1f2328; max-width: 760px; margin: 2rem auto; padding: 0 1.25rem; }
+h1 { font-size: 1.7rem; margin-bottom: .25rem; }
+h2 { font-size: 1.2rem; border-bottom: 1px solid #d0d7de; padding-bottom: .25rem; margin-top: 2rem; }
+.meta { color: #59636e; font-size: .9rem; margin: 0; }
+ul.actions { list-style: none; padding-left: 0; }
+ul.actions li { display: flex; gap: .5rem; align-items: baseline; margin: .3rem 0; }
+ul.actions input { accent-color: #1f883d; transform: translateY(1px); }
+ul.actions .done { text-decoration: line-through; color: #59636e; }
+.due { color: #9a6700; font-size: .85rem; }
+.why { color: #59636e; font-style: italic; }
+.notes, .transcript p { white-space: pre-wrap; }
+details { border: 1px solid #d0d7de; border-radius: 6px; padding: .5rem .75rem; }
+summary { cursor: pointer; font-weight: 600; }
+.time { color: #59636e; font-variant-numeric: tabular-nums; margin-right: .5rem; }
+footer { margin-top: 2.5rem; color: #59636e; font-size: .8rem; }
+@media print {
+  body { margin: 0; max-width: none; font-size: 11pt; }
+  details { border: none; padding: 0; }
+  summary { display: none; }
+  h2 { break-after: avoid; }
+  li, .transcript p { break-inside: avoid; }
+}
+";
+
+/// Escape text for HTML element content and attribute values.
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
         }
+    }
+    escaped
+}
 
-        let mut final_text: Option<String> = None;
+/// Escaped text with `**bold**` and `` `code` `` kept, which is all the
+/// summary prompts produce inline.
+fn markdown_inline_html(text: &str) -> String {
+    static INLINE: OnceLock<Regex> = OnceLock::new();
+    let inline = INLINE.get_or_init(|| Regex::new(r"\*\*(.+?)\*\*|`([^`]+)`").expect("valid regex"));
+    inline
+        .replace_all(&escape_html(text), |caps: &regex::Captures| match caps.get(1) {
+            Some(bold) => format!("<strong>{}</strong>", bold.as_str()),
+            None => format!("<code>{}</code>", &caps[2]),
+        })
+        .into_owned()
+}
 
-        if let Some(stdout) = child.stdout.take() {
-            let reader = BufReader::new(stdout);
-            for line in reader.lines().flatten() {
-                let trimmed = line.trim_end().to_string();
-                if trimmed.is_empty() {
-                    continue;
-                }
-                if let Ok(value) = serde_json::from_str::<serde_json::Value>(&trimmed) {
-                    if value.get("type").and_then(|v| v.as_str()) == Some("final") {
-                        if let Some(content) = value.get("content").and_then(|v| v.as_str()) {
-                            final_text = Some(content.to_string());
-                        }
-                    }
+/// Bullet lists, `###` headings and paragraphs from a summary section body.
+fn markdown_block_html(text: &str) -> String {
+    let mut html = String::new();
+    let mut in_list = false;
+    for line in text.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        let item = line.strip_prefix("- ").or_else(|| line.strip_prefix("* "));
+        if item.is_some() != in_list {
+            html.push_str(if in_list { "</ul>\n" } else { "<ul>\n" });
+            in_list = item.is_some();
+        }
+        match item {
+            Some(item) => html.push_str(&format!("<li>{}</li>\n", markdown_inline_html(item))),
+            None if line.starts_with('#') => html.push_str(&format!(
+                "<h3>{}</h3>\n",
+                markdown_inline_html(line.trim_start_matches('#').trim())
+            )),
+            None => html.push_str(&format!("<p>{}</p>\n", markdown_inline_html(line))),
+        }
+    }
+    if in_list {
+        html.push_str("</ul>\n");
+    }
+    html
+}
 
-                    let payload = serde_json::json!({
-                        "meetingId": meeting_id,
-                        "selectionId": selection_id,
-                        "event": value
-                    });
-                    let _ = app.emit("enhance-delta", payload);
-                } else {
-                    let _ = app.emit("summary-log", trimmed);
+/// Self-contained HTML page of a meeting: inline CSS with a print layout, the
+/// transcript in a collapsed `<details>` (opened for printing), and every
+/// piece of meeting text escaped.
+fn render_meeting_html(
+    meeting: &MeetingRecord,
+    config: &AppConfig,
+    include_transcript: bool,
+    include_timestamps: bool,
+) -> String {
+    let title = escape_html(&meeting.title);
+    let mut html = format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n\
+         <title>{title}</title>\n<style>{EXPORT_HTML_STYLE}</style>\n</head>\n<body>\n"
+    );
+    html.push_str(&format!("<h1>{title}</h1>\n"));
+    html.push_str(&format!(
+        "<p class=\"meta\">Date: {} &middot; Last updated: {}</p>\n",
+        escape_html(&meeting.created_at),
+        escape_html(&meeting.updated_at)
+    ));
+
+    if !meeting.summary.is_empty() {
+        let order = config.ai.effective_summary_sections();
+        match split_summary_sections(&meeting.summary, &order) {
+            Some(sections) => {
+                for (name, body) in sections {
+                    html.push_str(&format!(
+                        "<h2>{}</h2>\n{}",
+                        escape_html(&name),
+                        markdown_block_html(&body)
+                    ));
                 }
             }
+            None => {
+                html.push_str("<h2>Summary</h2>\n");
+                html.push_str(&markdown_block_html(&meeting.summary));
+            }
         }
+    }
 
-        let output = child.wait_with_output();
-        if let Ok(output) = output {
-            if !output.status.success() {
-                let stderr = String::from_utf8_lossy(&output.stderr);
-                let _ = app.emit(
-                    "enhance-error",
-                    format!("Copilot SDK failed: {stderr}"),
-                );
+    if !meeting.decisions.is_empty() {
+        html.push_str("<h2>Decisions</h2>\n<ul>\n");
+        for item in &meeting.decisions {
+            html.push_str(&format!("<li>{}", escape_html(item.decision.trim())));
+            if let Some(owner) = item.owner.as_deref().filter(|o| !o.trim().is_empty()) {
+                html.push_str(&format!(" ({})", escape_html(owner.trim())));
             }
+            if let Some(rationale) = item.rationale.as_deref().filter(|r| !r.trim().is_empty()) {
+                html.push_str(&format!(
+                    "<br><span class=\"why\">Why: {}</span>",
+                    escape_html(rationale.trim())
+                ));
+            }
+            html.push_str("</li>\n");
         }
+        html.push_str("</ul>\n");
+    }
 
-        let _ = app.emit(
-            "enhance-done",
-            serde_json::json!({
-                "meetingId": meeting_id,
-                "selectionId": selection_id,
-                "text": final_text
-            }),
-        );
-    });
+    if !meeting.action_items.is_empty() {
+        html.push_str("<h2>Action Items</h2>\n<ul class=\"actions\">\n");
+        for item in &meeting.action_items {
+            let done = item.status == "completed";
+            let assignee = item.assignee.as_deref().unwrap_or("Unassigned");
+            let due = item
+                .due_date
+                .as_deref()
+                .map(|d| format!(" <span class=\"due\">due {}</span>", escape_html(d)))
+                .unwrap_or_default();
+            html.push_str(&format!(
+                "<li><input type=\"checkbox\" disabled{}><span{}><strong>{}</strong>: {}</span>{due}</li>\n",
+                if done { " checked" } else { "" },
+                if done { " class=\"done\"" } else { "" },
+                escape_html(assignee),
+                escape_html(&item.task)
+            ));
+        }
+        html.push_str("</ul>\n");
+    }
 
-    Ok(())
-}
+    if !meeting.questions.is_empty() {
+        html.push_str("<h2>Questions</h2>\n<ul>\n");
+        for item in &meeting.questions {
+            html.push_str(&format!("<li>{}", escape_html(item.question.trim())));
+            if let Some(asked_by) = item.asked_by.as_deref().filter(|a| !a.trim().is_empty()) {
+                html.push_str(&format!(" ({})", escape_html(asked_by.trim())));
+            }
+            match item.answer.as_deref().filter(|a| item.answered && !a.trim().is_empty()) {
+                Some(answer) => html.push_str(&format!(
+                    "<br><span class=\"why\">Answer: {}</span>",
+                    escape_html(answer.trim())
+                )),
+                None if item.answered => {}
+                None => html.push_str("<br><span class=\"why\">Open</span>"),
+            }
+            html.push_str("</li>\n");
+        }
+        html.push_str("</ul>\n");
+    }
 
-#[tauri::command]
-fn clean_transcript(text: String, model: String) -> Result<String, String> {
-    let temp_dir = std::env::temp_dir().join("voxii");
-    fs::create_dir_all(&temp_dir)
-        .map_err(|err| format!("Failed to create temp dir: {err}"))?;
+    if !meeting.notes.is_empty() {
+        html.push_str(&format!(
+            "<h2>Notes</h2>\n<div class=\"notes\">{}</div>\n",
+            escape_html(meeting.notes.trim())
+        ));
+    }
 
-    let id = uuid::Uuid::new_v4().to_string();
-    let input_path = temp_dir.join(format!("{id}_clean_transcript.json"));
+    if include_transcript && !meeting.transcript.is_empty() {
+        html.push_str("<h2>Transcript</h2>\n<details class=\"transcript\">\n<summary>Show transcript</summary>\n");
+        if include_timestamps && !meeting.segments.is_empty() {
+            for segment in &meeting.segments {
+                html.push_str(&format!(
+                    "<p><span class=\"time\">{}</span>{}</p>\n",
+                    format_timestamp(segment.start_ms),
+                    escape_html(segment.text.trim())
+                ));
+            }
+        } else {
+            let transcript = export_transcript_text(meeting);
+            html.push_str(&format!("<p>{}</p>\n", escape_html(transcript.trim())));
+        }
+        html.push_str("</details>\n");
+    }
 
-    let payload = serde_json::json!({
-        "text": text,
-        "model": model
-    });
+    html.push_str("<footer>Generated by Voxii</footer>\n");
+    // Closed <details> don't print; open them for the print dialog.
+    html.push_str(
+        "<script>addEventListener(\"beforeprint\", () => document.querySelectorAll(\"details\").forEach((d) => { d.open = true; }));</script>\n",
+    );
+    html.push_str("</body>\n</html>\n");
+    html
+}
 
-    fs::write(&input_path, payload.to_string())
-        .map_err(|err| format!("Failed to write transcript payload: {err}"))?;
+fn export_dir(config: &AppConfig) -> PathBuf {
+    if config.export.local_path.is_empty() {
+        dirs::document_dir()
+            .unwrap_or_else(std::env::temp_dir)
+            .join("Voxii")
+    } else {
+        PathBuf::from(&config.export.local_path)
+    }
+}
 
-    let script_path = Path::new(env!("CARGO_MANIFEST_DIR"))
-        .join("scripts")
-        .join("copilot-clean-transcript.mjs");
+/// `<date> - <sanitized title>`
+fn export_file_stem(meeting: &MeetingRecord) -> String {
+    let safe_title: String = meeting.title
+        .chars()
+        .map(|c| if c.is_alphanumeric() || c == ' ' || c == '-' { c } else { '_' })
+        .collect();
+    format!("{} - {}",
+        meeting.created_at.split('T').next().unwrap_or("unknown"),
+        safe_title.trim()
+    )
+}
 
-    if !script_path.exists() {
-        return Err(format!(
-            "Clean transcript script not found: {}",
-            script_path.display()
-        ));
+/// How many of `meetings` share each export file stem, compared without case
+/// because the usual desktop filesystems ignore it.
+fn export_stem_counts(meetings: &[MeetingRecord]) -> HashMap<String, usize> {
+    let mut counts = HashMap::new();
+    for meeting in meetings {
+        *counts.entry(export_file_stem(meeting).to_lowercase()).or_default() += 1;
     }
+    counts
+}
 
-    let output = Command::new("node")
-        .arg(script_path)
-        .arg(&input_path)
-        .output()
-        .map_err(|err| format!("Failed to run Copilot SDK: {err}"))?;
+/// `<date> - <sanitized title>.<ext>`, with ` (<id prefix>)` before the
+/// extension when another meeting in `stems` has the same date and title.
+fn export_file_name(
+    meeting: &MeetingRecord,
+    stems: &HashMap<String, usize>,
+    extension: &str,
+) -> String {
+    let stem = export_file_stem(meeting);
+    if stems.get(&stem.to_lowercase()).copied().unwrap_or(0) < 2 {
+        return format!("{stem}.{extension}");
+    }
+    let id: String = meeting
+        .id
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .take(8)
+        .collect();
+    format!("{stem} ({id}).{extension}")
+}
 
-    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
-    let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+const EXPORT_SYNC_MANIFEST: &str = ".voxii-sync.json";
 
-    if !output.status.success() {
-        return Err(format!(
-            "Copilot SDK failed (code {}).\nstdout: {}\nstderr: {}",
-            output.status.code().unwrap_or(-1),
-            stdout,
-            stderr
-        ));
-    }
+/// Held while the sync manifest is read, updated and written back, so
+/// `sync_all_exports` and auto-export runs don't drop each other's entries.
+static EXPORT_SYNC_LOCK: Mutex<()> = Mutex::new(());
+
+/// What the last `sync_all_exports` wrote for a meeting in one format.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SyncedExport {
+    updated_at: String,
+    file: String,
+    #[serde(default)]
+    content_hash: Option<String>,
+}
 
-    Ok(stdout.trim().to_string())
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ExportSyncReport {
+    written: usize,
+    skipped: usize,
+    failed: usize,
+    errors: Vec<String>,
+    directory: String,
 }
 
+/// Mirror every meeting into the export directory, rewriting only meetings
+/// whose `updated_at` changed since the last sync.
 #[tauri::command]
-fn start_clean_transcript_stream(
+async fn sync_all_exports(
     app: tauri::AppHandle,
-    meeting_id: String,
-    text: String,
-    model: String,
-) -> Result<(), String> {
-    let temp_dir = std::env::temp_dir().join("voxii");
-    fs::create_dir_all(&temp_dir)
-        .map_err(|err| format!("Failed to create temp dir: {err}"))?;
+    format: Option<String>,
+) -> Result<ExportSyncReport, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let config = load_config_sync(&app)?;
+        let format = format.unwrap_or_else(|| config.export.default_format.clone());
+        let extension = sync_export_extension(&format)?;
+
+        let directory = export_dir(&config);
+        fs::create_dir_all(&directory).map_err(|err| describe_export_error(&err))?;
+        let meetings = load_meetings_sync(&app)?;
+        let _sync = EXPORT_SYNC_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut manifest = load_sync_manifest(&directory);
+        let mut report = ExportSyncReport {
+            written: 0,
+            skipped: 0,
+            failed: 0,
+            errors: Vec::new(),
+            directory: directory.to_string_lossy().to_string(),
+        };
 
-    let id = uuid::Uuid::new_v4().to_string();
-    let input_path = temp_dir.join(format!("{id}_clean_transcript.json"));
+        let stems = export_stem_counts(&meetings);
+        for meeting in &meetings {
+            match sync_meeting_export(meeting, &stems, &config, &directory, extension, &mut manifest) {
+                Ok(Some(_)) => report.written += 1,
+                Ok(None) => report.skipped += 1,
+                Err(err) => {
+                    report.failed += 1;
+                    report.errors.push(format!("{}: {err}", meeting.title));
+                }
+            }
+        }
 
-    let payload = serde_json::json!({
-        "text": text,
-        "model": model
-    });
+        save_sync_manifest(&directory, &manifest)?;
+        tracing::info!(
+            written = report.written,
+            skipped = report.skipped,
+            failed = report.failed,
+            "export sync finished"
+        );
+        Ok(report)
+    })
+    .await
+    .map_err(|err| format!("Failed to sync exports task: {err}"))?
+}
 
-    fs::write(&input_path, payload.to_string())
-        .map_err(|err| format!("Failed to write transcript payload: {err}"))?;
+/// File extension of a format the export dir can be mirrored in.
+fn sync_export_extension(format: &str) -> Result<&'static str, String> {
+    match format {
+        "markdown" | "md" => Ok("md"),
+        "html" => Ok("html"),
+        other => Err(format!("Unsupported export format: {other}")),
+    }
+}
 
-    let script_path = Path::new(env!("CARGO_MANIFEST_DIR"))
-        .join("scripts")
-        .join("copilot-clean-transcript.mjs");
+/// Manifest entries are per meeting and format, so markdown and HTML mirrors
+/// in the same directory don't overwrite each other's state.
+fn sync_manifest_key(meeting_id: &str, extension: &str) -> String {
+    format!("{meeting_id}:{extension}")
+}
 
-    if !script_path.exists() {
-        return Err(format!(
-            "Clean transcript script not found: {}",
-            script_path.display()
-        ));
+fn load_sync_manifest(directory: &Path) -> HashMap<String, SyncedExport> {
+    let manifest: HashMap<String, SyncedExport> =
+        fs::read_to_string(directory.join(EXPORT_SYNC_MANIFEST))
+            .ok()
+            .and_then(|raw| serde_json::from_str(&raw).ok())
+            .unwrap_or_default();
+    // Older manifests were keyed by meeting id alone; the file says which format.
+    manifest
+        .into_iter()
+        .map(|(key, synced)| {
+            if key.contains(':') {
+                return (key, synced);
+            }
+            let extension = Path::new(&synced.file)
+                .extension()
+                .map(|ext| ext.to_string_lossy().to_string())
+                .unwrap_or_default();
+            (sync_manifest_key(&key, &extension), synced)
+        })
+        .collect()
+}
+
+fn save_sync_manifest(directory: &Path, manifest: &HashMap<String, SyncedExport>) -> Result<(), String> {
+    let payload = serde_json::to_string_pretty(manifest)
+        .map_err(|err| format!("Failed to serialize export manifest: {err}"))?;
+    fs::write(directory.join(EXPORT_SYNC_MANIFEST), payload)
+        .map_err(|err| format!("Failed to save export manifest: {err}"))
+}
+
+/// Write one meeting into the mirrored export dir and record it in
+/// `manifest`. Returns the file written, or `None` when the manifest shows the
+/// meeting's content unchanged and its file still there.
+fn sync_meeting_export(
+    meeting: &MeetingRecord,
+    stems: &HashMap<String, usize>,
+    config: &AppConfig,
+    directory: &Path,
+    extension: &str,
+    manifest: &mut HashMap<String, SyncedExport>,
+) -> Result<Option<PathBuf>, String> {
+    let file = export_file_name(meeting, stems, extension);
+    let key = sync_manifest_key(&meeting.id, extension);
+    let hash = content_hash(meeting);
+    // Entries from before content hashes fall back to the timestamp.
+    let unchanged = manifest.get(&key).is_some_and(|synced| {
+        synced
+            .content_hash
+            .as_ref()
+            .map_or(synced.updated_at == meeting.updated_at, |synced| *synced == hash)
+            && synced.file == file
+            && directory.join(&file).is_file()
+    });
+    if unchanged {
+        return Ok(None);
     }
 
-    tauri::async_runtime::spawn_blocking(move || {
-        let mut child = match Command::new("node")
-            .env("STREAMING", "1")
-            .arg(script_path)
-            .arg(&input_path)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()
-        {
-            Ok(child) => child,
-            Err(err) => {
-                let _ = app.emit(
-                    "clean-transcript-error",
-                    format!("Failed to start Copilot SDK: {err}"),
-                );
-                return;
-            }
+    let content = if extension == "html" {
+        render_meeting_html(meeting, config, true, false)
+    } else {
+        let mut md = render_meeting_markdown(meeting, config, true, false);
+        if config.export.frontmatter {
+            md.insert_str(0, &render_front_matter(meeting));
+        }
+        md
+    };
+    let path = directory.join(&file);
+    fs::write(&path, content).map_err(|err| describe_export_error(&err))?;
+    // A renamed meeting would otherwise leave its old file behind, unless
+    // another meeting has taken that name over since. Names are compared
+    // without case, as most desktop filesystems do.
+    if let Some(previous) = manifest.get(&key) {
+        let same_file = |name: &str| name.to_lowercase() == previous.file.to_lowercase();
+        let claimed = manifest
+            .iter()
+            .any(|(other, synced)| *other != key && same_file(&synced.file));
+        if !same_file(&file) && !claimed {
+            let _ = fs::remove_file(directory.join(&previous.file));
+        }
+    }
+    manifest.insert(
+        key,
+        SyncedExport {
+            updated_at: meeting.updated_at.clone(),
+            file,
+            content_hash: Some(hash),
+        },
+    );
+    Ok(Some(path))
+}
+
+/// Bumped for every queued auto-export; a run that gets the sync lock after
+/// a newer one was queued skips, since its meetings list is stale.
+static AUTO_EXPORT_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// With `export.autoExport` on, mirror changed meetings in the background
+/// after a save. Each file written emits `auto-export-done`; failures emit
+/// `auto-export-error` and never fail the save.
+fn queue_auto_export(app: &tauri::AppHandle, meetings: Vec<MeetingRecord>) {
+    let Ok(config) = load_config_sync(app) else {
+        return;
+    };
+    if !config.export.auto_export {
+        return;
+    }
+    let generation = AUTO_EXPORT_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    let app = app.clone();
+    std::thread::spawn(move || {
+        let report_error = |meeting_id: Option<&str>, error: String| {
+            tracing::warn!("auto-export failed: {error}");
+            let _ = app.emit(
+                "auto-export-error",
+                serde_json::json!({ "meetingId": meeting_id, "error": error }),
+            );
+        };
+        let extension = match sync_export_extension(&config.export.auto_export_format) {
+            Ok(extension) => extension,
+            Err(err) => return report_error(None, err),
         };
+        let directory = export_dir(&config);
+        if let Err(err) = fs::create_dir_all(&directory) {
+            return report_error(None, describe_export_error(&err));
+        }
 
-        if let Some(stderr) = child.stderr.take() {
-            let app_handle = app.clone();
-            tauri::async_runtime::spawn_blocking(move || {
-                let reader = BufReader::new(stderr);
-                for line in reader.lines().flatten() {
-                    let _ = app_handle.emit("summary-log", line);
+        let _sync = EXPORT_SYNC_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+        if AUTO_EXPORT_GENERATION.load(Ordering::SeqCst) != generation {
+            return;
+        }
+        let mut manifest = load_sync_manifest(&directory);
+        let mut changed = false;
+        let stems = export_stem_counts(&meetings);
+        for meeting in &meetings {
+            match sync_meeting_export(meeting, &stems, &config, &directory, extension, &mut manifest) {
+                Ok(Some(path)) => {
+                    changed = true;
+                    let _ = app.emit(
+                        "auto-export-done",
+                        serde_json::json!({
+                            "meetingId": meeting.id,
+                            "path": path.to_string_lossy(),
+                        }),
+                    );
                 }
-            });
+                Ok(None) => {}
+                Err(err) => report_error(Some(&meeting.id), err),
+            }
+        }
+        if changed {
+            if let Err(err) = save_sync_manifest(&directory, &manifest) {
+                report_error(None, err);
+            }
         }
+    });
+}
 
-        let mut final_text: Option<String> = None;
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct MarkdownExport {
+    /// Where the file was written; `None` when writing failed.
+    path: Option<String>,
+    content: String,
+    error: Option<String>,
+}
 
-        if let Some(stdout) = child.stdout.take() {
-            let reader = BufReader::new(stdout);
-            for line in reader.lines().flatten() {
-                let trimmed = line.trim_end().to_string();
-                if trimmed.is_empty() {
-                    continue;
-                }
-                if let Ok(value) = serde_json::from_str::<serde_json::Value>(&trimmed) {
-                    if value.get("type").and_then(|v| v.as_str()) == Some("final") {
-                        if let Some(content) = value.get("content").and_then(|v| v.as_str()) {
-                            final_text = Some(content.to_string());
-                        }
-                    }
+/// `mm:ss`, or `h:mm:ss` past the hour.
+fn format_timestamp(ms: u64) -> String {
+    let seconds = ms / 1000;
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{minutes:02}:{seconds:02}")
+    }
+}
 
-                    let payload = serde_json::json!({
-                        "meetingId": meeting_id,
-                        "event": value
-                    });
-                    let _ = app.emit("clean-transcript-delta", payload);
-                } else {
-                    let _ = app.emit("summary-log", trimmed);
-                }
-            }
+fn describe_export_error(err: &std::io::Error) -> String {
+    match err.kind() {
+        std::io::ErrorKind::PermissionDenied | std::io::ErrorKind::ReadOnlyFilesystem => {
+            "Export folder is not writable; choose a different location in Settings".to_string()
         }
+        std::io::ErrorKind::StorageFull | std::io::ErrorKind::QuotaExceeded => {
+            "Not enough disk space to export; free up space or choose a different location in Settings"
+                .to_string()
+        }
+        _ => format!("Failed to write export file: {err}"),
+    }
+}
 
-        let output = child.wait_with_output();
-        if let Ok(output) = output {
-            if !output.status.success() {
-                let stderr = String::from_utf8_lossy(&output.stderr);
-                let _ = app.emit(
-                    "clean-transcript-error",
-                    format!("Copilot SDK failed: {stderr}"),
-                );
-            }
+/// Split a markdown summary on headings (`#`-style or a bold-only line) that
+/// name a configured section, returning the sections in `order`. Returns `None`
+/// when the summary isn't structured that way so callers can keep the blob.
+fn split_summary_sections(summary: &str, order: &[String]) -> Option<Vec<(String, String)>> {
+    let section_for = |line: &str| {
+        let trimmed = line.trim();
+        let title = if trimmed.starts_with('#') {
+            trimmed.trim_start_matches('#')
+        } else if trimmed.len() > 4 && trimmed.starts_with("**") && trimmed.ends_with("**") {
+            &trimmed[2..trimmed.len() - 2]
+        } else {
+            return None;
+        };
+        let title = title.trim().trim_end_matches(':').trim();
+        order.iter().find(|name| name.eq_ignore_ascii_case(title))
+    };
+
+    let mut bodies: HashMap<&String, Vec<&str>> = HashMap::new();
+    let mut current: Option<&String> = None;
+    for line in summary.lines() {
+        if let Some(name) = section_for(line) {
+            current = Some(name);
+            bodies.entry(name).or_default();
+            continue;
+        }
+        match current {
+            Some(name) => bodies.entry(name).or_default().push(line),
+            // Preamble before the first known section means it's not structured.
+            None if !line.trim().is_empty() => return None,
+            None => {}
+        }
+    }
+    if bodies.is_empty() {
+        return None;
+    }
+
+    Some(
+        order
+            .iter()
+            .filter_map(|name| {
+                let body = bodies.get(name)?.join("\n").trim().to_string();
+                (!body.is_empty()).then(|| (name.clone(), body))
+            })
+            .collect(),
+    )
+}
+
+fn load_config_sync(app: &tauri::AppHandle) -> Result<AppConfig, String> {
+    let path = config_path(app)?;
+    if !path.exists() {
+        return Ok(AppConfig::default());
+    }
+    let raw = fs::read_to_string(&path)
+        .map_err(|err| format!("Failed to read config: {err}"))?;
+    let config = serde_json::from_str::<AppConfig>(&raw)
+        .map_err(|err| format!("Failed to parse config: {err}"))?;
+    Ok(config)
+}
+
+fn load_meetings_sync(app: &tauri::AppHandle) -> Result<Vec<MeetingRecord>, String> {
+    // Backend edits start from here; land a held save first so they build on it.
+    flush_pending_meetings(app)?;
+    let path = meetings_path(app)?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let raw = fs::read_to_string(&path)
+        .map_err(|err| format!("Failed to read meetings: {err}"))?;
+    serde_json::from_str::<Vec<MeetingRecord>>(&raw)
+        .map_err(|err| format!("Failed to parse meetings: {err}"))
+}
+
+/// Persist meetings, skipping the write when the payload matches what we last
+/// wrote and the file hasn't been touched since. Returns whether it wrote.
+/// Refreshes each record's `content_hash` first.
+fn write_meetings_sync(app: &tauri::AppHandle, meetings: &mut [MeetingRecord]) -> Result<bool, String> {
+    for meeting in meetings.iter_mut() {
+        meeting.content_hash = Some(content_hash(meeting));
+    }
+    let path = meetings_path(app)?;
+    let payload = serde_json::to_string_pretty(meetings)
+        .map_err(|err| format!("Failed to serialize meetings: {err}"))?;
+
+    let mut hasher = DefaultHasher::new();
+    payload.hash(&mut hasher);
+    let hash = hasher.finish();
+
+    let state = app.state::<AppState>();
+    let mut last_write = state
+        .last_meetings_write
+        .lock()
+        .map_err(|_| "Failed to acquire meetings lock")?;
+    let modified = || fs::metadata(&path).and_then(|meta| meta.modified()).ok();
+    if let Some(previous) = last_write.as_ref() {
+        if previous.hash == hash && path.exists() && previous.modified == modified() {
+            return Ok(false);
         }
+    }
 
-        let _ = app.emit(
-            "clean-transcript-done",
-            serde_json::json!({
-                "meetingId": meeting_id,
-                "text": final_text
-            }),
-        );
+    // Write then rename so a crash mid-write can't truncate meetings.json.
+    let temp_path = path.with_extension("json.tmp");
+    fs::write(&temp_path, payload).map_err(|err| format!("Failed to save meetings: {err}"))?;
+    fs::rename(&temp_path, &path).map_err(|err| format!("Failed to save meetings: {err}"))?;
+    *last_write = Some(MeetingsWrite {
+        hash,
+        modified: modified(),
     });
+    Ok(true)
+}
 
-    Ok(())
+/// Stable FNV-1a hash of what a meeting says: title, notes, transcript,
+/// summary, action items, decisions, tags and questions, trimmed and with
+/// `\r\n` normalized. Timestamps and derived data (segments, chapters, history) are
+/// left out so re-saving unchanged content keeps the hash. Written out by
+/// hand because std's hashers aren't guaranteed stable across releases.
+/// FNV-1a of a transcript, ignoring surrounding whitespace and line endings.
+fn transcript_hash(transcript: &str) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in transcript.trim().replace("\r\n", "\n").bytes() {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    format!("{hash:016x}")
 }
 
-#[tauri::command]
-async fn load_config(app: tauri::AppHandle) -> Result<AppConfig, String> {
-    tauri::async_runtime::spawn_blocking(move || {
-        let path = config_path(&app)?;
-        if !path.exists() {
-            let default_config = AppConfig::default();
-            save_config(&path, &default_config)?;
-            return Ok(default_config);
+/// The stored paragraphed transcript while it was made from the current
+/// `transcript`; an edited or replaced transcript is used as is.
+fn export_transcript_text(meeting: &MeetingRecord) -> &str {
+    match (&meeting.formatted_transcript, &meeting.formatted_source_hash) {
+        (Some(formatted), Some(source)) if *source == transcript_hash(&meeting.transcript) => {
+            formatted
         }
+        _ => &meeting.transcript,
+    }
+}
 
-        let raw = fs::read_to_string(&path)
-            .map_err(|err| format!("Failed to read config: {err}"))?;
-        let mut config = serde_json::from_str::<AppConfig>(&raw)
-            .map_err(|err| format!("Failed to parse config: {err}"))?;
-        
-        // Migrate from v1 if needed
-        if config.version < 2 {
-            config.migrate_from_v1();
-            // Save migrated config
-            let _ = save_config(&path, &config);
+fn content_hash(meeting: &MeetingRecord) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    let mut field = |value: &str| {
+        let value = value.trim().replace("\r\n", "\n");
+        // 0x1f (unit separator) can't appear in the text, so fields can't run together.
+        for byte in value.bytes().chain([0x1f]) {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
         }
-        
-        Ok(config)
-    })
-    .await
-    .map_err(|err| format!("Failed to load config task: {err}"))?
+    };
+
+    field(&meeting.title);
+    field(&meeting.notes);
+    field(&meeting.transcript);
+    field(&meeting.summary);
+    for item in &meeting.action_items {
+        field(&item.task);
+        field(item.assignee.as_deref().unwrap_or_default());
+        field(item.due_date.as_deref().unwrap_or_default());
+        field(&item.priority);
+        field(&item.status);
+        field(item.context.as_deref().unwrap_or_default());
+    }
+    field("\u{1e}");
+    for decision in &meeting.decisions {
+        field(&decision.decision);
+        field(decision.rationale.as_deref().unwrap_or_default());
+        field(decision.owner.as_deref().unwrap_or_default());
+    }
+    field("\u{1e}");
+    for tag in &meeting.tags {
+        field(tag);
+    }
+    // Only hashed when present, so meetings from before questions keep their hash.
+    if !meeting.questions.is_empty() {
+        field("\u{1e}");
+        for question in &meeting.questions {
+            field(&question.question);
+            field(question.asked_by.as_deref().unwrap_or_default());
+            field(if question.answered { "answered" } else { "open" });
+            field(question.answer.as_deref().unwrap_or_default());
+        }
+    }
+    format!("{hash:016x}")
 }
 
-#[tauri::command]
-async fn save_config_command(
-    app: tauri::AppHandle,
-    config: AppConfig,
-) -> Result<(), String> {
-    tauri::async_runtime::spawn_blocking(move || {
-        let path = config_path(&app)?;
-        save_config(&path, &config)
-    })
-    .await
-    .map_err(|err| format!("Failed to save config task: {err}"))?
+// ============================================================================
+// Local HTTP Server
+// ============================================================================
+
+const HTTP_ACCEPT_POLL: Duration = Duration::from_millis(100);
+const HTTP_READ_TIMEOUT: Duration = Duration::from_secs(30);
+const MAX_HTTP_HEAD_BYTES: usize = 16 * 1024;
+
+struct HttpServer {
+    address: SocketAddr,
+    shutdown: Arc<AtomicBool>,
+}
+
+/// An HTTP error response: status code and message.
+type HttpError = (u16, String);
+
+struct HttpRequest {
+    method: String,
+    path: String,
+    /// Header names are lowercased.
+    headers: HashMap<String, String>,
+}
+
+struct MultipartPart<'a> {
+    name: String,
+    filename: Option<String>,
+    data: &'a [u8],
 }
 
+#[derive(Deserialize)]
+struct HttpSummarizeRequest {
+    transcript: String,
+    #[serde(default)]
+    notes: String,
+    model: Option<String>,
+}
+
+/// Start the localhost API described by `server` in the config. Returns the
+/// base URL; calling it while the server runs returns the running address.
 #[tauri::command]
-async fn load_meetings(app: tauri::AppHandle) -> Result<Vec<MeetingRecord>, String> {
-    tauri::async_runtime::spawn_blocking(move || {
-        let path = meetings_path(&app)?;
-        if !path.exists() {
-            return Ok(Vec::new());
-        }
-        let raw = fs::read_to_string(&path)
-            .map_err(|err| format!("Failed to read meetings: {err}"))?;
-        let meetings = serde_json::from_str::<Vec<MeetingRecord>>(&raw)
-            .map_err(|err| format!("Failed to parse meetings: {err}"))?;
-        Ok(meetings)
-    })
-    .await
-    .map_err(|err| format!("Failed to load meetings task: {err}"))?
+async fn start_http_server(app: tauri::AppHandle) -> Result<String, String> {
+    let config = load_config(app.clone()).await?;
+    start_http_server_sync(&app, &config)
 }
 
+/// Stop the localhost API; false when it wasn't running. Requests already
+/// being handled run to completion.
 #[tauri::command]
-async fn save_meetings(
-    app: tauri::AppHandle,
-    meetings: Vec<MeetingRecord>,
-) -> Result<(), String> {
-    tauri::async_runtime::spawn_blocking(move || {
-        let path = meetings_path(&app)?;
-        let payload = serde_json::to_string_pretty(&meetings)
-            .map_err(|err| format!("Failed to serialize meetings: {err}"))?;
-        fs::write(path, payload)
-            .map_err(|err| format!("Failed to save meetings: {err}"))?;
-        Ok(())
-    })
-    .await
-    .map_err(|err| format!("Failed to save meetings task: {err}"))?
+async fn stop_http_server(state: State<'_, AppState>) -> Result<bool, String> {
+    let mut running = state
+        .http_server
+        .lock()
+        .map_err(|_| "Failed to acquire lock")?;
+    let Some(server) = running.take() else {
+        return Ok(false);
+    };
+    server.shutdown.store(true, Ordering::SeqCst);
+    tracing::info!("HTTP server on {} stopping", server.address);
+    Ok(true)
 }
 
-fn resolve_whisper_path(input: &str) -> Result<PathBuf, String> {
-    if input.is_empty() {
-        return Err("Whisper path not configured".to_string());
+fn start_http_server_sync(app: &tauri::AppHandle, config: &AppConfig) -> Result<String, String> {
+    let server = &config.server;
+    if !server.enabled {
+        return Err("HTTP server is disabled; set server.enabled in the config".to_string());
     }
-    
-    let path = Path::new(input);
-    if path.is_file() {
-        return Ok(path.to_path_buf());
+    let token = server.token.trim().to_string();
+    if token.is_empty() {
+        return Err("server.token must be set before starting the HTTP server".to_string());
     }
-    if path.is_dir() {
-        // Cross-platform binary candidates
-        #[cfg(target_os = "windows")]
-        let candidates = [
-            "whisper-cli.exe",
-            "main.exe",
-            "whisper.exe",
-        ];
-        #[cfg(target_os = "macos")]
-        let candidates = [
-            "whisper-cli",
-            "whisper",
-            "main",
-        ];
-        #[cfg(target_os = "linux")]
-        let candidates = [
-            "whisper-cli",
-            "whisper",
-            "main",
-        ];
-        #[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
-        let candidates = [
-            "whisper-cli",
-            "whisper",
-            "main",
-        ];
-        
-        for name in candidates {
-            let candidate = path.join(name);
-            if candidate.is_file() {
-                return Ok(candidate);
+
+    let state = app.state::<AppState>();
+    let mut running = state
+        .http_server
+        .lock()
+        .map_err(|_| "Failed to acquire lock")?;
+    if let Some(existing) = running.as_ref() {
+        return Ok(format!("http://{}", existing.address));
+    }
+
+    // Localhost only: the API runs whisper and AI scripts on request.
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, server.port))
+        .map_err(|err| format!("Failed to bind HTTP server to port {}: {err}", server.port))?;
+    listener
+        .set_nonblocking(true)
+        .map_err(|err| format!("Failed to configure HTTP server: {err}"))?;
+    let address = listener
+        .local_addr()
+        .map_err(|err| format!("Failed to read HTTP server address: {err}"))?;
+
+    let shutdown = Arc::new(AtomicBool::new(false));
+    let handle = app.clone();
+    let stop = shutdown.clone();
+    std::thread::spawn(move || serve_http(handle, listener, token, stop));
+    tracing::info!("HTTP server listening on {address}");
+    *running = Some(HttpServer { address, shutdown });
+    Ok(format!("http://{address}"))
+}
+
+fn serve_http(app: tauri::AppHandle, listener: TcpListener, token: String, shutdown: Arc<AtomicBool>) {
+    let token = Arc::new(token);
+    while !shutdown.load(Ordering::SeqCst) {
+        match listener.accept() {
+            Ok((stream, _)) => {
+                let app = app.clone();
+                let token = token.clone();
+                std::thread::spawn(move || handle_http_connection(&app, stream, &token));
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::WouldBlock => {
+                std::thread::sleep(HTTP_ACCEPT_POLL)
+            }
+            Err(err) => {
+                tracing::warn!("HTTP accept failed: {err}");
+                std::thread::sleep(HTTP_ACCEPT_POLL);
             }
         }
     }
-    Err(format!(
-        "Whisper binary not found. Provide the whisper binary path or folder containing it. Got: {}",
-        input
-    ))
+    tracing::info!("HTTP server stopped");
 }
 
-fn resolve_model_path_with_selection(
-    base_path: &str,
-    selection: &str,
-) -> Result<PathBuf, String> {
-    let base_trimmed = base_path.trim();
-    if base_trimmed.is_empty() {
-        return Err("Model path not configured".to_string());
-    }
+fn handle_http_connection(app: &tauri::AppHandle, mut stream: TcpStream, token: &str) {
+    // Accepted sockets inherit non-blocking mode on some platforms.
+    let _ = stream.set_nonblocking(false);
+    let _ = stream.set_read_timeout(Some(HTTP_READ_TIMEOUT));
 
-    let selection_trimmed = selection.trim();
-    if !selection_trimmed.is_empty() {
-        let selection_path = Path::new(selection_trimmed);
-        if selection_path.is_file() {
-            return Ok(selection_path.to_path_buf());
+    let (status, body) = match serve_http_request(app, &mut stream, token) {
+        Ok(body) => (200, body),
+        Err((status, error)) => {
+            tracing::warn!(status, "HTTP request failed: {error}");
+            (status, serde_json::json!({ "error": error }))
         }
+    };
+    if let Err(err) = write_http_response(&mut stream, status, &body) {
+        tracing::warn!("Failed to write HTTP response: {err}");
     }
+}
 
-    let base = Path::new(base_trimmed);
-    if !selection_trimmed.is_empty() && base.is_dir() {
-        let candidate = base.join(selection_trimmed);
-        if candidate.is_file() {
-            return Ok(candidate);
+fn serve_http_request(
+    app: &tauri::AppHandle,
+    stream: &mut TcpStream,
+    token: &str,
+) -> Result<serde_json::Value, HttpError> {
+    let mut reader = BufReader::new(stream);
+    let request = read_http_head(&mut reader)?;
+
+    let authorized = request
+        .headers
+        .get("authorization")
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .is_some_and(|given| tokens_match(given.trim(), token));
+    if !authorized {
+        return Err((401, "Missing or invalid bearer token".to_string()));
+    }
+    if !matches!(request.path.as_str(), "/transcribe" | "/summarize") {
+        return Err((404, format!("Unknown endpoint: {}", request.path)));
+    }
+    if request.method != "POST" {
+        return Err((405, format!("{} only accepts POST", request.path)));
+    }
+
+    let config = load_config_sync(app).map_err(|err| (500, err))?;
+    let length = request
+        .headers
+        .get("content-length")
+        .and_then(|value| value.parse::<u64>().ok())
+        .ok_or((411, "Content-Length is required".to_string()))?;
+    let limit = config
+        .transcription
+        .max_audio_bytes
+        .unwrap_or(DEFAULT_MAX_AUDIO_BYTES);
+    if limit > 0 && length > limit {
+        return Err((413, format!("Request body exceeds {} MB", limit / 1_000_000)));
+    }
+    if request
+        .headers
+        .get("expect")
+        .is_some_and(|value| value.eq_ignore_ascii_case("100-continue"))
+    {
+        reader
+            .get_mut()
+            .write_all(b"HTTP/1.1 100 Continue\r\n\r\n")
+            .map_err(|err| (400, format!("Failed to write response: {err}")))?;
+    }
+    let mut body = Vec::new();
+    (&mut reader)
+        .take(length)
+        .read_to_end(&mut body)
+        .map_err(|err| (400, format!("Failed to read request body: {err}")))?;
+    if body.len() as u64 != length {
+        return Err((400, "Request body ended early".to_string()));
+    }
+
+    match request.path.as_str() {
+        "/transcribe" => http_transcribe(app, &config, &request, &body),
+        _ => http_summarize(app, &config, &body),
+    }
+}
+
+/// `POST /transcribe`: multipart form with the recording in a `file` (or
+/// `audio`) part and optional `language` and `provider` fields. Responds with
+/// the same JSON as `transcribe_audio`.
+fn http_transcribe(
+    app: &tauri::AppHandle,
+    config: &AppConfig,
+    request: &HttpRequest,
+    body: &[u8],
+) -> Result<serde_json::Value, HttpError> {
+    let content_type = request
+        .headers
+        .get("content-type")
+        .map(String::as_str)
+        .unwrap_or_default();
+    let boundary = content_type
+        .strip_prefix("multipart/form-data")
+        .and_then(|params| {
+            params
+                .split(';')
+                .find_map(|param| param.trim().strip_prefix("boundary="))
+        })
+        .map(|boundary| boundary.trim_matches('"'))
+        .filter(|boundary| !boundary.is_empty())
+        .ok_or((415, "Expected a multipart/form-data body".to_string()))?;
+    let parts = parse_multipart(body, boundary).map_err(|err| (400, err))?;
+
+    let field = |name: &str| {
+        parts
+            .iter()
+            .find(|part| part.name == name && part.filename.is_none())
+            .map(|part| String::from_utf8_lossy(part.data).trim().to_string())
+            .filter(|value| !value.is_empty())
+    };
+    let provider = field("provider");
+    check_provider_configured(app, config, provider.as_deref()).map_err(|err| (400, err))?;
+    let audio = parts
+        .iter()
+        .find(|part| matches!(part.name.as_str(), "file" | "audio"))
+        .or_else(|| parts.iter().find(|part| part.filename.is_some()))
+        .filter(|part| !part.data.is_empty())
+        .ok_or((400, "Missing audio part (form field \"file\")".to_string()))?;
+    let bytes = audio.data.to_vec();
+    let audio = AudioInput {
+        format: AudioFormat::sniff(&bytes),
+        bytes,
+    };
+
+    let start = Instant::now();
+    let result = tauri::async_runtime::block_on(finish_transcription(
+        app,
+        config,
+        audio,
+        field("language"),
+        provider.as_deref(),
+    ));
+    report_transcription(app, start, "http transcribe", &result);
+    let response = result.map_err(|err| (500, err))?;
+    serde_json::to_value(response)
+        .map_err(|err| (500, format!("Failed to serialize transcription: {err}")))
+}
+
+/// `POST /summarize`: JSON `{ transcript, notes?, model? }`, answered with
+/// `{ summary }`. The model defaults to `ai.defaultModel`.
+fn http_summarize(
+    app: &tauri::AppHandle,
+    config: &AppConfig,
+    body: &[u8],
+) -> Result<serde_json::Value, HttpError> {
+    let request: HttpSummarizeRequest = serde_json::from_slice(body)
+        .map_err(|err| (400, format!("Invalid summarize request: {err}")))?;
+    if request.transcript.trim().is_empty() {
+        return Err((400, "transcript is empty".to_string()));
+    }
+    let model = request
+        .model
+        .filter(|model| !model.trim().is_empty())
+        .unwrap_or_else(|| config.ai.default_model.clone());
+    let summary = generate_summary_sync(app.clone(), request.transcript, request.notes, Some(model))
+        .map_err(|err| (500, err))?;
+    Ok(serde_json::json!({ "summary": summary }))
+}
+
+fn read_http_head(reader: &mut impl BufRead) -> Result<HttpRequest, HttpError> {
+    let mut lines = Vec::new();
+    let mut remaining = MAX_HTTP_HEAD_BYTES;
+    loop {
+        let mut line = String::new();
+        let read = reader
+            .take(remaining as u64)
+            .read_line(&mut line)
+            .map_err(|err| (400, format!("Failed to read request: {err}")))?;
+        if read == 0 || !line.ends_with('\n') {
+            return Err((400, "Malformed or oversized request head".to_string()));
         }
-        return Err(format!(
-            "Selected model not found: {}",
-            candidate.display()
-        ));
+        remaining -= read;
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        lines.push(line.to_string());
     }
 
-    resolve_model_path(base_trimmed)
+    let mut request_line = lines.first().map(String::as_str).unwrap_or_default().split_whitespace();
+    let (Some(method), Some(target)) = (request_line.next(), request_line.next()) else {
+        return Err((400, "Malformed request line".to_string()));
+    };
+    let path = target.split('?').next().unwrap_or(target);
+    let headers = lines[1..]
+        .iter()
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, value)| (name.trim().to_ascii_lowercase(), value.trim().to_string()))
+        .collect();
+    Ok(HttpRequest {
+        method: method.to_string(),
+        path: path.to_string(),
+        headers,
+    })
 }
 
-fn resolve_model_path(input: &str) -> Result<PathBuf, String> {
-    let path = Path::new(input);
-    if path.is_file() {
-        return Ok(path.to_path_buf());
+fn parse_multipart<'a>(body: &'a [u8], boundary: &str) -> Result<Vec<MultipartPart<'a>>, String> {
+    let opening = format!("--{boundary}");
+    let delimiter = format!("\r\n--{boundary}");
+    let start = find_bytes(body, opening.as_bytes())
+        .ok_or("Multipart boundary not found in body")?;
+    let mut rest = &body[start + opening.len()..];
+    let mut parts = Vec::new();
+
+    // Each delimiter is followed by CRLF and a part, or by `--` at the end.
+    while !rest.starts_with(b"--") {
+        rest = rest
+            .strip_prefix(b"\r\n")
+            .ok_or("Malformed multipart delimiter")?;
+        let header_end = find_bytes(rest, b"\r\n\r\n").ok_or("Malformed multipart part headers")?;
+        let headers = String::from_utf8_lossy(&rest[..header_end]);
+        let content = &rest[header_end + 4..];
+        let end = find_bytes(content, delimiter.as_bytes()).ok_or("Unterminated multipart body")?;
+
+        let disposition = headers
+            .lines()
+            .find_map(|line| {
+                let (name, value) = line.split_once(':')?;
+                name.trim()
+                    .eq_ignore_ascii_case("content-disposition")
+                    .then_some(value)
+            })
+            .unwrap_or_default();
+        let param = |key: &str| {
+            disposition
+                .split(';')
+                .find_map(|param| param.trim().strip_prefix(key)?.strip_prefix('='))
+                .map(|value| value.trim_matches('"').to_string())
+        };
+        parts.push(MultipartPart {
+            name: param("name").unwrap_or_default(),
+            filename: param("filename"),
+            data: &content[..end],
+        });
+        rest = &content[end + delimiter.len()..];
     }
-    // When given a directory, prefer a sensible default model if present.
-    // This keeps startup simple (point to the models folder) while allowing
-    // better tradeoffs than always picking the largest file.
-    let preferred_names = [
-        "ggml-medium.en-q8_0.bin",
-        "ggml-medium.en.bin",
-        "ggml-medium.en-q5_0.bin",
-        "ggml-medium-q8_0.bin",
-        "ggml-medium.bin",
-        "ggml-small.en-q8_0.bin",
-        "ggml-small.en.bin",
-        "ggml-base.en.bin",
-    ];
+    Ok(parts)
+}
 
-    let search_dirs = if path.is_dir() {
-        vec![path.to_path_buf(), path.join("models")]
-    } else {
-        vec![]
+fn find_bytes(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|window| window == needle)
+}
+
+/// Compare without short-circuiting so response timing doesn't leak the token.
+fn tokens_match(given: &str, expected: &str) -> bool {
+    given.len() == expected.len()
+        && given
+            .bytes()
+            .zip(expected.bytes())
+            .fold(0, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+fn write_http_response(
+    stream: &mut TcpStream,
+    status: u16,
+    body: &serde_json::Value,
+) -> std::io::Result<()> {
+    let reason = match status {
+        200 => "OK",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        411 => "Length Required",
+        413 => "Payload Too Large",
+        415 => "Unsupported Media Type",
+        _ => "Internal Server Error",
     };
+    let body = body.to_string();
+    write!(
+        stream,
+        "HTTP/1.1 {status} {reason}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )?;
+    stream.flush()
+}
 
-    let mut candidates: Vec<(PathBuf, u64, String)> = Vec::new();
-    for dir in search_dirs {
-        if let Ok(entries) = fs::read_dir(&dir) {
-            for entry in entries.flatten() {
-                let p = entry.path();
-                if !p.extension().map(|e| e == "bin").unwrap_or(false) {
-                    continue;
-                }
-                let size = match p.metadata() {
-                    Ok(meta) => meta.len(),
-                    Err(_) => continue,
-                };
-                let file_name = p
-                    .file_name()
-                    .and_then(|n| n.to_str())
-                    .unwrap_or("")
-                    .to_ascii_lowercase();
-                candidates.push((p, size, file_name));
-            }
-        }
+// ============================================================================
+// Logging
+// ============================================================================
+
+struct LogHandle {
+    dir: PathBuf,
+    level: reload::Handle<LevelFilter, Registry>,
+    _guard: tracing_appender::non_blocking::WorkerGuard,
+}
+
+const LOG_FILE_PREFIX: &str = "voxii";
+const LOG_FILES_KEPT: usize = 7;
+
+/// Route `tracing` output to a daily-rotated file in the app log dir.
+fn init_logging(app: &tauri::AppHandle) -> Result<(), String> {
+    let dir = match app.path().app_log_dir() {
+        Ok(dir) => dir,
+        // Portable setups keep logs with the rest of the data.
+        Err(_) => voxii_data_dir(app)?.join("logs"),
+    };
+    fs::create_dir_all(&dir).map_err(|err| format!("Failed to create log dir: {err}"))?;
+
+    let configured = load_config_sync(app)
+        .map(|config| config.logging.level)
+        .unwrap_or_else(|_| default_log_level());
+    let level = parse_log_level(&configured).unwrap_or(LevelFilter::INFO);
+
+    let appender = tracing_appender::rolling::Builder::new()
+        .rotation(tracing_appender::rolling::Rotation::DAILY)
+        .filename_prefix(LOG_FILE_PREFIX)
+        .filename_suffix("log")
+        .max_log_files(LOG_FILES_KEPT)
+        .build(&dir)
+        .map_err(|err| format!("Failed to create log file: {err}"))?;
+    let (writer, guard) = tracing_appender::non_blocking(appender);
+    let (level_layer, level_handle) = reload::Layer::new(level);
+
+    tracing_subscriber::registry()
+        .with(level_layer)
+        .with(
+            tracing_subscriber::fmt::layer()
+                .with_ansi(false)
+                .with_writer(RedactingMakeWriter(writer)),
+        )
+        .try_init()
+        .map_err(|err| format!("Failed to install logger: {err}"))?;
+
+    let _ = app.state::<AppState>().logging.set(LogHandle {
+        dir,
+        level: level_handle,
+        _guard: guard,
+    });
+    tracing::info!("logging initialized at level {level}");
+    Ok(())
+}
+
+fn parse_log_level(level: &str) -> Option<LevelFilter> {
+    level.trim().parse::<LevelFilter>().ok()
+}
+
+/// Record how a spawned process exited.
+fn log_process_exit(label: &str, status: &ExitStatus) {
+    if status.success() {
+        tracing::info!("{label} process exited ({})", describe_exit_status(status));
+    } else {
+        tracing::warn!("{label} process failed ({})", describe_exit_status(status));
     }
+}
 
-    for preferred in preferred_names {
-        let preferred = preferred.to_ascii_lowercase();
-        if let Some((p, _, _)) = candidates.iter().find(|(_, _, name)| *name == preferred) {
-            return Ok(p.to_path_buf());
-        }
+/// Mask API keys and bearer tokens before anything reaches the log file.
+fn redact_secrets(text: &str) -> String {
+    static PATTERNS: OnceLock<Vec<(Regex, &'static str)>> = OnceLock::new();
+    let patterns = PATTERNS.get_or_init(|| {
+        vec![
+            (
+                Regex::new(r"(?i)(bearer\s+)[A-Za-z0-9._~+/=-]+").expect("valid regex"),
+                "${1}[REDACTED]",
+            ),
+            (
+                Regex::new(r"(?i)(\x22?api_?key\x22?\s*[:=]\s*\x22?)[^\x22\s,}]+").expect("valid regex"),
+                "${1}[REDACTED]",
+            ),
+            (
+                Regex::new(r"\bsk-[A-Za-z0-9_-]{8,}").expect("valid regex"),
+                "[REDACTED]",
+            ),
+        ]
+    });
+
+    let mut redacted = text.to_string();
+    for (pattern, replacement) in patterns {
+        redacted = pattern.replace_all(&redacted, *replacement).into_owned();
     }
+    redacted
+}
 
-    // Fallback: pick the largest .bin in the folder.
-    if let Some((p, _, _)) = candidates.into_iter().max_by_key(|(_, size, _)| *size) {
-        return Ok(p);
+struct RedactingMakeWriter(tracing_appender::non_blocking::NonBlocking);
+
+struct RedactingWriter(tracing_appender::non_blocking::NonBlocking);
+
+impl<'a> MakeWriter<'a> for RedactingMakeWriter {
+    type Writer = RedactingWriter;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        RedactingWriter(self.0.clone())
     }
+}
 
-    Err(format!(
-        "Model not found. Provide a .bin file or a folder containing ggml-*.bin models. Got: {}",
-        input
-    ))
+impl Write for RedactingWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let redacted = redact_secrets(&String::from_utf8_lossy(buf));
+        self.0.write_all(redacted.as_bytes())?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.0.flush()
+    }
+}
+
+/// Most recently written log file, or the log directory when none exist yet.
+fn current_log_file(dir: &Path) -> PathBuf {
+    fs::read_dir(dir)
+        .ok()
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_name().to_string_lossy().starts_with(LOG_FILE_PREFIX))
+        .filter_map(|entry| {
+            let modified = entry.metadata().ok()?.modified().ok()?;
+            Some((modified, entry.path()))
+        })
+        .max_by_key(|(modified, _)| *modified)
+        .map(|(_, path)| path)
+        .unwrap_or_else(|| dir.to_path_buf())
 }
 
-// ============================================================================
-// Action Items Extraction
-// ============================================================================
+#[tauri::command]
+fn get_log_path(state: State<'_, AppState>) -> Result<String, String> {
+    let handle = state.logging.get().ok_or("File logging is not enabled")?;
+    Ok(current_log_file(&handle.dir).to_string_lossy().to_string())
+}
 
 #[tauri::command]
-fn extract_action_items(
+async fn set_log_level(
     app: tauri::AppHandle,
-    meeting_id: String,
-    transcript: String,
-    notes: String,
-    model: String,
+    state: State<'_, AppState>,
+    level: String,
 ) -> Result<(), String> {
-    let temp_dir = std::env::temp_dir().join("voxii");
-    fs::create_dir_all(&temp_dir)
-        .map_err(|err| format!("Failed to create temp dir: {err}"))?;
-
-    let id = uuid::Uuid::new_v4().to_string();
-    let input_path = temp_dir.join(format!("{id}_actions.json"));
-
-    let payload = serde_json::json!({
-        "transcript": transcript,
-        "notes": notes,
-        "model": model
-    });
-
-    fs::write(&input_path, payload.to_string())
-        .map_err(|err| format!("Failed to write actions payload: {err}"))?;
+    let filter = parse_log_level(&level).ok_or_else(|| {
+        format!("Unknown log level: {level} (use trace, debug, info, warn, error or off)")
+    })?;
+    let handle = state.logging.get().ok_or("File logging is not enabled")?;
+    handle
+        .level
+        .modify(|current| *current = filter)
+        .map_err(|err| format!("Failed to change log level: {err}"))?;
+
+    let mut config = load_config(app.clone()).await?;
+    config.logging.level = level.trim().to_lowercase();
+    let path = config_path(&app)?;
+    save_config(&path, &config)?;
+    tracing::info!("log level set to {filter}");
+    Ok(())
+}
 
-    let script_path = Path::new(env!("CARGO_MANIFEST_DIR"))
-        .join("scripts")
-        .join("copilot-actions.mjs");
+const LOG_TAIL_POLL: Duration = Duration::from_millis(500);
 
-    if !script_path.exists() {
-        return Err(format!(
-            "Actions script not found: {}",
-            script_path.display()
-        ));
+/// Emit each line appended to the current log file as `app-log-line
+/// { line }`, redacted, until `stop_log_tail`. Starts at the end of the file,
+/// follows daily rotation and replaces a tail that is already running.
+#[tauri::command]
+fn start_log_tail(app: tauri::AppHandle, state: State<'_, AppState>) -> Result<(), String> {
+    let dir = state.logging.get().ok_or("File logging is not enabled")?.dir.clone();
+    let stop = Arc::new(AtomicBool::new(false));
+    {
+        let mut tail = state.log_tail.lock().map_err(|_| "Failed to acquire lock")?;
+        if let Some(previous) = tail.replace(stop.clone()) {
+            previous.store(true, Ordering::SeqCst);
+        }
     }
 
-    tauri::async_runtime::spawn_blocking(move || {
-        let output = match Command::new("node")
-            .arg(&script_path)
-            .arg(&input_path)
-            .output()
-        {
-            Ok(output) => output,
-            Err(err) => {
-                let _ = app.emit(
-                    "actions-error",
-                    serde_json::json!({
-                        "meetingId": meeting_id,
-                        "error": format!("Failed to run actions script: {err}")
-                    }),
-                );
-                return;
+    let file_len = |path: &Path| fs::metadata(path).map(|meta| meta.len()).unwrap_or(0);
+    let mut path = current_log_file(&dir);
+    let mut offset = file_len(&path);
+    std::thread::spawn(move || {
+        let mut partial = Vec::new();
+        while !stop.load(Ordering::SeqCst) {
+            std::thread::sleep(LOG_TAIL_POLL);
+            let latest = current_log_file(&dir);
+            if latest != path {
+                path = latest;
+                offset = 0;
+                partial.clear();
             }
-        };
-
-        let stdout = String::from_utf8_lossy(&output.stdout).to_string();
-        
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            let _ = app.emit(
-                "actions-error",
-                serde_json::json!({
-                    "meetingId": meeting_id,
-                    "error": format!("Actions extraction failed: {stderr}")
-                }),
-            );
-            return;
-        }
-
-        // Parse the JSON output
-        match serde_json::from_str::<serde_json::Value>(stdout.trim()) {
-            Ok(result) => {
-                let _ = app.emit(
-                    "actions-done",
-                    serde_json::json!({
-                        "meetingId": meeting_id,
-                        "actions": result
-                    }),
-                );
+            let len = file_len(&path);
+            if len < offset {
+                // Truncated underneath us; start over.
+                offset = 0;
+                partial.clear();
             }
-            Err(err) => {
+            if len == offset {
+                continue;
+            }
+            let Ok(mut file) = fs::File::open(&path) else {
+                continue;
+            };
+            use std::io::Seek;
+            if file.seek(std::io::SeekFrom::Start(offset)).is_err() {
+                continue;
+            }
+            let mut chunk = Vec::new();
+            if file.take(len - offset).read_to_end(&mut chunk).is_err() {
+                continue;
+            }
+            offset += chunk.len() as u64;
+            partial.extend_from_slice(&chunk);
+            // Hold back a trailing half-written line for the next poll.
+            let Some(end) = partial.iter().rposition(|byte| *byte == b'\n') else {
+                continue;
+            };
+            let complete: Vec<u8> = partial.drain(..=end).collect();
+            for line in lossy_lines(complete.as_slice()) {
+                let line = line.trim_end();
+                if line.is_empty() {
+                    continue;
+                }
                 let _ = app.emit(
-                    "actions-error",
-                    serde_json::json!({
-                        "meetingId": meeting_id,
-                        "error": format!("Failed to parse actions: {err}")
-                    }),
+                    "app-log-line",
+                    serde_json::json!({ "line": redact_secrets(line) }),
                 );
             }
         }
     });
-
     Ok(())
 }
 
-// ============================================================================
-// Export
-// ============================================================================
-
+/// Stop the `start_log_tail` poller; returns whether one was running.
 #[tauri::command]
-async fn export_meeting_markdown(
-    app: tauri::AppHandle,
-    meeting: MeetingRecord,
-    include_transcript: bool,
-) -> Result<String, String> {
-    tauri::async_runtime::spawn_blocking(move || {
-        let mut md = String::new();
-        
-        // Header
-        md.push_str(&format!("# {}\n\n", meeting.title));
-        md.push_str(&format!("**Date:** {}  \n", meeting.created_at));
-        md.push_str(&format!("**Last Updated:** {}\n\n", meeting.updated_at));
-        
-        // Summary
-        if !meeting.summary.is_empty() {
-            md.push_str("---\n\n");
-            md.push_str(&meeting.summary);
-            md.push_str("\n\n");
-        }
-        
-        // Action Items
-        if !meeting.action_items.is_empty() {
-            md.push_str("## Action Items\n\n");
-            for item in &meeting.action_items {
-                let checkbox = if item.status == "completed" { "[x]" } else { "[ ]" };
-                let assignee = item.assignee.as_deref().unwrap_or("Unassigned");
-                let due = item.due_date.as_deref().map(|d| format!(" (due: {})", d)).unwrap_or_default();
-                md.push_str(&format!("- {} **{}**: {}{}\n", checkbox, assignee, item.task, due));
-            }
-            md.push_str("\n");
-        }
-        
-        // Notes
-        if !meeting.notes.is_empty() {
-            md.push_str("## Notes\n\n");
-            md.push_str(&meeting.notes);
-            md.push_str("\n\n");
-        }
-        
-        // Transcript (optional)
-        if include_transcript && !meeting.transcript.is_empty() {
-            md.push_str("## Transcript\n\n");
-            md.push_str("<details>\n<summary>Click to expand transcript</summary>\n\n");
-            md.push_str(&meeting.transcript);
-            md.push_str("\n\n</details>\n\n");
-        }
-        
-        // Footer
-        md.push_str("---\n*Generated by Voxii*\n");
-        
-        // Save to file
-        let config = load_config_sync(&app)?;
-        let export_path = if config.export.local_path.is_empty() {
-            dirs::document_dir()
-                .unwrap_or_else(|| std::env::temp_dir())
-                .join("Voxii")
-        } else {
-            PathBuf::from(&config.export.local_path)
-        };
-        
-        fs::create_dir_all(&export_path)
-            .map_err(|err| format!("Failed to create export directory: {err}"))?;
-        
-        // Sanitize filename
-        let safe_title: String = meeting.title
-            .chars()
-            .map(|c| if c.is_alphanumeric() || c == ' ' || c == '-' { c } else { '_' })
-            .collect();
-        let filename = format!("{} - {}.md", 
-            meeting.created_at.split('T').next().unwrap_or("unknown"),
-            safe_title.trim()
-        );
-        let file_path = export_path.join(&filename);
-        
-        fs::write(&file_path, &md)
-            .map_err(|err| format!("Failed to write export file: {err}"))?;
-        
-        Ok(file_path.to_string_lossy().to_string())
-    })
-    .await
-    .map_err(|err| format!("Failed to export: {err}"))?
-}
-
-fn load_config_sync(app: &tauri::AppHandle) -> Result<AppConfig, String> {
-    let path = config_path(app)?;
-    if !path.exists() {
-        return Ok(AppConfig::default());
+fn stop_log_tail(state: State<'_, AppState>) -> Result<bool, String> {
+    let stop = state
+        .log_tail
+        .lock()
+        .map_err(|_| "Failed to acquire lock")?
+        .take();
+    if let Some(stop) = &stop {
+        stop.store(true, Ordering::SeqCst);
     }
-    let raw = fs::read_to_string(&path)
-        .map_err(|err| format!("Failed to read config: {err}"))?;
-    let config = serde_json::from_str::<AppConfig>(&raw)
-        .map_err(|err| format!("Failed to parse config: {err}"))?;
-    Ok(config)
+    Ok(stop.is_some())
 }
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
@@ -1629,52 +10692,342 @@ pub fn run() {
         .plugin(tauri_plugin_global_shortcut::Builder::new().build())
         .manage(AppState {
             streaming_sessions: Mutex::new(HashMap::new()),
+            ai_limiter: Arc::new(AiLimiter::default()),
+            whisper_queue: Arc::new(WhisperQueue::default()),
+            child_jobs: Arc::new(ChildJobs::default()),
+            action_item_jobs: Mutex::new(HashMap::new()),
+            summary_batch: Mutex::new(None),
+            log_tail: Mutex::new(None),
+            http_server: Mutex::new(None),
+            remote_usage: Mutex::new(UsageStats::default()),
+            logging: OnceLock::new(),
+            last_meetings_write: Mutex::new(None),
+            pending_meetings: Mutex::new(None),
+        })
+        .setup(|app| {
+            if let Err(err) = init_logging(app.handle()) {
+                eprintln!("File logging disabled: {err}");
+            }
+            match data_dir_location(app.handle()) {
+                Ok(location) if location.portable => {
+                    tracing::warn!("using portable data dir {}", location.path.display())
+                }
+                Ok(location) => tracing::info!("using data dir {}", location.path.display()),
+                Err(err) => tracing::error!("no usable data dir: {err}"),
+            }
+
+            let handle = app.handle().clone();
+            std::thread::spawn(move || loop {
+                std::thread::sleep(SESSION_SWEEP_INTERVAL);
+                prune_stale_sessions_sync(&handle, Duration::from_secs(DEFAULT_SESSION_IDLE_SECS));
+            });
+
+            match load_config_sync(app.handle()) {
+                Ok(config) if config.server.enabled => {
+                    if let Err(err) = start_http_server_sync(app.handle(), &config) {
+                        tracing::error!("HTTP server not started: {err}");
+                    }
+                }
+                Ok(_) => {}
+                Err(err) => tracing::warn!("HTTP server check skipped: {err}"),
+            }
+            Ok(())
         })
         .invoke_handler(tauri::generate_handler![
             transcribe_audio,
+            transcribe_file,
+            transcribe_multichannel,
             diagnose_whisper,
+            warmup_whisper,
+            test_model,
+            detect_language,
             generate_summary,
             start_summary_stream,
+            preview_summary_prompt,
             list_models,
             list_local_models,
+            supported_languages,
+            known_models,
+            model_catalog,
+            estimate_remote_cost,
+            get_usage_stats,
             enhance_text,
             start_enhance_stream,
             clean_transcript,
+            clean_transcript_with_diff,
             start_clean_transcript_stream,
             load_config,
             save_config_command,
+            repair_config,
+            effective_config,
+            merge_config,
+            provider_capabilities,
+            get_log_path,
+            get_data_dir,
+            storage_usage,
+            set_log_level,
+            start_log_tail,
+            stop_log_tail,
             load_meetings,
+            normalize_meetings,
             save_meetings,
             start_streaming_session,
             transcribe_chunk,
+            transcribe_pcm_chunk,
             end_streaming_session,
+            retry_failed_chunks,
+            start_live_summary,
+            get_streaming_session,
+            list_streaming_sessions,
+            prune_stale_sessions,
             extract_action_items,
-            export_meeting_markdown
+            cancel_action_items,
+            cancel_all_jobs,
+            suggest_title,
+            batch_regenerate_summaries,
+            cancel_batch_summaries,
+            segment_topics,
+            extract_decisions,
+            extract_questions,
+            meeting_stats,
+            models_in_use,
+            assess_summary_risk,
+            compute_wer,
+            paragraphize_transcript,
+            analyze_audio,
+            find_chunk_boundaries,
+            validate_audio,
+            export_meeting_markdown,
+            export_meeting_html,
+            export_transcript,
+            export_redacted_transcript,
+            generate_period_report,
+            list_recent_exports,
+            open_exports_folder,
+            reveal_export,
+            export_subtitles,
+            import_subtitles,
+            export_action_items_ics,
+            sync_all_exports,
+            delete_meeting,
+            purge_transcript,
+            meeting_content_hash,
+            attach_audio,
+            get_audio_path,
+            retranscribe_meeting,
+            export_backup,
+            import_backup,
+            start_http_server,
+            stop_http_server
         ])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|app, event| {
+            if let tauri::RunEvent::Exit = event {
+                if let Err(err) = flush_pending_meetings(app) {
+                    tracing::error!("meetings not saved on exit: {err}");
+                }
+            }
+        });
+}
+
+/// Why one of Voxii's working directories couldn't be used. Converts into the
+/// `String` errors commands return, so `?` works at every call site.
+#[derive(Debug)]
+enum DirError {
+    Resolve(String),
+    Create(PathBuf, std::io::Error),
+    NotWritable(PathBuf),
+}
+
+impl std::fmt::Display for DirError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DirError::Resolve(err) => write!(f, "Failed to resolve app data dir: {err}"),
+            DirError::Create(dir, err) => {
+                write!(f, "Failed to create {}: {err}", dir.display())
+            }
+            DirError::NotWritable(dir) => write!(f, "{} is not writable", dir.display()),
+        }
+    }
+}
+
+impl From<DirError> for String {
+    fn from(err: DirError) -> Self {
+        err.to_string()
+    }
+}
+
+/// Create `dir` if needed and make sure we can write to it. `create_dir_all`
+/// tolerates concurrent creators, so parallel commands can share this.
+fn ensure_writable_dir(dir: PathBuf) -> Result<PathBuf, DirError> {
+    fs::create_dir_all(&dir).map_err(|err| DirError::Create(dir.clone(), err))?;
+    let readonly = fs::metadata(&dir)
+        .map(|meta| meta.permissions().readonly())
+        .unwrap_or(false);
+    if readonly {
+        return Err(DirError::NotWritable(dir));
+    }
+    Ok(dir)
+}
+
+/// Scratch space for whisper inputs/outputs and node payloads. The one place
+/// to change if the temp location ever becomes configurable.
+fn voxii_temp_dir() -> Result<PathBuf, DirError> {
+    ensure_writable_dir(std::env::temp_dir().join("voxii"))
+}
+
+/// Where the data dir lives, resolved once per run.
+struct DataDirLocation {
+    path: PathBuf,
+    /// The platform dir couldn't be resolved; using a dir next to the
+    /// executable (or the working dir) instead.
+    portable: bool,
+}
+
+fn data_dir_location(app: &tauri::AppHandle) -> Result<&'static DataDirLocation, DirError> {
+    static LOCATION: OnceLock<DataDirLocation> = OnceLock::new();
+    if let Some(location) = LOCATION.get() {
+        return Ok(location);
+    }
+
+    let location = match app.path().app_data_dir() {
+        Ok(base) => DataDirLocation {
+            path: base.join("voxii"),
+            portable: false,
+        },
+        Err(err) => {
+            let path = std::env::current_exe()
+                .ok()
+                .and_then(|exe| exe.parent().map(|dir| dir.join("voxii-data")))
+                .or_else(|| std::env::current_dir().ok().map(|dir| dir.join("voxii-data")))
+                .ok_or_else(|| DirError::Resolve(err.to_string()))?;
+            tracing::warn!("app data dir unavailable ({err}); using {}", path.display());
+            DataDirLocation {
+                path,
+                portable: true,
+            }
+        }
+    };
+    Ok(LOCATION.get_or_init(|| location))
+}
+
+/// Config, meetings and attached audio live here.
+fn voxii_data_dir(app: &tauri::AppHandle) -> Result<PathBuf, DirError> {
+    ensure_writable_dir(data_dir_location(app)?.path.clone())
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DataDirInfo {
+    path: String,
+    portable: bool,
+}
+
+#[tauri::command]
+async fn get_data_dir(app: tauri::AppHandle) -> Result<DataDirInfo, String> {
+    let location = data_dir_location(&app)?;
+    Ok(DataDirInfo {
+        path: location.path.to_string_lossy().to_string(),
+        portable: location.portable,
+    })
+}
+
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+struct DirUsage {
+    path: String,
+    bytes: u64,
+    files: u64,
+    /// Subdirectories or files that couldn't be read and were left out.
+    unreadable: u64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct StorageUsage {
+    /// Includes `audioDir`, which lives inside it.
+    data_dir: DirUsage,
+    models_dir: DirUsage,
+    audio_dir: DirUsage,
+    export_dir: DirUsage,
+    temp_dir: DirUsage,
+}
+
+/// Disk used by each of Voxii's directories, for the storage settings page.
+#[tauri::command]
+async fn storage_usage(app: tauri::AppHandle) -> Result<StorageUsage, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let config = load_config_sync(&app)?;
+        let data_dir = voxii_data_dir(&app)?;
+        let models_root = models_root(&app, &config)?;
+        // The configured model path may name a single file; report its folder.
+        let model_path = unquote_path(config.effective_model_path());
+        let models_dir = if model_path.is_empty() {
+            models_root
+        } else {
+            let path = expand_model_path(model_path, &models_root);
+            match path.parent() {
+                Some(parent) if path.is_file() => parent.to_path_buf(),
+                _ => path,
+            }
+        };
+        Ok(StorageUsage {
+            audio_dir: dir_usage(&data_dir.join("audio")),
+            data_dir: dir_usage(&data_dir),
+            models_dir: dir_usage(&models_dir),
+            export_dir: dir_usage(&export_dir(&config)),
+            temp_dir: dir_usage(&std::env::temp_dir().join("voxii")),
+        })
+    })
+    .await
+    .map_err(|err| format!("Failed to measure storage task: {err}"))?
+}
+
+/// Total size and file count under `root`. Symlinks aren't followed, and
+/// unreadable entries are counted and skipped rather than failing the walk.
+fn dir_usage(root: &Path) -> DirUsage {
+    let mut usage = DirUsage {
+        path: root.to_string_lossy().to_string(),
+        ..Default::default()
+    };
+    if !root.is_dir() {
+        return usage;
+    }
+    let mut pending = vec![root.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        let Ok(entries) = fs::read_dir(&dir) else {
+            usage.unreadable += 1;
+            continue;
+        };
+        for entry in entries {
+            let Ok(entry) = entry else {
+                usage.unreadable += 1;
+                continue;
+            };
+            match entry.file_type() {
+                Ok(kind) if kind.is_dir() => pending.push(entry.path()),
+                Ok(kind) if kind.is_file() => match entry.metadata() {
+                    Ok(meta) => {
+                        usage.bytes += meta.len();
+                        usage.files += 1;
+                    }
+                    Err(_) => usage.unreadable += 1,
+                },
+                Ok(_) => {}
+                Err(_) => usage.unreadable += 1,
+            }
+        }
+    }
+    usage
 }
 
 fn config_path(app: &tauri::AppHandle) -> Result<PathBuf, String> {
-    let dir = app
-        .path()
-        .app_data_dir()
-        .map_err(|err| format!("Failed to resolve app data dir: {err}"))?
-        .join("voxii");
-    fs::create_dir_all(&dir)
-        .map_err(|err| format!("Failed to create app data dir: {err}"))?;
-    Ok(dir.join("config.json"))
+    Ok(voxii_data_dir(app)?.join("config.json"))
 }
 
 fn meetings_path(app: &tauri::AppHandle) -> Result<PathBuf, String> {
-    let dir = app
-        .path()
-        .app_data_dir()
-        .map_err(|err| format!("Failed to resolve app data dir: {err}"))?
-        .join("voxii");
-    fs::create_dir_all(&dir)
-        .map_err(|err| format!("Failed to create app data dir: {err}"))?;
-    Ok(dir.join("meetings.json"))
+    Ok(voxii_data_dir(app)?.join("meetings.json"))
 }
 
 fn save_config(path: &Path, config: &AppConfig) -> Result<(), String> {
@@ -1683,3 +11036,223 @@ fn save_config(path: &Path, config: &AppConfig) -> Result<(), String> {
     fs::write(path, payload).map_err(|err| format!("Failed to save config: {err}"))?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Hands out one chunk per `read`, like a pipe written in pieces.
+    struct ChunkedReader(std::collections::VecDeque<Vec<u8>>);
+
+    impl Read for ChunkedReader {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let Some(mut chunk) = self.0.pop_front() else {
+                return Ok(0);
+            };
+            let n = chunk.len().min(buf.len());
+            buf[..n].copy_from_slice(&chunk[..n]);
+            if n < chunk.len() {
+                self.0.push_front(chunk.split_off(n));
+            }
+            Ok(n)
+        }
+    }
+
+    /// Scratch dir under the system temp dir, removed on drop. The name has a
+    /// space and non-ASCII characters on purpose.
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new() -> Self {
+            let dir = std::env::temp_dir().join(format!("voxii test ü-{}", uuid::Uuid::new_v4()));
+            fs::create_dir_all(&dir).expect("create temp dir");
+            TempDir(dir)
+        }
+
+        fn touch(&self, relative: &str, bytes: usize) -> PathBuf {
+            let path = self.0.join(relative);
+            fs::create_dir_all(path.parent().expect("parent")).expect("create parent");
+            fs::write(&path, vec![0u8; bytes]).expect("write file");
+            path
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    const WHISPER_BIN: &str = if cfg!(windows) { "whisper-cli.exe" } else { "whisper-cli" };
+
+    #[test]
+    fn whisper_path_with_spaces_quotes_and_trailing_separator() {
+        let temp = TempDir::new();
+        let binary = temp.touch(&format!("whisper cpp/{WHISPER_BIN}"), 1);
+        let dir = temp.0.join("whisper cpp");
+        let dir_text = dir.to_string_lossy().to_string();
+        let sep = std::path::MAIN_SEPARATOR;
+
+        for input in [
+            dir_text.clone(),
+            format!("{dir_text}/"),
+            format!("{dir_text}{sep}"),
+            format!("  \"{dir_text}\"  "),
+            format!("'{dir_text}{sep}'"),
+            binary.to_string_lossy().to_string(),
+            format!("\"{}\"", binary.display()),
+        ] {
+            assert_eq!(resolve_whisper_path(&input).as_deref(), Ok(binary.as_path()), "{input}");
+        }
+        assert!(resolve_whisper_path(&format!("{dir_text} missing")).is_err());
+        assert!(resolve_whisper_path("  \"\" ").is_err());
+    }
+
+    #[test]
+    fn model_path_relative_prefixes_and_selection() {
+        let temp = TempDir::new();
+        let root = &temp.0;
+        let small = temp.touch("models/ggml-small.bin", 1);
+        let base_en = temp.touch("models/ggml-base.en.bin", 2);
+        let accented = temp.touch("models/modèle été.bin", 1);
+
+        // Relative prefixes, with either separator, resolve against the root.
+        assert_eq!(expand_model_path("./models/ggml-small.bin", root), small);
+        assert_eq!(expand_model_path("models/ggml-small.bin", root), small);
+        assert_eq!(expand_model_path(".\\models", root), root.join("models"));
+        assert_eq!(expand_model_path(&small.to_string_lossy(), root), small);
+
+        // A folder picks the largest .bin when nothing preferred is there.
+        for base in ["models/", "./models", "./models/", " \"./models/\" "] {
+            assert_eq!(resolve_model_path_with_selection(base, "", root), Ok(base_en.clone()), "{base}");
+        }
+
+        // Selections: file name, friendly name, non-ASCII name, quoted path.
+        let dir = root.join("models").to_string_lossy().to_string();
+        assert_eq!(
+            resolve_model_path_with_selection(&format!("{dir}/"), "ggml-small.bin", root),
+            Ok(small.clone())
+        );
+        assert_eq!(resolve_model_path_with_selection(&dir, "base.en", root), Ok(base_en.clone()));
+        assert_eq!(
+            resolve_model_path_with_selection("./models", "modèle été.bin", root),
+            Ok(accented.clone())
+        );
+        assert_eq!(
+            resolve_model_path_with_selection("models/", &format!("\"{}\"", small.display()), root),
+            Ok(small.clone())
+        );
+        let missing = resolve_model_path_with_selection("models/", "tiny", root).unwrap_err();
+        assert!(missing.contains("not downloaded"), "{missing}");
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn model_path_with_backslashes() {
+        let temp = TempDir::new();
+        let model = temp.touch("models\\ggml-small.bin", 1);
+        for base in ["models\\", ".\\models", ".\\models\\"] {
+            assert_eq!(resolve_model_path_with_selection(base, "", &temp.0), Ok(model.clone()), "{base}");
+        }
+    }
+
+    #[test]
+    fn lossy_lines_survives_split_and_invalid_utf8() {
+        // "é" (0xC3 0xA9) straddles two reads; 0xFF is never valid UTF-8.
+        let chunks = [
+            b"{\"type\":\"delta\",\"content\":\"caf\xC3".to_vec(),
+            b"\xA9\"}\r\nbad \xFF byte\n".to_vec(),
+            b"last".to_vec(),
+        ];
+        let lines: Vec<String> = lossy_lines(ChunkedReader(chunks.into_iter().collect())).collect();
+        assert_eq!(
+            lines,
+            [
+                "{\"type\":\"delta\",\"content\":\"café\"}",
+                "bad \u{FFFD} byte",
+                "last",
+            ]
+        );
+    }
+
+    #[test]
+    fn speaker_labels_only_match_known_forms() {
+        for (line, label) in [
+            ("Speaker 1: hi", "Speaker 1"),
+            ("SPEAKER_01: hi", "SPEAKER_01"),
+            ("[SPEAKER_01] hi", "SPEAKER_01"),
+            ("[Alice Smith]: hi", "Alice Smith"),
+        ] {
+            assert_eq!(split_speaker_label(line), (Some(label), "hi"), "{line}");
+        }
+        for line in ["Note: the budget is fixed", "Action items: none", "[Music] la la", "Alice: hi"] {
+            assert_eq!(split_speaker_label(line), (None, line), "{line}");
+        }
+    }
+
+    fn meeting(id: &str, title: &str) -> MeetingRecord {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "title": title,
+            "notes": "",
+            "transcript": "",
+            "summary": "",
+            "createdAt": "2026-10-17T09:00:00Z",
+            "updatedAt": "2026-10-17T09:00:00Z",
+        }))
+        .expect("meeting")
+    }
+
+    #[test]
+    fn sync_export_keeps_same_day_same_title_meetings_apart() {
+        let temp = TempDir::new();
+        let config = AppConfig::default();
+        let mut manifest = HashMap::new();
+        let mut sync = |meetings: &[MeetingRecord]| {
+            let stems = export_stem_counts(meetings);
+            for meeting in meetings {
+                sync_meeting_export(meeting, &stems, &config, &temp.0, "md", &mut manifest)
+                    .expect("sync");
+            }
+        };
+        let file = |name: &str| temp.0.join(format!("2026-10-17 - {name}.md"));
+
+        let mut standup = meeting("aaaaaaaa-1111", "Standup");
+        sync(&[standup.clone()]);
+        assert!(file("Standup").is_file());
+
+        // The renamed meeting's cleanup must not delete the file another
+        // meeting has just taken its old name for.
+        standup.title = "Retro".to_string();
+        let other = meeting("bbbbbbbb-2222", "Standup");
+        sync(&[other.clone(), standup.clone()]);
+        assert!(file("Standup").is_file());
+        assert!(file("Retro").is_file());
+
+        // A second retro that day gets its own file, and so does the first;
+        // the plain name is nobody's now and goes away.
+        let retro = meeting("cccccccc-3333", "retro");
+        sync(&[other, standup, retro]);
+        assert!(file("Retro (aaaaaaaa)").is_file());
+        assert!(file("retro (cccccccc)").is_file());
+        assert!(!file("Retro").exists());
+        assert!(file("Standup").is_file());
+    }
+
+    #[test]
+    fn sync_manifest_tracks_each_format() {
+        let temp = TempDir::new();
+        let config = AppConfig::default();
+        let meetings = [meeting("aaaaaaaa-1111", "Standup")];
+        let stems = export_stem_counts(&meetings);
+        let mut manifest = HashMap::new();
+        for (pass, expect_written) in [(1, true), (2, false)] {
+            for extension in ["md", "html"] {
+                let written =
+                    sync_meeting_export(&meetings[0], &stems, &config, &temp.0, extension, &mut manifest)
+                        .expect("sync");
+                assert_eq!(written.is_some(), expect_written, "pass {pass}, {extension}");
+            }
+        }
+    }
+}
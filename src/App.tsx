@@ -22,6 +22,46 @@ type TranscribeResponse = {
   stderr: string;
   command: string;
   provider: string;
+  model?: string;
+  segments?: TranscriptSegment[];
+  rawTranscript?: string;
+  punctuationRestored?: boolean;
+  language?: string;
+  audioPath?: string;
+  modelFallbackFrom?: string;
+  words?: WordTiming[];
+  wordsInterpolated?: boolean;
+};
+
+type WordTiming = {
+  text: string;
+  startMs: number;
+  endMs: number;
+};
+
+type RecentExport = {
+  name: string;
+  path: string;
+  size: number;
+  modifiedAt: string;
+};
+
+type StreamingSessionResult = {
+  transcript: string;
+  failedIndices: number[];
+};
+
+type TranscriptSegment = {
+  startMs: number;
+  endMs: number;
+  text: string;
+  confidence?: number;
+  language?: string;
+};
+
+type LanguageOption = {
+  code: string;
+  name: string;
 };
 
 type TranscriptionProvider = "local" | "openai-compatible" | "auto";
@@ -38,17 +78,23 @@ type LocalTranscriptionConfig = {
   modelName: string;
   beamSize: number;
   bestOf: number;
+  // Overrides transcription.language for this provider
+  language?: string;
 };
 
 type OpenAICompatibleConfig = {
   endpoint: string;
   apiKey: string;
   model: string;
+  language?: string;
 };
 
 type TranscriptionConfig = {
   provider: TranscriptionProvider;
-  language: string;
+  // Unset = "en", "auto" = detect, "" = send no language hint
+  language?: string;
+  autoClean?: boolean;
+  restorePunctuation?: boolean;
   streaming: StreamingConfig;
   local: LocalTranscriptionConfig;
   openaiCompatible: OpenAICompatibleConfig;
@@ -61,12 +107,23 @@ type AIConfig = {
 type ExportConfig = {
   defaultFormat: string;
   localPath: string;
+  frontmatter?: boolean;
+  sidecarMetadata?: boolean;
+  autoExport?: boolean;
+  autoExportFormat?: string;
 };
 
 type UIConfig = {
   theme: string;
   showDiagnostics: boolean;
   includeSystemAudio: boolean;
+  saveDebounceMs?: number;
+};
+
+type ServerConfig = {
+  enabled: boolean;
+  port: number;
+  token: string;
 };
 
 type AppConfig = {
@@ -75,6 +132,8 @@ type AppConfig = {
   ai: AIConfig;
   export: ExportConfig;
   ui: UIConfig;
+  server?: ServerConfig;
+  offlineMode?: boolean;
   // Legacy fields for backward compat
   whisperPath?: string;
   modelPath?: string;
@@ -93,6 +152,27 @@ type ActionItem = {
   context: string | null;
 };
 
+type Decision = {
+  decision: string;
+  rationale: string | null;
+  owner: string | null;
+};
+
+type Question = {
+  question: string;
+  askedBy: string | null;
+  answered: boolean;
+  answer: string | null;
+};
+
+type TopicChapter = {
+  title: string;
+  startChar: number;
+  endChar: number;
+  startMs?: number;
+  endMs?: number;
+};
+
 type MeetingRecord = {
   id: string;
   title: string;
@@ -102,6 +182,18 @@ type MeetingRecord = {
   actionItems: ActionItem[];
   createdAt: string;
   updatedAt: string;
+  audioPath?: string | null;
+  chapters?: TopicChapter[];
+  decisions?: Decision[];
+  questions?: Question[];
+  tags?: string[];
+  transcriptionProvider?: string;
+  transcriptionModel?: string;
+  segments?: TranscriptSegment[];
+  transcriptHistory?: { transcript: string; replacedAt: string }[];
+  summaryHistory?: { summary: string; replacedAt: string }[];
+  formattedTranscript?: string;
+  contentHash?: string;
 };
 
 type SelectionState = {
@@ -346,6 +438,7 @@ function App() {
   const [models, setModels] = useState<string[]>([]);
   const [selectedModel, setSelectedModel] = useState<string>("gpt-4.1");
   const [localModelOptions, setLocalModelOptions] = useState<string[]>([]);
+  const [languageOptions, setLanguageOptions] = useState<LanguageOption[]>([]);
   
   // UI State
   const [status, setStatus] = useState("Idle");
@@ -355,10 +448,15 @@ function App() {
   const [isCleaningTranscript, setIsCleaningTranscript] = useState(false);
   const [isEnhancingSelection, setIsEnhancingSelection] = useState(false);
   const [isExtractingActions, setIsExtractingActions] = useState(false);
+  const [isSegmentingTopics, setIsSegmentingTopics] = useState(false);
+  const [isExtractingDecisions, setIsExtractingDecisions] = useState(false);
+  const [isExtractingQuestions, setIsExtractingQuestions] = useState(false);
+  const [isRetranscribing, setIsRetranscribing] = useState(false);
   const [logs, setLogs] = useState<string[]>([]);
   const [diagnosticsOpen, setDiagnosticsOpen] = useState(false);
   const [settingsOpen, setSettingsOpen] = useState(false);
   const [isTranscriptCollapsed, setIsTranscriptCollapsed] = useState(false);
+  const [recentExports, setRecentExports] = useState<RecentExport[] | null>(null);
   const [selection, setSelection] = useState<SelectionState>({
     field: null,
     start: 0,
@@ -367,6 +465,8 @@ function App() {
   
   // Live transcript during recording
   const [liveTranscript, setLiveTranscript] = useState<string>("");
+  // Text of the chunk still being transcribed, when the provider streams it.
+  const [livePartial, setLivePartial] = useState<string>("");
   const [streamingSessionId, setStreamingSessionId] = useState<string | null>(null);
 
   // Refs
@@ -392,6 +492,8 @@ function App() {
   const streamingChunkIndexRef = useRef<number>(0);
   const streamingBusyRef = useRef<boolean>(false);
   const pendingStreamingChunkRef = useRef<Blob | null>(null);
+  // Audio of chunks that failed to transcribe, kept for a retry at stop.
+  const failedChunkAudioRef = useRef<Map<number, string>>(new Map());
 
   const activeMeeting = useMemo(
     () => meetings.find((meeting) => meeting.id === activeMeetingId) || null,
@@ -407,6 +509,12 @@ function App() {
       .then((data) => {
         setConfig(data);
         setSelectedModel(data.defaultModel || "gpt-4.1");
+        // Prime the local model so the first recording isn't slowed by a cold load.
+        if ((data.transcription?.provider ?? "local") !== "openai-compatible") {
+          void invoke<number>("warmup_whisper")
+            .then((elapsedMs) => appendLog(`Whisper warmed up in ${elapsedMs}ms.`))
+            .catch((error) => appendLog(`Whisper warm-up skipped: ${String(error)}`));
+        }
       })
       .catch((error) => {
         appendLog(String(error));
@@ -504,6 +612,16 @@ function App() {
     );
   }, [localModelOptions, config]);
 
+  useEffect(() => {
+    if (!settingsOpen) return;
+    invoke<LanguageOption[]>("supported_languages")
+      .then(setLanguageOptions)
+      .catch((error) => {
+        appendLog(`Failed to list languages: ${String(error)}`);
+        setLanguageOptions([]);
+      });
+  }, [settingsOpen, config?.transcription?.provider, config?.transcription?.local?.modelName]);
+
   useEffect(() => {
     let isMounted = true;
     void register("Ctrl+Shift+Space", () => {
@@ -718,6 +836,7 @@ function App() {
       const payload = event.payload as { 
         meetingId: string; 
         actions: { items: ActionItem[] } 
+        warnings?: number;
       };
       if (payload?.meetingId === activeMeetingRef.current) {
         const items = payload.actions?.items || [];
@@ -729,6 +848,9 @@ function App() {
         setIsExtractingActions(false);
         setStatus("Idle");
         appendLog(`Extracted ${items.length} action items.`);
+        if (payload.warnings) {
+          appendLog(`Repaired ${payload.warnings} malformed action item field(s).`);
+        }
       }
     });
 
@@ -741,6 +863,15 @@ function App() {
       }
     });
 
+    const unlistenActionsCancelled = listen("actions-cancelled", (event) => {
+      const payload = event.payload as { meetingId: string };
+      if (payload?.meetingId === activeMeetingRef.current) {
+        setIsExtractingActions(false);
+        setStatus("Idle");
+        appendLog("Action extraction cancelled.");
+      }
+    });
+
     // Streaming transcription events
     const unlistenTranscriptionChunk = listen("transcription-chunk", (event) => {
       const payload = event.payload as {
@@ -748,8 +879,29 @@ function App() {
         chunkIndex: number;
         text: string;
         provider: string;
+        avgConfidence?: number | null;
+        lowConfidence?: boolean;
       };
       setLiveTranscript((prev) => prev + " " + payload.text);
+      setLivePartial("");
+      if (payload.lowConfidence) {
+        const score = Math.round((payload.avgConfidence ?? 0) * 100);
+        appendLog(
+          `Chunk ${payload.chunkIndex} has low confidence (${score}%); consider re-recording that part.`
+        );
+      }
+    });
+
+    const unlistenChunkDelta = listen("transcription-chunk-delta", (event) => {
+      const payload = event.payload as { sessionId: string; chunkIndex: number; partial: string };
+      setLivePartial(payload.partial);
+    });
+
+    const unlistenSeamWarning = listen("transcription-seam-warning", (event) => {
+      const payload = event.payload as { sessionId: string; chunkIndex: number };
+      appendLog(
+        `Chunk ${payload.chunkIndex} may have split a word at its boundary; check the transcript there.`
+      );
     });
 
     const unlistenTranscriptionError = listen("transcription-error", (event) => {
@@ -761,6 +913,43 @@ function App() {
       appendLog(`Transcription chunk error: ${payload.error}`);
     });
 
+    // Local whisper runs one at a time; streaming chunks queue silently.
+    const unlistenTranscriptionQueued = listen("transcription-queued", (event) => {
+      const payload = event.payload as { position: number; sessionId?: string };
+      if (!payload.sessionId) {
+        setStatus(`Waiting for whisper (${payload.position} ahead)...`);
+      }
+    });
+
+    const unlistenTranscriptionStarted = listen("transcription-started", (event) => {
+      const payload = event.payload as { sessionId?: string };
+      if (!payload.sessionId) {
+        setStatus("Transcribing (local)...");
+      }
+    });
+
+    // Backend-side meeting edits (audio attachments, deletions)
+    const unlistenMeetingUpdated = listen("meeting-updated", (event) => {
+      const updated = event.payload as MeetingRecord;
+      setMeetings((prev) =>
+        prev.map((meeting) => (meeting.id === updated.id ? updated : meeting))
+      );
+    });
+
+    const unlistenMeetingCreated = listen("meeting-created", (event) => {
+      const created = event.payload as MeetingRecord;
+      setMeetings((prev) =>
+        prev.some((meeting) => meeting.id === created.id) ? prev : [created, ...prev]
+      );
+    });
+
+    const unlistenMeetingDeleted = listen("meeting-deleted", (event) => {
+      const payload = event.payload as { meetingId: string };
+      setMeetings((prev) =>
+        prev.filter((meeting) => meeting.id !== payload.meetingId)
+      );
+    });
+
     return () => {
       void unlistenDelta.then((fn) => fn());
       void unlistenDone.then((fn) => fn());
@@ -774,8 +963,16 @@ function App() {
       void unlistenCleanError.then((fn) => fn());
       void unlistenActionsDone.then((fn) => fn());
       void unlistenActionsError.then((fn) => fn());
+      void unlistenActionsCancelled.then((fn) => fn());
       void unlistenTranscriptionChunk.then((fn) => fn());
+      void unlistenChunkDelta.then((fn) => fn());
+      void unlistenSeamWarning.then((fn) => fn());
       void unlistenTranscriptionError.then((fn) => fn());
+      void unlistenTranscriptionQueued.then((fn) => fn());
+      void unlistenTranscriptionStarted.then((fn) => fn());
+      void unlistenMeetingUpdated.then((fn) => fn());
+      void unlistenMeetingCreated.then((fn) => fn());
+      void unlistenMeetingDeleted.then((fn) => fn());
     };
   }, []);
 
@@ -956,6 +1153,7 @@ function App() {
           });
           setStreamingSessionId(sessionId);
           streamingChunkIndexRef.current = 0;
+          failedChunkAudioRef.current.clear();
           appendLog(`Streaming session started: ${sessionId}`);
 
           const intervalMs = Math.max(
@@ -966,12 +1164,14 @@ function App() {
             if (streamingBusyRef.current) return;
             if (!recorderRef.current) return;
             if (!sessionId) return;
+            let audioBase64 = "";
+            let chunkIndex = -1;
             try {
               streamingBusyRef.current = true;
               const chunk = await recorderRef.current.getChunk();
               if (!chunk) return;
-              const audioBase64 = await blobToWavBase64(chunk);
-              const chunkIndex = streamingChunkIndexRef.current;
+              audioBase64 = await blobToWavBase64(chunk);
+              chunkIndex = streamingChunkIndexRef.current;
               streamingChunkIndexRef.current += 1;
               await invoke("transcribe_chunk", {
                 sessionId,
@@ -979,6 +1179,7 @@ function App() {
                 chunkIndex,
               });
             } catch (err) {
+              if (chunkIndex >= 0) failedChunkAudioRef.current.set(chunkIndex, audioBase64);
               appendLog(`Streaming chunk failed: ${String(err)}`);
             } finally {
               streamingBusyRef.current = false;
@@ -1046,6 +1247,9 @@ function App() {
               sessionId: streamingSessionId,
               audioBase64,
               chunkIndex,
+            }).catch((err) => {
+              failedChunkAudioRef.current.set(chunkIndex, audioBase64);
+              throw err;
             });
           }
         } catch (err) {
@@ -1055,23 +1259,47 @@ function App() {
 
       // End streaming session if active
       let mergedTranscript = "";
+      let missingChunks: number[] = [];
       if (streamingSessionId) {
         try {
-          mergedTranscript = await invoke<string>("end_streaming_session", {
+          let result = await invoke<StreamingSessionResult>("end_streaming_session", {
             sessionId: streamingSessionId,
           });
+          const retryable = result.failedIndices.filter((index) =>
+            failedChunkAudioRef.current.has(index)
+          );
+          if (retryable.length) {
+            appendLog(`Retrying ${retryable.length} failed streaming chunk(s)...`);
+            result = await invoke<StreamingSessionResult>("retry_failed_chunks", {
+              sessionId: streamingSessionId,
+              chunks: retryable.map((chunkIndex) => ({
+                chunkIndex,
+                audioBase64: failedChunkAudioRef.current.get(chunkIndex),
+              })),
+            });
+          }
+          mergedTranscript = result.transcript;
+          missingChunks = result.failedIndices;
         } catch {
           // Ignore cleanup errors
         }
+        failedChunkAudioRef.current.clear();
         setStreamingSessionId(null);
       }
 
       const streamingEnabled = config?.transcription?.streaming?.enabled ?? false;
       const mergedText = mergedTranscript.trim();
       const liveText = liveTranscript.trim();
-      const shouldUseStreaming = streamingEnabled && (mergedText || liveText);
+      // A merge with gaps would silently drop speech; transcribe the whole recording instead.
+      if (missingChunks.length) {
+        appendLog(`Streaming transcript is missing chunk(s) ${missingChunks.join(", ")}.`);
+      }
+      const shouldUseStreaming =
+        streamingEnabled && !missingChunks.length && (mergedText || liveText);
 
       let finalTranscript = "";
+      let newSegments: TranscriptSegment[] = [];
+      let source: { provider?: string; model?: string } = {};
 
       if (shouldUseStreaming) {
         finalTranscript = mergedText || liveText;
@@ -1089,21 +1317,49 @@ function App() {
 
         appendLog(`Provider: ${result.provider}`);
         appendLog(`Command: ${result.command}`);
+        if (result.audioPath) appendLog(`Kept audio: ${result.audioPath}`);
+        if (result.punctuationRestored) appendLog("Punctuation restored by AI");
+        if (result.modelFallbackFrom) {
+          appendLog(`Model ${result.modelFallbackFrom} failed to load; used ${result.model} instead`);
+        }
         if (result.stdout.trim()) appendLog(`stdout: ${result.stdout.trim()}`);
         if (result.stderr.trim()) appendLog(`stderr: ${result.stderr.trim()}`);
 
         finalTranscript = result.transcript.trim();
+        source = { provider: result.provider, model: result.model || undefined };
+        // Cleaned text no longer matches whisper's segments.
+        if (!result.rawTranscript) newSegments = result.segments ?? [];
       }
       
-      updateActiveMeeting((meeting) => ({
-        ...meeting,
-        transcript: meeting.transcript
-          ? `${meeting.transcript}\n${finalTranscript}`
-          : finalTranscript,
-        updatedAt: new Date().toISOString(),
-      }));
+      updateActiveMeeting((meeting) => {
+        // Segments must cover the whole transcript; shift new ones past the
+        // previous recording, or drop them when this recording has none.
+        const previous = meeting.transcript ? meeting.segments ?? [] : [];
+        const canKeep = newSegments.length > 0 && (!meeting.transcript || previous.length > 0);
+        const offset = previous.length ? previous[previous.length - 1].endMs : 0;
+        return {
+          ...meeting,
+          transcript: meeting.transcript
+            ? `${meeting.transcript}\n${finalTranscript}`
+            : finalTranscript,
+          segments: canKeep
+            ? [
+                ...previous,
+                ...newSegments.map((segment) => ({
+                  ...segment,
+                  startMs: segment.startMs + offset,
+                  endMs: segment.endMs + offset,
+                })),
+              ]
+            : [],
+          transcriptionProvider: source.provider ?? meeting.transcriptionProvider,
+          transcriptionModel: source.provider ? source.model : meeting.transcriptionModel,
+          updatedAt: new Date().toISOString(),
+        };
+      });
       
       setLiveTranscript("");
+      setLivePartial("");
       setRecordingTime(0);
       streamingChunkIndexRef.current = 0;
       setStatus("Idle");
@@ -1150,17 +1406,224 @@ function App() {
     }
   }
 
+  async function handleSegmentTopics() {
+    if (!activeMeeting?.transcript.trim()) return;
+
+    setIsSegmentingTopics(true);
+    setStatus("Finding chapters...");
+    appendLog("Starting topic segmentation...");
+
+    try {
+      const chapters = await invoke<TopicChapter[]>("segment_topics", {
+        meetingId: activeMeeting.id,
+        model: selectedModel,
+      });
+      setStatus("Idle");
+      appendLog(`Found ${chapters.length} chapters.`);
+    } catch (error) {
+      const message =
+        error instanceof Error
+          ? error.message
+          : typeof error === "string"
+            ? error
+            : "Topic segmentation failed";
+      setStatus(message);
+      appendLog(message);
+    } finally {
+      setIsSegmentingTopics(false);
+    }
+  }
+
+  async function handleCancelActions() {
+    if (!activeMeeting) return;
+    try {
+      await invoke<boolean>("cancel_action_items", { meetingId: activeMeeting.id });
+    } catch (error) {
+      appendLog(`Failed to cancel action extraction: ${String(error)}`);
+    }
+  }
+
+  async function handleExtractDecisions() {
+    if (!activeMeeting) return;
+    if (!activeMeeting.transcript.trim() && !activeMeeting.notes.trim()) {
+      appendLog("Decision extraction blocked: transcript and notes are empty.");
+      return;
+    }
+
+    setIsExtractingDecisions(true);
+    setStatus("Extracting decisions...");
+    appendLog("Starting decision extraction...");
+
+    try {
+      const decisions = await invoke<Decision[]>("extract_decisions", {
+        meetingId: activeMeeting.id,
+        model: selectedModel,
+      });
+      setStatus("Idle");
+      appendLog(`Extracted ${decisions.length} decisions.`);
+    } catch (error) {
+      const message =
+        error instanceof Error
+          ? error.message
+          : typeof error === "string"
+            ? error
+            : "Decision extraction failed";
+      setStatus(message);
+      appendLog(message);
+    } finally {
+      setIsExtractingDecisions(false);
+    }
+  }
+
+  async function handleExtractQuestions() {
+    if (!activeMeeting) return;
+    if (!activeMeeting.transcript.trim() && !activeMeeting.notes.trim()) {
+      appendLog("Question extraction blocked: transcript and notes are empty.");
+      return;
+    }
+
+    setIsExtractingQuestions(true);
+    setStatus("Extracting questions...");
+    appendLog("Starting question extraction...");
+
+    try {
+      const questions = await invoke<Question[]>("extract_questions", {
+        meetingId: activeMeeting.id,
+        model: selectedModel,
+      });
+      setStatus("Idle");
+      const open = questions.filter((item) => !item.answered).length;
+      appendLog(`Extracted ${questions.length} questions (${open} open).`);
+    } catch (error) {
+      const message =
+        error instanceof Error
+          ? error.message
+          : typeof error === "string"
+            ? error
+            : "Question extraction failed";
+      setStatus(message);
+      appendLog(message);
+    } finally {
+      setIsExtractingQuestions(false);
+    }
+  }
+
+  async function handleRetranscribe() {
+    if (!activeMeeting?.audioPath) return;
+
+    setIsRetranscribing(true);
+    setStatus("Re-transcribing recording...");
+    appendLog("Re-transcribing stored audio with current settings...");
+
+    try {
+      await invoke<MeetingRecord>("retranscribe_meeting", {
+        meetingId: activeMeeting.id,
+      });
+      setStatus("Idle");
+      appendLog("Re-transcription complete; previous transcript kept in history.");
+    } catch (error) {
+      const message =
+        error instanceof Error
+          ? error.message
+          : typeof error === "string"
+            ? error
+            : "Re-transcription failed";
+      setStatus(message);
+      appendLog(message);
+    } finally {
+      setIsRetranscribing(false);
+    }
+  }
+
+  function jumpToChapter(chapter: TopicChapter) {
+    const textarea = transcriptRef.current;
+    if (!textarea || !activeMeeting) return;
+    // Chapter offsets count code points; textarea offsets count UTF-16 units.
+    const chars = Array.from(activeMeeting.transcript);
+    const start = chars.slice(0, chapter.startChar).join("").length;
+    const end = start + chars.slice(chapter.startChar, chapter.endChar).join("").length;
+    textarea.focus();
+    textarea.setSelectionRange(start, end);
+    const ratio = start / Math.max(activeMeeting.transcript.length, 1);
+    textarea.scrollTop = ratio * textarea.scrollHeight;
+  }
+
+  async function handleSyncExports() {
+    setStatus("Syncing exports...");
+    try {
+      const report = await invoke<{
+        written: number;
+        skipped: number;
+        failed: number;
+        errors: string[];
+        directory: string;
+      }>("sync_all_exports");
+      report.errors.forEach((error) => appendLog(error));
+      const message = `Synced to ${report.directory}: ${report.written} written, ${report.skipped} unchanged, ${report.failed} failed.`;
+      appendLog(message);
+      setStatus(report.failed ? message : "Idle");
+    } catch (error) {
+      const message =
+        error instanceof Error
+          ? error.message
+          : typeof error === "string"
+            ? error
+            : "Export sync failed";
+      setStatus(message);
+      appendLog(message);
+    }
+  }
+
   async function handleExportMarkdown() {
     if (!activeMeeting) return;
     
     setStatus("Exporting markdown...");
     try {
-      const path = await invoke<string>("export_meeting_markdown", {
+      const result = await invoke<{
+        path: string | null;
+        content: string;
+        error: string | null;
+      }>("export_meeting_markdown", {
         meeting: activeMeeting,
         includeTranscript: true,
       });
+      if (result.path) {
+        setStatus("Exported!");
+        appendLog(`Exported to: ${result.path}`);
+        setTimeout(() => setStatus("Idle"), 2000);
+      } else {
+        const message = result.error ?? "Export failed";
+        try {
+          await navigator.clipboard.writeText(result.content);
+          setStatus(`${message}. Markdown copied to clipboard instead.`);
+        } catch {
+          setStatus(message);
+        }
+        appendLog(message);
+      }
+    } catch (error) {
+      const message =
+        error instanceof Error
+          ? error.message
+          : typeof error === "string"
+            ? error
+            : "Export failed";
+      setStatus(message);
+      appendLog(message);
+    }
+  }
+
+  async function handleExportTranscript() {
+    if (!activeMeeting) return;
+
+    setStatus("Exporting transcript...");
+    try {
+      const path = await invoke<string>("export_transcript", {
+        meetingId: activeMeeting.id,
+        includeTimestamps: true,
+      });
       setStatus("Exported!");
-      appendLog(`Exported to: ${path}`);
+      appendLog(`Transcript exported to: ${path}`);
       setTimeout(() => setStatus("Idle"), 2000);
     } catch (error) {
       const message =
@@ -1168,12 +1631,37 @@ function App() {
           ? error.message
           : typeof error === "string"
             ? error
-            : "Export failed";
+            : "Transcript export failed";
       setStatus(message);
       appendLog(message);
     }
   }
 
+  async function handleOpenExportsFolder() {
+    try {
+      const dir = await invoke<string>("open_exports_folder");
+      appendLog(`Opened export folder: ${dir}`);
+    } catch (error) {
+      appendLog(`Failed to open export folder: ${String(error)}`);
+    }
+  }
+
+  async function loadRecentExports() {
+    try {
+      setRecentExports(await invoke<RecentExport[]>("list_recent_exports", { limit: 10 }));
+    } catch (error) {
+      appendLog(`Failed to list exports: ${String(error)}`);
+    }
+  }
+
+  async function revealExport(path: string) {
+    try {
+      await invoke("reveal_export", { path });
+    } catch (error) {
+      appendLog(`Failed to reveal export: ${String(error)}`);
+    }
+  }
+
   function toggleActionItem(itemId: string) {
     updateActiveMeeting((meeting) => ({
       ...meeting,
@@ -1488,6 +1976,58 @@ function App() {
                 <span>Live transcript</span>
               </label>
             )}
+            {config && (
+              <label className="capture-toggle" title="Clean up filler words after transcription">
+                <input
+                  type="checkbox"
+                  checked={Boolean(config?.transcription?.autoClean)}
+                  disabled={isRecording}
+                  onChange={async (event) => {
+                    const next = event.target.checked;
+                    const newConfig = {
+                      ...config,
+                      transcription: {
+                        ...config.transcription,
+                        autoClean: next,
+                      },
+                    };
+                    setConfig(newConfig);
+                    try {
+                      await invoke("save_config_command", { config: newConfig });
+                    } catch (error) {
+                      appendLog(`Failed to save auto-clean toggle: ${String(error)}`);
+                    }
+                  }}
+                />
+                <span>Auto-clean</span>
+              </label>
+            )}
+            {config && !config.transcription?.autoClean && (
+              <label className="capture-toggle" title="Restore punctuation when whisper returns none">
+                <input
+                  type="checkbox"
+                  checked={Boolean(config?.transcription?.restorePunctuation)}
+                  disabled={isRecording}
+                  onChange={async (event) => {
+                    const next = event.target.checked;
+                    const newConfig = {
+                      ...config,
+                      transcription: {
+                        ...config.transcription,
+                        restorePunctuation: next,
+                      },
+                    };
+                    setConfig(newConfig);
+                    try {
+                      await invoke("save_config_command", { config: newConfig });
+                    } catch (error) {
+                      appendLog(`Failed to save punctuation toggle: ${String(error)}`);
+                    }
+                  }}
+                />
+                <span>Punctuate</span>
+              </label>
+            )}
             {isRecording && (
               <span className="recording-timer">{formatTime(recordingTime)}</span>
             )}
@@ -1508,15 +2048,63 @@ function App() {
             >
               📄 Export
             </button>
+            <button
+              className="ghost"
+              onClick={handleExportTranscript}
+              disabled={!activeMeeting?.transcript?.trim()}
+              title="Export the transcript as a timestamped text file"
+            >
+              📝 Transcript
+            </button>
+            <button
+              className="ghost"
+              onClick={handleSyncExports}
+              disabled={!meetings.length}
+              title="Export every changed meeting to the export folder"
+            >
+              🔄 Sync all
+            </button>
+            <button
+              className="ghost"
+              onClick={handleOpenExportsFolder}
+              title="Open the export folder"
+            >
+              📂 Exports
+            </button>
           </div>
           <div className="record-hint">
             {config?.ui?.includeSystemAudio
               ? "System + mic capture enabled (configured)."
               : "Mic-only capture (configured)."}
           </div>
+          <details
+            className="recent-exports"
+            onToggle={(event) => {
+              if (event.currentTarget.open) void loadRecentExports();
+            }}
+          >
+            <summary>Recent exports</summary>
+            {recentExports?.length === 0 && <div className="record-hint">No exports yet.</div>}
+            <ul>
+              {recentExports?.map((file) => (
+                <li key={file.path}>
+                  <button
+                    className="ghost"
+                    onClick={() => revealExport(file.path)}
+                    title={file.path}
+                  >
+                    {file.name}
+                  </button>
+                  <span className="record-hint">
+                    {new Date(file.modifiedAt).toLocaleString()} · {Math.max(1, Math.round(file.size / 1024))} KB
+                  </span>
+                </li>
+              ))}
+            </ul>
+          </details>
         </section>
 
-        {isRecording && liveTranscript && (
+        {isRecording && (liveTranscript || livePartial) && (
           <section className="panel live-transcript-panel">
             <div className="panel-header">
               <h2>
@@ -1526,6 +2114,7 @@ function App() {
             </div>
             <div className="live-transcript-body">
               {liveTranscript}
+              {livePartial && <span className="live-partial"> {livePartial}</span>}
             </div>
           </section>
         )}
@@ -1583,6 +2172,26 @@ function App() {
               >
                 Enhance selection
               </button>
+              <button
+                className="ghost"
+                onClick={handleSegmentTopics}
+                disabled={
+                  isSegmentingTopics ||
+                  isCleaningTranscript ||
+                  !activeMeeting?.transcript.trim()
+                }
+              >
+                {isSegmentingTopics ? "Finding chapters..." : "Chapters"}
+              </button>
+              {activeMeeting?.audioPath ? (
+                <button
+                  className="ghost"
+                  onClick={handleRetranscribe}
+                  disabled={isRetranscribing || isRecording}
+                >
+                  {isRetranscribing ? "Re-transcribing..." : "Re-transcribe"}
+                </button>
+              ) : null}
               <button
                 className="ghost"
                 onClick={() => setIsTranscriptCollapsed((prev) => !prev)}
@@ -1608,6 +2217,20 @@ function App() {
               placeholder="Transcript will appear here..."
             />
           )}
+          {!isTranscriptCollapsed && activeMeeting?.chapters?.length ? (
+            <ol className="chapter-list">
+              {activeMeeting.chapters.map((chapter) => (
+                <li key={`${chapter.startChar}-${chapter.title}`}>
+                  <button className="ghost" onClick={() => jumpToChapter(chapter)}>
+                    {chapter.startMs != null
+                      ? `${formatTime(Math.floor(chapter.startMs / 1000))} · `
+                      : ""}
+                    {chapter.title}
+                  </button>
+                </li>
+              ))}
+            </ol>
+          ) : null}
         </section>
 
         <section
@@ -1691,6 +2314,11 @@ function App() {
               ) : null}
             </div>
             <div className="panel-actions">
+              {isExtractingActions ? (
+                <button className="ghost" onClick={handleCancelActions}>
+                  Cancel
+                </button>
+              ) : null}
               <button
                 className="primary"
                 onClick={handleExtractActions}
@@ -1746,6 +2374,101 @@ function App() {
           </div>
         </section>
 
+        <section className={`panel action-items-panel ${isExtractingDecisions ? "action-items-panel--loading" : ""}`}>
+          <div className="panel-header">
+            <div>
+              <h2>Decisions</h2>
+              {isExtractingDecisions ? (
+                <span className="pill">Extracting decisions…</span>
+              ) : activeMeeting?.decisions?.length ? (
+                <span className="pill">{activeMeeting.decisions.length} decisions</span>
+              ) : null}
+            </div>
+            <div className="panel-actions">
+              <button
+                className="primary"
+                onClick={handleExtractDecisions}
+                disabled={isExtractingDecisions || isSummarizing || !activeMeeting?.transcript?.trim()}
+              >
+                Extract decisions
+              </button>
+            </div>
+          </div>
+          <div className="action-items-body">
+            {activeMeeting?.decisions?.length ? (
+              <ul className="action-items-list">
+                {activeMeeting.decisions.map((item, index) => (
+                  <li key={index} className="action-item">
+                    <div className="action-item-content">
+                      <div className="action-item-task">{item.decision}</div>
+                      {item.owner && (
+                        <div className="action-item-meta">
+                          <span className="action-item-assignee">👤 {item.owner}</span>
+                        </div>
+                      )}
+                      {item.rationale && (
+                        <div className="action-item-context">{item.rationale}</div>
+                      )}
+                    </div>
+                  </li>
+                ))}
+              </ul>
+            ) : (
+              <div className="action-items-empty">
+                No decisions yet. Click "Extract decisions" to pull them from the transcript.
+              </div>
+            )}
+          </div>
+        </section>
+
+        <section className={`panel action-items-panel ${isExtractingQuestions ? "action-items-panel--loading" : ""}`}>
+          <div className="panel-header">
+            <div>
+              <h2>Questions</h2>
+              {isExtractingQuestions ? (
+                <span className="pill">Extracting questions…</span>
+              ) : activeMeeting?.questions?.length ? (
+                <span className="pill">{activeMeeting.questions.length} questions</span>
+              ) : null}
+            </div>
+            <div className="panel-actions">
+              <button
+                className="primary"
+                onClick={handleExtractQuestions}
+                disabled={isExtractingQuestions || isSummarizing || !activeMeeting?.transcript?.trim()}
+              >
+                Extract questions
+              </button>
+            </div>
+          </div>
+          <div className="action-items-body">
+            {activeMeeting?.questions?.length ? (
+              <ul className="action-items-list">
+                {activeMeeting.questions.map((item, index) => (
+                  <li key={index} className="action-item">
+                    <div className="action-item-content">
+                      <div className="action-item-task">{item.question}</div>
+                      <div className="action-item-meta">
+                        {item.askedBy && (
+                          <span className="action-item-assignee">👤 {item.askedBy}</span>
+                        )}
+                        <span>{item.answered ? "Answered" : "Open"}</span>
+                      </div>
+                      {item.answer && (
+                        <div className="action-item-context">{item.answer}</div>
+                      )}
+                    </div>
+                  </li>
+                ))}
+              </ul>
+            ) : (
+              <div className="action-items-empty">
+                No questions yet. Click "Extract questions" to pull them from the transcript.
+              </div>
+            )}
+          </div>
+        </section>
+
         <section className={`panel diagnostics ${diagnosticsOpen ? "open" : ""}`}>
           <div className="panel-header">
             <button
@@ -1895,6 +2618,44 @@ function App() {
                     placeholder="~/Documents/voxii-meetings"
                   />
                 </div>
+                <div className="settings-field">
+                  <label>
+                    <input
+                      type="checkbox"
+                      checked={Boolean(config?.export?.frontmatter)}
+                      onChange={(e) => {
+                        const newConfig = {
+                          ...config,
+                          export: {
+                            ...config?.export,
+                            frontmatter: e.target.checked,
+                          },
+                        };
+                        setConfig(newConfig as AppConfig);
+                      }}
+                    />{" "}
+                    Add YAML front-matter (Hugo/Jekyll)
+                  </label>
+                </div>
+                <div className="settings-field">
+                  <label>
+                    <input
+                      type="checkbox"
+                      checked={Boolean(config?.export?.sidecarMetadata)}
+                      onChange={(e) => {
+                        const newConfig = {
+                          ...config,
+                          export: {
+                            ...config?.export,
+                            sidecarMetadata: e.target.checked,
+                          },
+                        };
+                        setConfig(newConfig as AppConfig);
+                      }}
+                    />{" "}
+                    Write a .meta.json file next to each export
+                  </label>
+                </div>
               </section>
 
               <section className="settings-section">
@@ -1943,23 +2704,66 @@ function App() {
                   />
                 </div>
                 <div className="settings-field">
-                  <label>Language</label>
-                  <input
-                    type="text"
-                    value={config?.transcription?.language ?? "en"}
+                  <label>Language Hint</label>
+                  <select
+                    value={
+                      config?.transcription?.language === ""
+                        ? "none"
+                        : config?.transcription?.language === "auto"
+                          ? "auto"
+                          : "specific"
+                    }
                     onChange={(e) => {
+                      const mode = e.target.value;
                       const newConfig = {
                         ...config,
                         transcription: {
                           ...config?.transcription,
-                          language: e.target.value,
+                          language: mode === "none" ? "" : mode === "auto" ? "auto" : "en",
                         },
                       };
                       setConfig(newConfig as AppConfig);
                     }}
-                    placeholder="en"
-                  />
+                  >
+                    <option value="specific">Specific language</option>
+                    <option value="auto">Auto-detect</option>
+                    <option value="none">No hint (multilingual)</option>
+                  </select>
                 </div>
+                {config?.transcription?.language !== "" &&
+                  config?.transcription?.language !== "auto" && (
+                    <div className="settings-field">
+                      <label>Language</label>
+                      <select
+                        value={config?.transcription?.language ?? "en"}
+                        onChange={(e) => {
+                          const newConfig = {
+                            ...config,
+                            transcription: {
+                              ...config?.transcription,
+                              language: e.target.value,
+                            },
+                          };
+                          setConfig(newConfig as AppConfig);
+                        }}
+                      >
+                        {!languageOptions.some(
+                          (option) => option.code === (config?.transcription?.language ?? "en")
+                        ) && (
+                          <option value={config?.transcription?.language ?? "en"}>
+                            {config?.transcription?.language ?? "en"}
+                          </option>
+                        )}
+                        {languageOptions
+                          .filter((option) => option.code !== "auto")
+                          .map((option) => (
+                            <option key={option.code} value={option.code}>
+                              {option.name} ({option.code})
+                            </option>
+                          ))}
+                      </select>
+                    </div>
+                  )}
               </section>
             </div>
             <div className="settings-footer">